@@ -12,11 +12,7 @@ async fn main() {
     // only run example program against demo account!!
     assert_eq!(api_host, "api-fxpractice.oanda.com");
 
-    let client = fxoanda::Client {
-        host: String::from(api_host),
-        reqwest: reqwest::Client::new(),
-        authentication: String::from(api_key),
-    };
+    let client = fxoanda::Client::new(api_host, api_key);
 
     match
         fxoanda::GetInstrumentCandlesRequest
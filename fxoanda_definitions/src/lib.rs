@@ -6,7 +6,34 @@ extern crate serde;
 use fxoanda_serdes::*;
 use chrono::prelude::*;
 use std::str::FromStr;
-#[derive(Debug, Serialize, Deserialize)]
+
+/// Error returned when a string does not name a variant of one of the API
+/// enumerations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    /// Name of the enumeration that was being parsed, e.g. "TimeInForce".
+    pub enum_name: &'static str,
+    /// The string that failed to parse.
+    pub input: String,
+}
+
+impl ParseEnumError {
+    pub fn new(enum_name: &'static str, input: &str) -> ParseEnumError {
+        ParseEnumError {
+            enum_name,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid {} value: {:?}", self.enum_name, self.input)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TradeClientExtensionsModifyRejectTransaction {
     /// The ID of the Trade who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -88,6 +115,11 @@ pub struct TradeClientExtensionsModifyRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TradeClientExtensionsModifyRejectTransaction {
     pub fn new() -> TradeClientExtensionsModifyRejectTransaction {
@@ -103,6 +135,8 @@ impl TradeClientExtensionsModifyRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -214,7 +248,7 @@ impl TradeClientExtensionsModifyRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -501,6 +535,11 @@ pub struct MarketIfTouchedOrder {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketIfTouchedOrder {
     pub fn new() -> MarketIfTouchedOrder {
@@ -532,6 +571,8 @@ impl MarketIfTouchedOrder {
             trade_client_extensions: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -859,7 +900,7 @@ impl MarketIfTouchedOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TradeClientExtensionsModifyTransaction {
     /// The ID of the Trade who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -936,6 +977,11 @@ pub struct TradeClientExtensionsModifyTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TradeClientExtensionsModifyTransaction {
     pub fn new() -> TradeClientExtensionsModifyTransaction {
@@ -950,6 +996,8 @@ impl TradeClientExtensionsModifyTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -1053,7 +1101,7 @@ impl TradeClientExtensionsModifyTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct FixedPriceOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -1256,6 +1304,11 @@ pub struct FixedPriceOrder {
     #[serde(default)]
     #[serde(rename = "takeProfitOnFill", skip_serializing_if = "Option::is_none")]
     pub take_profit_on_fill: Option<TakeProfitDetails>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl FixedPriceOrder {
     pub fn new() -> FixedPriceOrder {
@@ -1281,6 +1334,8 @@ impl FixedPriceOrder {
             otype: None,
             id: None,
             take_profit_on_fill: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -1518,7 +1573,7 @@ impl FixedPriceOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct DelayedTradeClosureTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -1584,6 +1639,11 @@ pub struct DelayedTradeClosureTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl DelayedTradeClosureTransaction {
     pub fn new() -> DelayedTradeClosureTransaction {
@@ -1597,6 +1657,8 @@ impl DelayedTradeClosureTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -1689,7 +1751,7 @@ impl DelayedTradeClosureTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LimitOrderRejectTransaction {
     /// The time-in-force requested for the Limit Order.
     #[serde(default)]
@@ -1890,6 +1952,11 @@ pub struct LimitOrderRejectTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LimitOrderRejectTransaction {
     pub fn new() -> LimitOrderRejectTransaction {
@@ -1916,6 +1983,8 @@ impl LimitOrderRejectTransaction {
             trade_client_extensions: None,
             time: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -2164,7 +2233,7 @@ impl LimitOrderRejectTransaction {
 /// In the context of an Order or a Trade, defines whether the units are
 /// positive or negative.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Direction {
     #[serde(rename = "LONG")]
     Long,
@@ -2172,27 +2241,43 @@ pub enum Direction {
     Short,
 }
 
+impl Direction {
+    /// Every variant of Direction, in definition order.
+    pub const ALL: &[Direction] = &[
+        Direction::Long,
+        Direction::Short,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Long => "LONG",
+            Direction::Short => "SHORT",
+        }
+    }
+}
+
 impl FromStr for Direction {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Direction, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<Direction, ParseEnumError> {
         match s {
             "LONG" => Ok(Direction::Long),
             "SHORT" => Ok(Direction::Short),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("Direction", s)),
         }
     }
 }
 
 impl std::fmt::Display for Direction {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The day of the week to use for candlestick granularities with weekly
 /// alignment.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WeeklyAlignment {
     #[serde(rename = "Monday")]
     Monday,
@@ -2210,9 +2295,35 @@ pub enum WeeklyAlignment {
     Sunday,
 }
 
+impl WeeklyAlignment {
+    /// Every variant of WeeklyAlignment, in definition order.
+    pub const ALL: &[WeeklyAlignment] = &[
+        WeeklyAlignment::Monday,
+        WeeklyAlignment::Tuesday,
+        WeeklyAlignment::Wednesday,
+        WeeklyAlignment::Thursday,
+        WeeklyAlignment::Friday,
+        WeeklyAlignment::Saturday,
+        WeeklyAlignment::Sunday,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeeklyAlignment::Monday => "Monday",
+            WeeklyAlignment::Tuesday => "Tuesday",
+            WeeklyAlignment::Wednesday => "Wednesday",
+            WeeklyAlignment::Thursday => "Thursday",
+            WeeklyAlignment::Friday => "Friday",
+            WeeklyAlignment::Saturday => "Saturday",
+            WeeklyAlignment::Sunday => "Sunday",
+        }
+    }
+}
+
 impl FromStr for WeeklyAlignment {
-    type Err = ();
-    fn from_str(s: &str) -> Result<WeeklyAlignment, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<WeeklyAlignment, ParseEnumError> {
         match s {
             "Monday" => Ok(WeeklyAlignment::Monday),
             "Tuesday" => Ok(WeeklyAlignment::Tuesday),
@@ -2221,18 +2332,18 @@ impl FromStr for WeeklyAlignment {
             "Friday" => Ok(WeeklyAlignment::Friday),
             "Saturday" => Ok(WeeklyAlignment::Saturday),
             "Sunday" => Ok(WeeklyAlignment::Sunday),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("WeeklyAlignment", s)),
         }
     }
 }
 
 impl std::fmt::Display for WeeklyAlignment {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderPositionCloseout {
     /// Indication of how much of the Position to close. Either "ALL", or a
     /// DecimalNumber reflection a partial close of the Trade. The
@@ -2248,12 +2359,19 @@ pub struct MarketOrderPositionCloseout {
     #[serde(default)]
     #[serde(rename = "instrument", skip_serializing_if = "Option::is_none")]
     pub instrument: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderPositionCloseout {
     pub fn new() -> MarketOrderPositionCloseout {
         MarketOrderPositionCloseout {
             units: None,
             instrument: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -2279,7 +2397,7 @@ impl MarketOrderPositionCloseout {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TradeSummary {
     /// ID of the Trade's Stop Loss Order, only provided if such an Order
     /// exists.
@@ -2480,6 +2598,11 @@ pub struct TradeSummary {
     #[serde(default)]
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TradeSummary {
     pub fn new() -> TradeSummary {
@@ -2503,6 +2626,8 @@ impl TradeSummary {
             closing_transaction_i_ds: None,
             client_extensions: None,
             id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -2714,7 +2839,7 @@ impl TradeSummary {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopLossOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -2958,6 +3083,11 @@ pub struct StopLossOrder {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopLossOrder {
     pub fn new() -> StopLossOrder {
@@ -2985,6 +3115,8 @@ impl StopLossOrder {
             client_trade_id: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -3269,7 +3401,7 @@ impl StopLossOrder {
 
 /// The financing mode of an Account
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AccountFinancingMode {
     #[serde(rename = "NO_FINANCING")]
     NoFinancing,
@@ -3279,25 +3411,43 @@ pub enum AccountFinancingMode {
     Daily,
 }
 
+impl AccountFinancingMode {
+    /// Every variant of AccountFinancingMode, in definition order.
+    pub const ALL: &[AccountFinancingMode] = &[
+        AccountFinancingMode::NoFinancing,
+        AccountFinancingMode::SecondBySecond,
+        AccountFinancingMode::Daily,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountFinancingMode::NoFinancing => "NO_FINANCING",
+            AccountFinancingMode::SecondBySecond => "SECOND_BY_SECOND",
+            AccountFinancingMode::Daily => "DAILY",
+        }
+    }
+}
+
 impl FromStr for AccountFinancingMode {
-    type Err = ();
-    fn from_str(s: &str) -> Result<AccountFinancingMode, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<AccountFinancingMode, ParseEnumError> {
         match s {
             "NO_FINANCING" => Ok(AccountFinancingMode::NoFinancing),
             "SECOND_BY_SECOND" => Ok(AccountFinancingMode::SecondBySecond),
             "DAILY" => Ok(AccountFinancingMode::Daily),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("AccountFinancingMode", s)),
         }
     }
 }
 
 impl std::fmt::Display for AccountFinancingMode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct AccountChangesState {
     /// The price-dependent state for each open Position in the Account.
     #[serde(default)]
@@ -3463,6 +3613,11 @@ pub struct AccountChangesState {
         with = "serfloats"
     )]
     pub position_value: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl AccountChangesState {
     pub fn new() -> AccountChangesState {
@@ -3483,6 +3638,8 @@ impl AccountChangesState {
             orders: None,
             margin_closeout_position_value: None,
             position_value: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -3650,7 +3807,7 @@ impl AccountChangesState {
 
 /// The reason that an Account is being funded.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FundingReason {
     #[serde(rename = "CLIENT_FUNDING")]
     ClientFunding,
@@ -3664,27 +3821,49 @@ pub enum FundingReason {
     Adjustment,
 }
 
+impl FundingReason {
+    /// Every variant of FundingReason, in definition order.
+    pub const ALL: &[FundingReason] = &[
+        FundingReason::ClientFunding,
+        FundingReason::AccountTransfer,
+        FundingReason::DivisionMigration,
+        FundingReason::SiteMigration,
+        FundingReason::Adjustment,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FundingReason::ClientFunding => "CLIENT_FUNDING",
+            FundingReason::AccountTransfer => "ACCOUNT_TRANSFER",
+            FundingReason::DivisionMigration => "DIVISION_MIGRATION",
+            FundingReason::SiteMigration => "SITE_MIGRATION",
+            FundingReason::Adjustment => "ADJUSTMENT",
+        }
+    }
+}
+
 impl FromStr for FundingReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<FundingReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<FundingReason, ParseEnumError> {
         match s {
             "CLIENT_FUNDING" => Ok(FundingReason::ClientFunding),
             "ACCOUNT_TRANSFER" => Ok(FundingReason::AccountTransfer),
             "DIVISION_MIGRATION" => Ok(FundingReason::DivisionMigration),
             "SITE_MIGRATION" => Ok(FundingReason::SiteMigration),
             "ADJUSTMENT" => Ok(FundingReason::Adjustment),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("FundingReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for FundingReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ClientConfigureTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -3752,6 +3931,11 @@ pub struct ClientConfigureTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ClientConfigureTransaction {
     pub fn new() -> ClientConfigureTransaction {
@@ -3765,6 +3949,8 @@ impl ClientConfigureTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -3855,7 +4041,7 @@ impl ClientConfigureTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Account {
     /// The details all Account Positions.
     #[serde(default)]
@@ -4248,6 +4434,11 @@ pub struct Account {
     #[serde(default)]
     #[serde(rename = "orders", skip_serializing_if = "Option::is_none")]
     pub orders: Option<Vec<Order>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Account {
     pub fn new() -> Account {
@@ -4291,6 +4482,8 @@ impl Account {
             created_time: None,
             last_order_fill_timestamp: None,
             orders: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -4698,7 +4891,7 @@ impl Account {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TradeOpen {
     /// The ID of the Trade that was opened
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -4773,6 +4966,11 @@ pub struct TradeOpen {
         with = "serfloats"
     )]
     pub half_spread_cost: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TradeOpen {
     pub fn new() -> TradeOpen {
@@ -4784,6 +4982,8 @@ impl TradeOpen {
             units: None,
             client_extensions: None,
             half_spread_cost: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -4863,7 +5063,7 @@ impl TradeOpen {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MT4TransactionHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -4884,12 +5084,19 @@ pub struct MT4TransactionHeartbeat {
         with = "serdates"
     )]
     pub time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MT4TransactionHeartbeat {
     pub fn new() -> MT4TransactionHeartbeat {
         MT4TransactionHeartbeat {
             otype: None,
             time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -4918,7 +5125,7 @@ impl MT4TransactionHeartbeat {
 
 /// The reason that an Order was filled
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderFillReason {
     #[serde(rename = "LIMIT_ORDER")]
     LimitOrder,
@@ -4944,9 +5151,43 @@ pub enum OrderFillReason {
     MarketOrderDelayedTradeClose,
 }
 
+impl OrderFillReason {
+    /// Every variant of OrderFillReason, in definition order.
+    pub const ALL: &[OrderFillReason] = &[
+        OrderFillReason::LimitOrder,
+        OrderFillReason::StopOrder,
+        OrderFillReason::MarketIfTouchedOrder,
+        OrderFillReason::TakeProfitOrder,
+        OrderFillReason::StopLossOrder,
+        OrderFillReason::TrailingStopLossOrder,
+        OrderFillReason::MarketOrder,
+        OrderFillReason::MarketOrderTradeClose,
+        OrderFillReason::MarketOrderPositionCloseout,
+        OrderFillReason::MarketOrderMarginCloseout,
+        OrderFillReason::MarketOrderDelayedTradeClose,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderFillReason::LimitOrder => "LIMIT_ORDER",
+            OrderFillReason::StopOrder => "STOP_ORDER",
+            OrderFillReason::MarketIfTouchedOrder => "MARKET_IF_TOUCHED_ORDER",
+            OrderFillReason::TakeProfitOrder => "TAKE_PROFIT_ORDER",
+            OrderFillReason::StopLossOrder => "STOP_LOSS_ORDER",
+            OrderFillReason::TrailingStopLossOrder => "TRAILING_STOP_LOSS_ORDER",
+            OrderFillReason::MarketOrder => "MARKET_ORDER",
+            OrderFillReason::MarketOrderTradeClose => "MARKET_ORDER_TRADE_CLOSE",
+            OrderFillReason::MarketOrderPositionCloseout => "MARKET_ORDER_POSITION_CLOSEOUT",
+            OrderFillReason::MarketOrderMarginCloseout => "MARKET_ORDER_MARGIN_CLOSEOUT",
+            OrderFillReason::MarketOrderDelayedTradeClose => "MARKET_ORDER_DELAYED_TRADE_CLOSE",
+        }
+    }
+}
+
 impl FromStr for OrderFillReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderFillReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderFillReason, ParseEnumError> {
         match s {
             "LIMIT_ORDER" => Ok(OrderFillReason::LimitOrder),
             "STOP_ORDER" => Ok(OrderFillReason::StopOrder),
@@ -4959,18 +5200,18 @@ impl FromStr for OrderFillReason {
             "MARKET_ORDER_POSITION_CLOSEOUT" => Ok(OrderFillReason::MarketOrderPositionCloseout),
             "MARKET_ORDER_MARGIN_CLOSEOUT" => Ok(OrderFillReason::MarketOrderMarginCloseout),
             "MARKET_ORDER_DELAYED_TRADE_CLOSE" => Ok(OrderFillReason::MarketOrderDelayedTradeClose),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderFillReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderFillReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderDelayedTradeClose {
     /// The Transaction ID of the DelayedTradeClosure transaction to which
     /// this Delayed Trade Close belongs to
@@ -4997,6 +5238,11 @@ pub struct MarketOrderDelayedTradeClose {
     #[serde(default)]
     #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
     pub client_trade_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderDelayedTradeClose {
     pub fn new() -> MarketOrderDelayedTradeClose {
@@ -5004,6 +5250,8 @@ impl MarketOrderDelayedTradeClose {
             source_transaction_id: None,
             trade_id: None,
             client_trade_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5040,7 +5288,7 @@ impl MarketOrderDelayedTradeClose {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Candlestick {
     /// A flag indicating if the candlestick is complete. A complete
     /// candlestick is one whose ending time is not in the future.
@@ -5086,6 +5334,11 @@ pub struct Candlestick {
     #[serde(default)]
     #[serde(rename = "ask", skip_serializing_if = "Option::is_none")]
     pub ask: Option<CandlestickData>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Candlestick {
     pub fn new() -> Candlestick {
@@ -5096,6 +5349,8 @@ impl Candlestick {
             volume: None,
             time: None,
             ask: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5159,7 +5414,7 @@ impl Candlestick {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PositionSide {
     /// The total amount of financing paid/collected for this PositionSide
     /// over the lifetime of the Account.
@@ -5250,6 +5505,11 @@ pub struct PositionSide {
         with = "serfloats"
     )]
     pub pl: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PositionSide {
     pub fn new() -> PositionSide {
@@ -5262,6 +5522,8 @@ impl PositionSide {
             units: None,
             average_price: None,
             pl: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5352,7 +5614,7 @@ impl PositionSide {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Position {
     /// The total amount of financing paid/collected for this instrument over
     /// the lifetime of the Account.
@@ -5454,6 +5716,11 @@ pub struct Position {
         with = "serfloats"
     )]
     pub pl: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Position {
     pub fn new() -> Position {
@@ -5468,6 +5735,8 @@ impl Position {
             resettable_pl: None,
             guaranteed_execution_fees: None,
             pl: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5577,7 +5846,7 @@ impl Position {
 
 /// A filter that can be used when fetching Transactions
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionFilter {
     #[serde(rename = "ORDER")]
     Order,
@@ -5661,9 +5930,101 @@ pub enum TransactionFilter {
     ResetResettablePl,
 }
 
+impl TransactionFilter {
+    /// Every variant of TransactionFilter, in definition order.
+    pub const ALL: &[TransactionFilter] = &[
+        TransactionFilter::Order,
+        TransactionFilter::Funding,
+        TransactionFilter::Admin,
+        TransactionFilter::Create,
+        TransactionFilter::Close,
+        TransactionFilter::Reopen,
+        TransactionFilter::ClientConfigure,
+        TransactionFilter::ClientConfigureReject,
+        TransactionFilter::TransferFunds,
+        TransactionFilter::TransferFundsReject,
+        TransactionFilter::MarketOrder,
+        TransactionFilter::MarketOrderReject,
+        TransactionFilter::LimitOrder,
+        TransactionFilter::LimitOrderReject,
+        TransactionFilter::StopOrder,
+        TransactionFilter::StopOrderReject,
+        TransactionFilter::MarketIfTouchedOrder,
+        TransactionFilter::MarketIfTouchedOrderReject,
+        TransactionFilter::TakeProfitOrder,
+        TransactionFilter::TakeProfitOrderReject,
+        TransactionFilter::StopLossOrder,
+        TransactionFilter::StopLossOrderReject,
+        TransactionFilter::TrailingStopLossOrder,
+        TransactionFilter::TrailingStopLossOrderReject,
+        TransactionFilter::OneCancelsAllOrder,
+        TransactionFilter::OneCancelsAllOrderReject,
+        TransactionFilter::OneCancelsAllOrderTriggered,
+        TransactionFilter::OrderFill,
+        TransactionFilter::OrderCancel,
+        TransactionFilter::OrderCancelReject,
+        TransactionFilter::OrderClientExtensionsModify,
+        TransactionFilter::OrderClientExtensionsModifyReject,
+        TransactionFilter::TradeClientExtensionsModify,
+        TransactionFilter::TradeClientExtensionsModifyReject,
+        TransactionFilter::MarginCallEnter,
+        TransactionFilter::MarginCallExtend,
+        TransactionFilter::MarginCallExit,
+        TransactionFilter::DelayedTradeClosure,
+        TransactionFilter::DailyFinancing,
+        TransactionFilter::ResetResettablePl,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionFilter::Order => "ORDER",
+            TransactionFilter::Funding => "FUNDING",
+            TransactionFilter::Admin => "ADMIN",
+            TransactionFilter::Create => "CREATE",
+            TransactionFilter::Close => "CLOSE",
+            TransactionFilter::Reopen => "REOPEN",
+            TransactionFilter::ClientConfigure => "CLIENT_CONFIGURE",
+            TransactionFilter::ClientConfigureReject => "CLIENT_CONFIGURE_REJECT",
+            TransactionFilter::TransferFunds => "TRANSFER_FUNDS",
+            TransactionFilter::TransferFundsReject => "TRANSFER_FUNDS_REJECT",
+            TransactionFilter::MarketOrder => "MARKET_ORDER",
+            TransactionFilter::MarketOrderReject => "MARKET_ORDER_REJECT",
+            TransactionFilter::LimitOrder => "LIMIT_ORDER",
+            TransactionFilter::LimitOrderReject => "LIMIT_ORDER_REJECT",
+            TransactionFilter::StopOrder => "STOP_ORDER",
+            TransactionFilter::StopOrderReject => "STOP_ORDER_REJECT",
+            TransactionFilter::MarketIfTouchedOrder => "MARKET_IF_TOUCHED_ORDER",
+            TransactionFilter::MarketIfTouchedOrderReject => "MARKET_IF_TOUCHED_ORDER_REJECT",
+            TransactionFilter::TakeProfitOrder => "TAKE_PROFIT_ORDER",
+            TransactionFilter::TakeProfitOrderReject => "TAKE_PROFIT_ORDER_REJECT",
+            TransactionFilter::StopLossOrder => "STOP_LOSS_ORDER",
+            TransactionFilter::StopLossOrderReject => "STOP_LOSS_ORDER_REJECT",
+            TransactionFilter::TrailingStopLossOrder => "TRAILING_STOP_LOSS_ORDER",
+            TransactionFilter::TrailingStopLossOrderReject => "TRAILING_STOP_LOSS_ORDER_REJECT",
+            TransactionFilter::OneCancelsAllOrder => "ONE_CANCELS_ALL_ORDER",
+            TransactionFilter::OneCancelsAllOrderReject => "ONE_CANCELS_ALL_ORDER_REJECT",
+            TransactionFilter::OneCancelsAllOrderTriggered => "ONE_CANCELS_ALL_ORDER_TRIGGERED",
+            TransactionFilter::OrderFill => "ORDER_FILL",
+            TransactionFilter::OrderCancel => "ORDER_CANCEL",
+            TransactionFilter::OrderCancelReject => "ORDER_CANCEL_REJECT",
+            TransactionFilter::OrderClientExtensionsModify => "ORDER_CLIENT_EXTENSIONS_MODIFY",
+            TransactionFilter::OrderClientExtensionsModifyReject => "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT",
+            TransactionFilter::TradeClientExtensionsModify => "TRADE_CLIENT_EXTENSIONS_MODIFY",
+            TransactionFilter::TradeClientExtensionsModifyReject => "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT",
+            TransactionFilter::MarginCallEnter => "MARGIN_CALL_ENTER",
+            TransactionFilter::MarginCallExtend => "MARGIN_CALL_EXTEND",
+            TransactionFilter::MarginCallExit => "MARGIN_CALL_EXIT",
+            TransactionFilter::DelayedTradeClosure => "DELAYED_TRADE_CLOSURE",
+            TransactionFilter::DailyFinancing => "DAILY_FINANCING",
+            TransactionFilter::ResetResettablePl => "RESET_RESETTABLE_PL",
+        }
+    }
+}
+
 impl FromStr for TransactionFilter {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TransactionFilter, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TransactionFilter, ParseEnumError> {
         match s {
             "ORDER" => Ok(TransactionFilter::Order),
             "FUNDING" => Ok(TransactionFilter::Funding),
@@ -5696,61 +6057,75 @@ impl FromStr for TransactionFilter {
             "ORDER_CANCEL" => Ok(TransactionFilter::OrderCancel),
             "ORDER_CANCEL_REJECT" => Ok(TransactionFilter::OrderCancelReject),
             "ORDER_CLIENT_EXTENSIONS_MODIFY" => Ok(TransactionFilter::OrderClientExtensionsModify),
-            "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT" => {
-                Ok(TransactionFilter::OrderClientExtensionsModifyReject)
-            }
+            "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT" => Ok(TransactionFilter::OrderClientExtensionsModifyReject),
             "TRADE_CLIENT_EXTENSIONS_MODIFY" => Ok(TransactionFilter::TradeClientExtensionsModify),
-            "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT" => {
-                Ok(TransactionFilter::TradeClientExtensionsModifyReject)
-            }
+            "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT" => Ok(TransactionFilter::TradeClientExtensionsModifyReject),
             "MARGIN_CALL_ENTER" => Ok(TransactionFilter::MarginCallEnter),
             "MARGIN_CALL_EXTEND" => Ok(TransactionFilter::MarginCallExtend),
             "MARGIN_CALL_EXIT" => Ok(TransactionFilter::MarginCallExit),
             "DELAYED_TRADE_CLOSURE" => Ok(TransactionFilter::DelayedTradeClosure),
             "DAILY_FINANCING" => Ok(TransactionFilter::DailyFinancing),
             "RESET_RESETTABLE_PL" => Ok(TransactionFilter::ResetResettablePl),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TransactionFilter", s)),
         }
     }
 }
 
 impl std::fmt::Display for TransactionFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The status of the Price.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PriceStatus {
     #[serde(rename = "tradeable")]
     Tradeable,
-    #[serde(rename = "nontradeable")]
+    #[serde(rename = "non-tradeable")]
     Nontradeable,
     #[serde(rename = "invalid")]
     Invalid,
 }
 
+impl PriceStatus {
+    /// Every variant of PriceStatus, in definition order.
+    pub const ALL: &[PriceStatus] = &[
+        PriceStatus::Tradeable,
+        PriceStatus::Nontradeable,
+        PriceStatus::Invalid,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceStatus::Tradeable => "tradeable",
+            PriceStatus::Nontradeable => "non-tradeable",
+            PriceStatus::Invalid => "invalid",
+        }
+    }
+}
+
 impl FromStr for PriceStatus {
-    type Err = ();
-    fn from_str(s: &str) -> Result<PriceStatus, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<PriceStatus, ParseEnumError> {
         match s {
             "tradeable" => Ok(PriceStatus::Tradeable),
-            "nontradeable" => Ok(PriceStatus::Nontradeable),
+            "non-tradeable" => Ok(PriceStatus::Nontradeable),
             "invalid" => Ok(PriceStatus::Invalid),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("PriceStatus", s)),
         }
     }
 }
 
 impl std::fmt::Display for PriceStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct DynamicOrderState {
     /// The distance between the Trailing Stop Loss Order's trailingStopValue
     /// and the current Market Price. This represents the distance (in price
@@ -5794,6 +6169,11 @@ pub struct DynamicOrderState {
         with = "serfloats"
     )]
     pub trailing_stop_value: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl DynamicOrderState {
     pub fn new() -> DynamicOrderState {
@@ -5802,6 +6182,8 @@ impl DynamicOrderState {
             is_trigger_distance_exact: None,
             id: None,
             trailing_stop_value: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5850,7 +6232,7 @@ impl DynamicOrderState {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ResetResettablePLTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -5902,6 +6284,11 @@ pub struct ResetResettablePLTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ResetResettablePLTransaction {
     pub fn new() -> ResetResettablePLTransaction {
@@ -5913,6 +6300,8 @@ impl ResetResettablePLTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -5985,7 +6374,7 @@ impl ResetResettablePLTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct DailyFinancingTransaction {
     /// The amount of financing paid/collected for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -6072,6 +6461,11 @@ pub struct DailyFinancingTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl DailyFinancingTransaction {
     pub fn new() -> DailyFinancingTransaction {
@@ -6087,6 +6481,8 @@ impl DailyFinancingTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -6195,7 +6591,7 @@ impl DailyFinancingTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderLevelRestriction {
     /// Applies to Trades with a guaranteed Stop Loss Order attached for the
     /// specified Instrument. This is the total allowed Trade volume that can
@@ -6221,12 +6617,19 @@ pub struct GuaranteedStopLossOrderLevelRestriction {
         with = "serfloats"
     )]
     pub price_range: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl GuaranteedStopLossOrderLevelRestriction {
     pub fn new() -> GuaranteedStopLossOrderLevelRestriction {
         GuaranteedStopLossOrderLevelRestriction {
             volume: None,
             price_range: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -6254,7 +6657,7 @@ impl GuaranteedStopLossOrderLevelRestriction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderClientExtensionsModifyRejectTransaction {
     /// The ID of the Order who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -6346,6 +6749,11 @@ pub struct OrderClientExtensionsModifyRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderClientExtensionsModifyRejectTransaction {
     pub fn new() -> OrderClientExtensionsModifyRejectTransaction {
@@ -6362,6 +6770,8 @@ impl OrderClientExtensionsModifyRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -6483,7 +6893,7 @@ impl OrderClientExtensionsModifyRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct HomeConversions {
     /// The currency to be converted into the home currency.
     /// format: A string containing an ISO 4217 currency
@@ -6529,6 +6939,11 @@ pub struct HomeConversions {
         with = "serfloats"
     )]
     pub account_loss: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl HomeConversions {
     pub fn new() -> HomeConversions {
@@ -6537,6 +6952,8 @@ impl HomeConversions {
             account_gain: None,
             position_value: None,
             account_loss: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -6586,16 +7003,25 @@ impl HomeConversions {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderMarginCloseout {
     /// The reason the Market Order was created to perform a margin closeout
     #[serde(default)]
     #[serde(rename = "reason", skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderMarginCloseout {
     pub fn new() -> MarketOrderMarginCloseout {
-        MarketOrderMarginCloseout { reason: None }
+        MarketOrderMarginCloseout {
+            reason: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
     }
 
     /// The reason the Market Order was created to perform a margin closeout
@@ -6607,7 +7033,7 @@ impl MarketOrderMarginCloseout {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TakeProfitOrderTransaction {
     /// The time-in-force requested for the TakeProfit Order. Restricted to
     /// "GTC", "GFD" and "GTD" for TakeProfit Orders.
@@ -6772,6 +7198,11 @@ pub struct TakeProfitOrderTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TakeProfitOrderTransaction {
     pub fn new() -> TakeProfitOrderTransaction {
@@ -6794,6 +7225,8 @@ impl TakeProfitOrderTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -6998,7 +7431,7 @@ impl TakeProfitOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderCancelTransaction {
     /// The ID of the Order cancelled
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -7081,6 +7514,11 @@ pub struct OrderCancelTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderCancelTransaction {
     pub fn new() -> OrderCancelTransaction {
@@ -7096,6 +7534,8 @@ impl OrderCancelTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -7211,7 +7651,7 @@ impl OrderCancelTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct AccountSummary {
     /// The date/time that the Account's resettablePL was last reset.
     /// format: The RFC 3339 representation is a string conforming to
@@ -7589,6 +8029,11 @@ pub struct AccountSummary {
         with = "serdates"
     )]
     pub last_order_fill_timestamp: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl AccountSummary {
     pub fn new() -> AccountSummary {
@@ -7629,6 +8074,8 @@ impl AccountSummary {
             margin_closeout_unrealized_pl: None,
             created_time: None,
             last_order_fill_timestamp: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -8012,7 +8459,7 @@ impl AccountSummary {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -8162,6 +8609,11 @@ pub struct MarketIfTouchedOrderRequest {
     #[serde(default)]
     #[serde(rename = "positionFill", skip_serializing_if = "Option::is_none")]
     pub position_fill: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketIfTouchedOrderRequest {
     pub fn new() -> MarketIfTouchedOrderRequest {
@@ -8180,6 +8632,8 @@ impl MarketIfTouchedOrderRequest {
             gtd_time: None,
             otype: None,
             position_fill: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -8353,7 +8807,7 @@ impl MarketIfTouchedOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct AccountChanges {
     /// The Trades reduced.
     #[serde(default)]
@@ -8400,6 +8854,11 @@ pub struct AccountChanges {
     #[serde(default)]
     #[serde(rename = "tradesClosed", skip_serializing_if = "Option::is_none")]
     pub trades_closed: Option<Vec<TradeSummary>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl AccountChanges {
     pub fn new() -> AccountChanges {
@@ -8413,6 +8872,8 @@ impl AccountChanges {
             orders_triggered: None,
             orders_cancelled: None,
             trades_closed: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -8490,7 +8951,7 @@ impl AccountChanges {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TakeProfitOrderRejectTransaction {
     /// The time-in-force requested for the TakeProfit Order. Restricted to
     /// "GTC", "GFD" and "GTD" for TakeProfit Orders.
@@ -8653,6 +9114,11 @@ pub struct TakeProfitOrderRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TakeProfitOrderRejectTransaction {
     pub fn new() -> TakeProfitOrderRejectTransaction {
@@ -8675,6 +9141,8 @@ impl TakeProfitOrderRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -8877,7 +9345,7 @@ impl TakeProfitOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TakeProfitOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -8962,6 +9430,11 @@ pub struct TakeProfitOrderRequest {
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TakeProfitOrderRequest {
     pub fn new() -> TakeProfitOrderRequest {
@@ -8974,6 +9447,8 @@ impl TakeProfitOrderRequest {
             client_trade_id: None,
             gtd_time: None,
             otype: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -9078,7 +9553,7 @@ impl TakeProfitOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CreateTransaction {
     /// The ID of the user that the Account was created for
     #[serde(default)]
@@ -9157,6 +9632,11 @@ pub struct CreateTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CreateTransaction {
     pub fn new() -> CreateTransaction {
@@ -9173,6 +9653,8 @@ impl CreateTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -9287,7 +9769,7 @@ impl CreateTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopLossDetails {
     /// Specifies the distance (in price units) from the Trade's open price to
     /// use as the Stop Loss Order price. Only one of the distance and price
@@ -9350,6 +9832,11 @@ pub struct StopLossDetails {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopLossDetails {
     pub fn new() -> StopLossDetails {
@@ -9360,6 +9847,8 @@ impl StopLossDetails {
             guaranteed: None,
             time_in_force: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -9432,7 +9921,7 @@ impl StopLossDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarginCallExitTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -9484,6 +9973,11 @@ pub struct MarginCallExitTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarginCallExitTransaction {
     pub fn new() -> MarginCallExitTransaction {
@@ -9495,6 +9989,8 @@ impl MarginCallExitTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -9569,7 +10065,7 @@ impl MarginCallExitTransaction {
 
 /// The reason that the Take Profit Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TakeProfitOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -9579,28 +10075,46 @@ pub enum TakeProfitOrderReason {
     OnFill,
 }
 
+impl TakeProfitOrderReason {
+    /// Every variant of TakeProfitOrderReason, in definition order.
+    pub const ALL: &[TakeProfitOrderReason] = &[
+        TakeProfitOrderReason::ClientOrder,
+        TakeProfitOrderReason::Replacement,
+        TakeProfitOrderReason::OnFill,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TakeProfitOrderReason::ClientOrder => "CLIENT_ORDER",
+            TakeProfitOrderReason::Replacement => "REPLACEMENT",
+            TakeProfitOrderReason::OnFill => "ON_FILL",
+        }
+    }
+}
+
 impl FromStr for TakeProfitOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TakeProfitOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TakeProfitOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(TakeProfitOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(TakeProfitOrderReason::Replacement),
             "ON_FILL" => Ok(TakeProfitOrderReason::OnFill),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TakeProfitOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for TakeProfitOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The overall behaviour of the Account regarding guaranteed Stop Loss
 /// Orders.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GuaranteedStopLossOrderMode {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -9610,27 +10124,45 @@ pub enum GuaranteedStopLossOrderMode {
     Required,
 }
 
+impl GuaranteedStopLossOrderMode {
+    /// Every variant of GuaranteedStopLossOrderMode, in definition order.
+    pub const ALL: &[GuaranteedStopLossOrderMode] = &[
+        GuaranteedStopLossOrderMode::Disabled,
+        GuaranteedStopLossOrderMode::Allowed,
+        GuaranteedStopLossOrderMode::Required,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GuaranteedStopLossOrderMode::Disabled => "DISABLED",
+            GuaranteedStopLossOrderMode::Allowed => "ALLOWED",
+            GuaranteedStopLossOrderMode::Required => "REQUIRED",
+        }
+    }
+}
+
 impl FromStr for GuaranteedStopLossOrderMode {
-    type Err = ();
-    fn from_str(s: &str) -> Result<GuaranteedStopLossOrderMode, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<GuaranteedStopLossOrderMode, ParseEnumError> {
         match s {
             "DISABLED" => Ok(GuaranteedStopLossOrderMode::Disabled),
             "ALLOWED" => Ok(GuaranteedStopLossOrderMode::Allowed),
             "REQUIRED" => Ok(GuaranteedStopLossOrderMode::Required),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("GuaranteedStopLossOrderMode", s)),
         }
     }
 }
 
 impl std::fmt::Display for GuaranteedStopLossOrderMode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The state to filter the requested Orders by.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderStateFilter {
     #[serde(rename = "PENDING")]
     Pending,
@@ -9644,27 +10176,49 @@ pub enum OrderStateFilter {
     All,
 }
 
+impl OrderStateFilter {
+    /// Every variant of OrderStateFilter, in definition order.
+    pub const ALL: &[OrderStateFilter] = &[
+        OrderStateFilter::Pending,
+        OrderStateFilter::Filled,
+        OrderStateFilter::Triggered,
+        OrderStateFilter::Cancelled,
+        OrderStateFilter::All,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStateFilter::Pending => "PENDING",
+            OrderStateFilter::Filled => "FILLED",
+            OrderStateFilter::Triggered => "TRIGGERED",
+            OrderStateFilter::Cancelled => "CANCELLED",
+            OrderStateFilter::All => "ALL",
+        }
+    }
+}
+
 impl FromStr for OrderStateFilter {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderStateFilter, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderStateFilter, ParseEnumError> {
         match s {
             "PENDING" => Ok(OrderStateFilter::Pending),
             "FILLED" => Ok(OrderStateFilter::Filled),
             "TRIGGERED" => Ok(OrderStateFilter::Triggered),
             "CANCELLED" => Ok(OrderStateFilter::Cancelled),
             "ALL" => Ok(OrderStateFilter::All),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderStateFilter", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderStateFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderTransaction {
     /// A MarketOrderPositionCloseout specifies the extensions to a Market
     /// Order when it has been created to closeout a specific Position.
@@ -9849,6 +10403,11 @@ pub struct MarketOrderTransaction {
     #[serde(default)]
     #[serde(rename = "marginCloseout", skip_serializing_if = "Option::is_none")]
     pub margin_closeout: Option<MarketOrderMarginCloseout>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderTransaction {
     pub fn new() -> MarketOrderTransaction {
@@ -9876,6 +10435,8 @@ impl MarketOrderTransaction {
             trade_client_extensions: None,
             time: None,
             margin_closeout: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10109,17 +10670,25 @@ impl MarketOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct OrderRequest {}
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct OrderRequest {
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
 impl OrderRequest {
     pub fn new() -> OrderRequest {
-        OrderRequest {}
+        OrderRequest {
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
     }
 }
 
 /// The reason that the Limit Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LimitOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -10127,26 +10696,42 @@ pub enum LimitOrderReason {
     Replacement,
 }
 
+impl LimitOrderReason {
+    /// Every variant of LimitOrderReason, in definition order.
+    pub const ALL: &[LimitOrderReason] = &[
+        LimitOrderReason::ClientOrder,
+        LimitOrderReason::Replacement,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LimitOrderReason::ClientOrder => "CLIENT_ORDER",
+            LimitOrderReason::Replacement => "REPLACEMENT",
+        }
+    }
+}
+
 impl FromStr for LimitOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<LimitOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<LimitOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(LimitOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(LimitOrderReason::Replacement),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("LimitOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for LimitOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The current state of the Trade.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TradeState {
     #[serde(rename = "OPEN")]
     Open,
@@ -10156,28 +10741,46 @@ pub enum TradeState {
     CloseWhenTradeable,
 }
 
+impl TradeState {
+    /// Every variant of TradeState, in definition order.
+    pub const ALL: &[TradeState] = &[
+        TradeState::Open,
+        TradeState::Closed,
+        TradeState::CloseWhenTradeable,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeState::Open => "OPEN",
+            TradeState::Closed => "CLOSED",
+            TradeState::CloseWhenTradeable => "CLOSE_WHEN_TRADEABLE",
+        }
+    }
+}
+
 impl FromStr for TradeState {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TradeState, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TradeState, ParseEnumError> {
         match s {
             "OPEN" => Ok(TradeState::Open),
             "CLOSED" => Ok(TradeState::Closed),
             "CLOSE_WHEN_TRADEABLE" => Ok(TradeState::CloseWhenTradeable),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TradeState", s)),
         }
     }
 }
 
 impl std::fmt::Display for TradeState {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The reason that the Market Order was created to perform a margin
 /// closeout
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MarketOrderMarginCloseoutReason {
     #[serde(rename = "MARGIN_CHECK_VIOLATION")]
     MarginCheckViolation,
@@ -10187,29 +10790,43 @@ pub enum MarketOrderMarginCloseoutReason {
     RegulatoryMarginCheckViolation,
 }
 
+impl MarketOrderMarginCloseoutReason {
+    /// Every variant of MarketOrderMarginCloseoutReason, in definition order.
+    pub const ALL: &[MarketOrderMarginCloseoutReason] = &[
+        MarketOrderMarginCloseoutReason::MarginCheckViolation,
+        MarketOrderMarginCloseoutReason::RegulatoryMarginCallViolation,
+        MarketOrderMarginCloseoutReason::RegulatoryMarginCheckViolation,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketOrderMarginCloseoutReason::MarginCheckViolation => "MARGIN_CHECK_VIOLATION",
+            MarketOrderMarginCloseoutReason::RegulatoryMarginCallViolation => "REGULATORY_MARGIN_CALL_VIOLATION",
+            MarketOrderMarginCloseoutReason::RegulatoryMarginCheckViolation => "REGULATORY_MARGIN_CHECK_VIOLATION",
+        }
+    }
+}
+
 impl FromStr for MarketOrderMarginCloseoutReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<MarketOrderMarginCloseoutReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<MarketOrderMarginCloseoutReason, ParseEnumError> {
         match s {
             "MARGIN_CHECK_VIOLATION" => Ok(MarketOrderMarginCloseoutReason::MarginCheckViolation),
-            "REGULATORY_MARGIN_CALL_VIOLATION" => {
-                Ok(MarketOrderMarginCloseoutReason::RegulatoryMarginCallViolation)
-            }
-            "REGULATORY_MARGIN_CHECK_VIOLATION" => {
-                Ok(MarketOrderMarginCloseoutReason::RegulatoryMarginCheckViolation)
-            }
-            _ => Err(()),
+            "REGULATORY_MARGIN_CALL_VIOLATION" => Ok(MarketOrderMarginCloseoutReason::RegulatoryMarginCallViolation),
+            "REGULATORY_MARGIN_CHECK_VIOLATION" => Ok(MarketOrderMarginCloseoutReason::RegulatoryMarginCheckViolation),
+            _ => Err(ParseEnumError::new("MarketOrderMarginCloseoutReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for MarketOrderMarginCloseoutReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Transaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -10255,6 +10872,103 @@ pub struct Transaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// The Type of the Transaction.
+    #[serde(default)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub otype: Option<String>,
+
+    /// The ID of the Order the Transaction is about.
+    /// format: The string representation of the OANDA-assigned OrderID. OANDA-
+    /// assigned OrderIDs are positive integers, and are derived from the
+    /// TransactionID of the Transaction that created the Order.
+    #[serde(default)]
+    #[serde(rename = "orderID", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+
+    /// The client Order ID of that Order (only provided if the client has
+    /// assigned one).
+    #[serde(default)]
+    #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+
+    /// The instrument of the Order or Trade.
+    /// format: A string containing the base currency and quote currency delimited by
+    /// a "_".
+    #[serde(default)]
+    #[serde(rename = "instrument", skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<String>,
+
+    /// The units of the Order, or the number of units filled.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    #[serde(default)]
+    #[serde(
+        rename = "units",
+        skip_serializing_if = "Option::is_none",
+        with = "serfloats"
+    )]
+    pub units: Option<f32>,
+
+    /// The price of the Order, or the price it was filled at.
+    /// format: A decimal number encodes as a string. The amount of precision provided
+    /// depends on the Instrument.
+    #[serde(default)]
+    #[serde(
+        rename = "price",
+        skip_serializing_if = "Option::is_none",
+        with = "serfloats"
+    )]
+    pub price: Option<f32>,
+
+    /// The Account's balance after the Transaction, for the types that change
+    /// it.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on the Account's home currency.
+    #[serde(default)]
+    #[serde(
+        rename = "accountBalance",
+        skip_serializing_if = "Option::is_none",
+        with = "serdoubles"
+    )]
+    pub account_balance: Option<f64>,
+
+    /// A TradeOpen object represents a Trade for an instrument that was
+    /// opened in an Account. It is found embedded in Transactions that affect
+    /// the position of an instrument in the Account, specifically the
+    /// OrderFill Transaction.
+    #[serde(default)]
+    #[serde(rename = "tradeOpened", skip_serializing_if = "Option::is_none")]
+    pub trade_opened: Option<TradeOpen>,
+
+    /// A TradeReduce object represents a Trade for an instrument that was
+    /// reduced (either partially or fully) in an Account. It is found
+    /// embedded in Transactions that affect the position of an instrument in
+    /// the account, specifically the OrderFill Transaction.
+    #[serde(default)]
+    #[serde(rename = "tradeReduced", skip_serializing_if = "Option::is_none")]
+    pub trade_reduced: Option<TradeReduce>,
+
+    /// The Trades that were closed when the Order was filled (only provided
+    /// if filling the Order resulted in a closing open Trades).
+    #[serde(default)]
+    #[serde(rename = "tradesClosed", skip_serializing_if = "Option::is_none")]
+    pub trades_closed: Option<Vec<TradeReduce>>,
+
+    /// The reason the Transaction was created, for the types that have one.
+    #[serde(default)]
+    #[serde(rename = "reason", skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// The reason a Reject Transaction was created.
+    #[serde(default)]
+    #[serde(rename = "rejectReason", skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Transaction {
     pub fn new() -> Transaction {
@@ -10265,6 +10979,20 @@ impl Transaction {
             time: None,
             id: None,
             account_id: None,
+            otype: None,
+            order_id: None,
+            client_order_id: None,
+            instrument: None,
+            units: None,
+            price: None,
+            account_balance: None,
+            trade_opened: None,
+            trade_reduced: None,
+            trades_closed: None,
+            reason: None,
+            reject_reason: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10289,46 +11017,162 @@ impl Transaction {
     /// The Request ID of the request which generated the transaction.
     /// - param String
     /// - return Transaction
-    pub fn with_request_id(mut self, x: String) -> Self {
-        self.request_id = Some(x);
+    pub fn with_request_id(mut self, x: String) -> Self {
+        self.request_id = Some(x);
+        self
+    }
+
+    /// The date/time when the Transaction was created.
+    /// format: The RFC 3339 representation is a string conforming to
+    /// https://tools.ietf.org/rfc/rfc3339.txt. The Unix representation is a
+    /// string representing the number of seconds since the Unix Epoch
+    /// (January 1st, 1970 at UTC). The value is a fractional number, where
+    /// the fractional part represents a fraction of a second (up to nine
+    /// decimal places).
+    /// - param DateTime<Utc>
+    /// - return Transaction
+    pub fn with_time(mut self, x: DateTime<Utc>) -> Self {
+        self.time = Some(x);
+        self
+    }
+
+    /// The Transaction's Identifier.
+    /// format: String representation of the numerical OANDA-assigned TransactionID
+    /// - param String
+    /// - return Transaction
+    pub fn with_id(mut self, x: String) -> Self {
+        self.id = Some(x);
+        self
+    }
+
+    /// The ID of the Account the Transaction was created for.
+    /// format: "-"-delimited string with format
+    /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
+    /// - param String
+    /// - return Transaction
+    pub fn with_account_id(mut self, x: String) -> Self {
+        self.account_id = Some(x);
+        self
+    }
+
+    /// The Type of the Transaction.
+    /// - param String
+    /// - return Transaction
+    pub fn with_otype(mut self, x: String) -> Self {
+        self.otype = Some(x);
+        self
+    }
+
+    /// The ID of the Order the Transaction is about.
+    /// format: The string representation of the OANDA-assigned OrderID. OANDA-
+    /// assigned OrderIDs are positive integers, and are derived from the
+    /// TransactionID of the Transaction that created the Order.
+    /// - param String
+    /// - return Transaction
+    pub fn with_order_id(mut self, x: String) -> Self {
+        self.order_id = Some(x);
+        self
+    }
+
+    /// The client Order ID of that Order (only provided if the client has
+    /// assigned one).
+    /// - param String
+    /// - return Transaction
+    pub fn with_client_order_id(mut self, x: String) -> Self {
+        self.client_order_id = Some(x);
+        self
+    }
+
+    /// The instrument of the Order or Trade.
+    /// format: A string containing the base currency and quote currency delimited by
+    /// a "_".
+    /// - param String
+    /// - return Transaction
+    pub fn with_instrument(mut self, x: String) -> Self {
+        self.instrument = Some(x);
+        self
+    }
+
+    /// The units of the Order, or the number of units filled.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    /// - param f32
+    /// - return Transaction
+    pub fn with_units(mut self, x: f32) -> Self {
+        self.units = Some(x);
+        self
+    }
+
+    /// The price of the Order, or the price it was filled at.
+    /// format: A decimal number encodes as a string. The amount of precision provided
+    /// depends on the Instrument.
+    /// - param f32
+    /// - return Transaction
+    pub fn with_price(mut self, x: f32) -> Self {
+        self.price = Some(x);
+        self
+    }
+
+    /// The Account's balance after the Transaction, for the types that change
+    /// it.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on the Account's home currency.
+    /// - param f64
+    /// - return Transaction
+    pub fn with_account_balance(mut self, x: f64) -> Self {
+        self.account_balance = Some(x);
+        self
+    }
+
+    /// A TradeOpen object represents a Trade for an instrument that was
+    /// opened in an Account. It is found embedded in Transactions that affect
+    /// the position of an instrument in the Account, specifically the
+    /// OrderFill Transaction.
+    /// - param TradeOpen
+    /// - return Transaction
+    pub fn with_trade_opened(mut self, x: TradeOpen) -> Self {
+        self.trade_opened = Some(x);
+        self
+    }
+
+    /// A TradeReduce object represents a Trade for an instrument that was
+    /// reduced (either partially or fully) in an Account. It is found
+    /// embedded in Transactions that affect the position of an instrument in
+    /// the account, specifically the OrderFill Transaction.
+    /// - param TradeReduce
+    /// - return Transaction
+    pub fn with_trade_reduced(mut self, x: TradeReduce) -> Self {
+        self.trade_reduced = Some(x);
         self
     }
 
-    /// The date/time when the Transaction was created.
-    /// format: The RFC 3339 representation is a string conforming to
-    /// https://tools.ietf.org/rfc/rfc3339.txt. The Unix representation is a
-    /// string representing the number of seconds since the Unix Epoch
-    /// (January 1st, 1970 at UTC). The value is a fractional number, where
-    /// the fractional part represents a fraction of a second (up to nine
-    /// decimal places).
-    /// - param DateTime<Utc>
+    /// The Trades that were closed when the Order was filled (only provided
+    /// if filling the Order resulted in a closing open Trades).
+    /// - param Vec<TradeReduce>
     /// - return Transaction
-    pub fn with_time(mut self, x: DateTime<Utc>) -> Self {
-        self.time = Some(x);
+    pub fn with_trades_closed(mut self, x: Vec<TradeReduce>) -> Self {
+        self.trades_closed = Some(x);
         self
     }
 
-    /// The Transaction's Identifier.
-    /// format: String representation of the numerical OANDA-assigned TransactionID
+    /// The reason the Transaction was created, for the types that have one.
     /// - param String
     /// - return Transaction
-    pub fn with_id(mut self, x: String) -> Self {
-        self.id = Some(x);
+    pub fn with_reason(mut self, x: String) -> Self {
+        self.reason = Some(x);
         self
     }
 
-    /// The ID of the Account the Transaction was created for.
-    /// format: "-"-delimited string with format
-    /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
+    /// The reason a Reject Transaction was created.
     /// - param String
     /// - return Transaction
-    pub fn with_account_id(mut self, x: String) -> Self {
-        self.account_id = Some(x);
+    pub fn with_reject_reason(mut self, x: String) -> Self {
+        self.reject_reason = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct QuoteHomeConversionFactors {
     /// The factor used to convert a negative amount of the Price's
     /// Instrument's quote currency into a negative amount of the Account's
@@ -10357,12 +11201,19 @@ pub struct QuoteHomeConversionFactors {
         with = "serfloats"
     )]
     pub positive_units: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl QuoteHomeConversionFactors {
     pub fn new() -> QuoteHomeConversionFactors {
         QuoteHomeConversionFactors {
             negative_units: None,
             positive_units: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10393,7 +11244,7 @@ impl QuoteHomeConversionFactors {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderBookBucket {
     /// The lowest price (inclusive) covered by the bucket. The bucket covers
     /// the price range from the price to price + the order book's
@@ -10431,6 +11282,11 @@ pub struct OrderBookBucket {
         with = "serfloats"
     )]
     pub long_count_percent: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderBookBucket {
     pub fn new() -> OrderBookBucket {
@@ -10438,6 +11294,8 @@ impl OrderBookBucket {
             price: None,
             short_count_percent: None,
             long_count_percent: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10478,29 +11336,43 @@ impl OrderBookBucket {
 
 /// The reason that the Fixed Price Order was created
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FixedPriceOrderReason {
     #[serde(rename = "PLATFORM_ACCOUNT_MIGRATION")]
     PlatformAccountMigration,
 }
 
+impl FixedPriceOrderReason {
+    /// Every variant of FixedPriceOrderReason, in definition order.
+    pub const ALL: &[FixedPriceOrderReason] = &[
+        FixedPriceOrderReason::PlatformAccountMigration,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FixedPriceOrderReason::PlatformAccountMigration => "PLATFORM_ACCOUNT_MIGRATION",
+        }
+    }
+}
+
 impl FromStr for FixedPriceOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<FixedPriceOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<FixedPriceOrderReason, ParseEnumError> {
         match s {
             "PLATFORM_ACCOUNT_MIGRATION" => Ok(FixedPriceOrderReason::PlatformAccountMigration),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("FixedPriceOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for FixedPriceOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TransferFundsTransaction {
     /// An optional comment that may be attached to a fund transfer for audit
     /// purposes
@@ -10587,6 +11459,11 @@ pub struct TransferFundsTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TransferFundsTransaction {
     pub fn new() -> TransferFundsTransaction {
@@ -10602,6 +11479,8 @@ impl TransferFundsTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10713,7 +11592,7 @@ impl TransferFundsTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderEntryData {
     /// A GuaranteedStopLossOrderLevelRestriction represents the total
     /// position size that can exist within a given price window for Trades
@@ -10747,6 +11626,11 @@ pub struct GuaranteedStopLossOrderEntryData {
         with = "serfloats"
     )]
     pub premium: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl GuaranteedStopLossOrderEntryData {
     pub fn new() -> GuaranteedStopLossOrderEntryData {
@@ -10754,6 +11638,8 @@ impl GuaranteedStopLossOrderEntryData {
             level_restriction: None,
             minimum_distance: None,
             premium: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -10792,7 +11678,7 @@ impl GuaranteedStopLossOrderEntryData {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopLossOrderRequest {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -10899,6 +11785,11 @@ pub struct StopLossOrderRequest {
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopLossOrderRequest {
     pub fn new() -> StopLossOrderRequest {
@@ -10913,6 +11804,8 @@ impl StopLossOrderRequest {
             client_trade_id: None,
             gtd_time: None,
             otype: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11041,7 +11934,7 @@ impl StopLossOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderBook {
     /// The partitioned order book, divided into buckets using a default
     /// bucket width. These buckets are only provided for price ranges which
@@ -11095,6 +11988,11 @@ pub struct OrderBook {
         with = "serdates"
     )]
     pub time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderBook {
     pub fn new() -> OrderBook {
@@ -11104,6 +12002,8 @@ impl OrderBook {
             price: None,
             bucket_width: None,
             time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11164,7 +12064,7 @@ impl OrderBook {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CalculatedPositionState {
     /// The unrealized profit/loss of the Position's short open Trades
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11216,6 +12116,11 @@ pub struct CalculatedPositionState {
         with = "serfloats"
     )]
     pub long_unrealized_pl: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CalculatedPositionState {
     pub fn new() -> CalculatedPositionState {
@@ -11225,6 +12130,8 @@ impl CalculatedPositionState {
             margin_used: None,
             net_unrealized_pl: None,
             long_unrealized_pl: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11281,7 +12188,7 @@ impl CalculatedPositionState {
 
 /// The type of an Instrument.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum InstrumentType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -11291,25 +12198,43 @@ pub enum InstrumentType {
     Metal,
 }
 
+impl InstrumentType {
+    /// Every variant of InstrumentType, in definition order.
+    pub const ALL: &[InstrumentType] = &[
+        InstrumentType::Currency,
+        InstrumentType::Cfd,
+        InstrumentType::Metal,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstrumentType::Currency => "CURRENCY",
+            InstrumentType::Cfd => "CFD",
+            InstrumentType::Metal => "METAL",
+        }
+    }
+}
+
 impl FromStr for InstrumentType {
-    type Err = ();
-    fn from_str(s: &str) -> Result<InstrumentType, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<InstrumentType, ParseEnumError> {
         match s {
             "CURRENCY" => Ok(InstrumentType::Currency),
             "CFD" => Ok(InstrumentType::Cfd),
             "METAL" => Ok(InstrumentType::Metal),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("InstrumentType", s)),
         }
     }
 }
 
 impl std::fmt::Display for InstrumentType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UnitsAvailable {
     /// Representation of many units of an Instrument are available to be
     /// traded for both long and short Orders.
@@ -11334,6 +12259,11 @@ pub struct UnitsAvailable {
     #[serde(default)]
     #[serde(rename = "reduceFirst", skip_serializing_if = "Option::is_none")]
     pub reduce_first: Option<UnitsAvailableDetails>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl UnitsAvailable {
     pub fn new() -> UnitsAvailable {
@@ -11342,6 +12272,8 @@ impl UnitsAvailable {
             reduce_only: None,
             open_only: None,
             reduce_first: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11382,7 +12314,7 @@ impl UnitsAvailable {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PriceBucket {
     /// The Price offered by the PriceBucket
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11399,12 +12331,19 @@ pub struct PriceBucket {
     #[serde(default)]
     #[serde(rename = "liquidity", skip_serializing_if = "Option::is_none")]
     pub liquidity: Option<i32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PriceBucket {
     pub fn new() -> PriceBucket {
         PriceBucket {
             price: None,
             liquidity: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11427,7 +12366,7 @@ impl PriceBucket {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Trade {
     /// The financing paid/collected for this Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11629,6 +12568,11 @@ pub struct Trade {
     #[serde(default)]
     #[serde(rename = "stopLossOrder", skip_serializing_if = "Option::is_none")]
     pub stop_loss_order: Option<StopLossOrder>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Trade {
     pub fn new() -> Trade {
@@ -11652,6 +12596,8 @@ impl Trade {
             client_extensions: None,
             id: None,
             stop_loss_order: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11864,7 +12810,7 @@ impl Trade {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CandlestickData {
     /// The highest price in the time-range represented by the candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11911,6 +12857,11 @@ pub struct CandlestickData {
         with = "serfloats"
     )]
     pub o: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CandlestickData {
     pub fn new() -> CandlestickData {
@@ -11919,6 +12870,8 @@ impl CandlestickData {
             c: None,
             l: None,
             o: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -11965,7 +12918,7 @@ impl CandlestickData {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct FixedPriceOrderTransaction {
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
@@ -12111,6 +13064,11 @@ pub struct FixedPriceOrderTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl FixedPriceOrderTransaction {
     pub fn new() -> FixedPriceOrderTransaction {
@@ -12133,6 +13091,8 @@ impl FixedPriceOrderTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -12318,7 +13278,7 @@ impl FixedPriceOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarginCallExtendTransaction {
     /// The number of the extensions to the Account's current margin call that
     /// have been applied. This value will be set to 1 for the first
@@ -12377,6 +13337,11 @@ pub struct MarginCallExtendTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarginCallExtendTransaction {
     pub fn new() -> MarginCallExtendTransaction {
@@ -12389,6 +13354,8 @@ impl MarginCallExtendTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -12471,7 +13438,7 @@ impl MarginCallExtendTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderTradeClose {
     /// Indication of how much of the Trade to close. Either "ALL", or a
     /// DecimalNumber reflection a partial close of the Trade.
@@ -12491,6 +13458,11 @@ pub struct MarketOrderTradeClose {
     #[serde(default)]
     #[serde(rename = "clientTradeID", skip_serializing_if = "Option::is_none")]
     pub client_trade_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderTradeClose {
     pub fn new() -> MarketOrderTradeClose {
@@ -12498,6 +13470,8 @@ impl MarketOrderTradeClose {
             units: None,
             trade_id: None,
             client_trade_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -12530,7 +13504,7 @@ impl MarketOrderTradeClose {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CloseTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -12582,6 +13556,11 @@ pub struct CloseTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CloseTransaction {
     pub fn new() -> CloseTransaction {
@@ -12593,6 +13572,8 @@ impl CloseTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -12665,7 +13646,7 @@ impl CloseTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PositionFinancing {
     /// The instrument of the Position that financing is being paid/collected
     /// for.
@@ -12693,6 +13674,11 @@ pub struct PositionFinancing {
         skip_serializing_if = "Option::is_none"
     )]
     pub open_trade_financings: Option<Vec<OpenTradeFinancing>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PositionFinancing {
     pub fn new() -> PositionFinancing {
@@ -12700,6 +13686,8 @@ impl PositionFinancing {
             instrument: None,
             financing: None,
             open_trade_financings: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -12735,7 +13723,7 @@ impl PositionFinancing {
 
 /// DateTime header
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AcceptDatetimeFormat {
     #[serde(rename = "UNIX")]
     Unix,
@@ -12743,26 +13731,42 @@ pub enum AcceptDatetimeFormat {
     Rfc3339,
 }
 
+impl AcceptDatetimeFormat {
+    /// Every variant of AcceptDatetimeFormat, in definition order.
+    pub const ALL: &[AcceptDatetimeFormat] = &[
+        AcceptDatetimeFormat::Unix,
+        AcceptDatetimeFormat::Rfc3339,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AcceptDatetimeFormat::Unix => "UNIX",
+            AcceptDatetimeFormat::Rfc3339 => "RFC3339",
+        }
+    }
+}
+
 impl FromStr for AcceptDatetimeFormat {
-    type Err = ();
-    fn from_str(s: &str) -> Result<AcceptDatetimeFormat, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<AcceptDatetimeFormat, ParseEnumError> {
         match s {
             "UNIX" => Ok(AcceptDatetimeFormat::Unix),
             "RFC3339" => Ok(AcceptDatetimeFormat::Rfc3339),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("AcceptDatetimeFormat", s)),
         }
     }
 }
 
 impl std::fmt::Display for AcceptDatetimeFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The reason that the Market Order was created
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MarketOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -12776,27 +13780,49 @@ pub enum MarketOrderReason {
     DelayedTradeClose,
 }
 
+impl MarketOrderReason {
+    /// Every variant of MarketOrderReason, in definition order.
+    pub const ALL: &[MarketOrderReason] = &[
+        MarketOrderReason::ClientOrder,
+        MarketOrderReason::TradeClose,
+        MarketOrderReason::PositionCloseout,
+        MarketOrderReason::MarginCloseout,
+        MarketOrderReason::DelayedTradeClose,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketOrderReason::ClientOrder => "CLIENT_ORDER",
+            MarketOrderReason::TradeClose => "TRADE_CLOSE",
+            MarketOrderReason::PositionCloseout => "POSITION_CLOSEOUT",
+            MarketOrderReason::MarginCloseout => "MARGIN_CLOSEOUT",
+            MarketOrderReason::DelayedTradeClose => "DELAYED_TRADE_CLOSE",
+        }
+    }
+}
+
 impl FromStr for MarketOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<MarketOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<MarketOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(MarketOrderReason::ClientOrder),
             "TRADE_CLOSE" => Ok(MarketOrderReason::TradeClose),
             "POSITION_CLOSEOUT" => Ok(MarketOrderReason::PositionCloseout),
             "MARGIN_CLOSEOUT" => Ok(MarketOrderReason::MarginCloseout),
             "DELAYED_TRADE_CLOSE" => Ok(MarketOrderReason::DelayedTradeClose),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("MarketOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for MarketOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CalculatedAccountState {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12947,6 +13973,11 @@ pub struct CalculatedAccountState {
         with = "serfloats"
     )]
     pub position_value: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CalculatedAccountState {
     pub fn new() -> CalculatedAccountState {
@@ -12964,6 +13995,8 @@ impl CalculatedAccountState {
             margin_closeout_unrealized_pl: None,
             margin_closeout_position_value: None,
             position_value: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -13105,7 +14138,7 @@ impl CalculatedAccountState {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderFillTransaction {
     /// The total guaranteed execution fees charged for all Trades opened,
     /// closed or reduced with guaranteed Stop Loss Orders.
@@ -13350,6 +14383,11 @@ pub struct OrderFillTransaction {
     #[serde(default)]
     #[serde(rename = "tradesClosed", skip_serializing_if = "Option::is_none")]
     pub trades_closed: Option<Vec<TradeReduce>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderFillTransaction {
     pub fn new() -> OrderFillTransaction {
@@ -13380,6 +14418,8 @@ impl OrderFillTransaction {
             time: None,
             trade_opened: None,
             trades_closed: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -13658,7 +14698,7 @@ impl OrderFillTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopLossOrderRejectTransaction {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -13843,6 +14883,11 @@ pub struct StopLossOrderRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopLossOrderRejectTransaction {
     pub fn new() -> StopLossOrderRejectTransaction {
@@ -13867,6 +14912,8 @@ impl StopLossOrderRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14093,7 +15140,7 @@ impl StopLossOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderRequest {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -14177,6 +15224,11 @@ pub struct TrailingStopLossOrderRequest {
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TrailingStopLossOrderRequest {
     pub fn new() -> TrailingStopLossOrderRequest {
@@ -14189,6 +15241,8 @@ impl TrailingStopLossOrderRequest {
             client_trade_id: None,
             gtd_time: None,
             otype: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14292,7 +15346,7 @@ impl TrailingStopLossOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TransactionHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -14319,6 +15373,11 @@ pub struct TransactionHeartbeat {
         with = "serdates"
     )]
     pub time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TransactionHeartbeat {
     pub fn new() -> TransactionHeartbeat {
@@ -14326,6 +15385,8 @@ impl TransactionHeartbeat {
             otype: None,
             last_transaction_id: None,
             time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14361,7 +15422,7 @@ impl TransactionHeartbeat {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Order {
     /// The current state of the Order.
     #[serde(default)]
@@ -14397,6 +15458,40 @@ pub struct Order {
         with = "serdates"
     )]
     pub create_time: Option<DateTime<Utc>>,
+
+    /// ID of the Transaction that filled this Order (only provided when the
+    /// Order's state is FILLED)
+    /// format: String representation of the numerical OANDA-assigned TransactionID
+    #[serde(default)]
+    #[serde(
+        rename = "fillingTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filling_transaction_id: Option<String>,
+
+    /// ID of the Transaction that cancelled the Order (only provided when the
+    /// Order's state is CANCELLED)
+    /// format: String representation of the numerical OANDA-assigned TransactionID
+    #[serde(default)]
+    #[serde(
+        rename = "cancellingTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cancelling_transaction_id: Option<String>,
+
+    /// The ID of the Trade a take profit, stop loss or trailing stop loss
+    /// Order belongs to.
+    /// format: The string representation of the OANDA-assigned TradeID. OANDA-
+    /// assigned TradeIDs are positive integers, and are derived from the
+    /// TransactionID of the Transaction that opened the Trade.
+    #[serde(default)]
+    #[serde(rename = "tradeID", skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Order {
     pub fn new() -> Order {
@@ -14405,6 +15500,11 @@ impl Order {
             client_extensions: None,
             id: None,
             create_time: None,
+            filling_transaction_id: None,
+            cancelling_transaction_id: None,
+            trade_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14450,9 +15550,41 @@ impl Order {
         self.create_time = Some(x);
         self
     }
+
+    /// ID of the Transaction that filled this Order (only provided when the
+    /// Order's state is FILLED)
+    /// format: String representation of the numerical OANDA-assigned TransactionID
+    /// - param String
+    /// - return Order
+    pub fn with_filling_transaction_id(mut self, x: String) -> Self {
+        self.filling_transaction_id = Some(x);
+        self
+    }
+
+    /// ID of the Transaction that cancelled the Order (only provided when the
+    /// Order's state is CANCELLED)
+    /// format: String representation of the numerical OANDA-assigned TransactionID
+    /// - param String
+    /// - return Order
+    pub fn with_cancelling_transaction_id(mut self, x: String) -> Self {
+        self.cancelling_transaction_id = Some(x);
+        self
+    }
+
+    /// The ID of the Trade a take profit, stop loss or trailing stop loss
+    /// Order belongs to.
+    /// format: The string representation of the OANDA-assigned TradeID. OANDA-
+    /// assigned TradeIDs are positive integers, and are derived from the
+    /// TransactionID of the Transaction that opened the Trade.
+    /// - param String
+    /// - return Order
+    pub fn with_trade_id(mut self, x: String) -> Self {
+        self.trade_id = Some(x);
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ClientConfigureRejectTransaction {
     /// The reason that the Reject Transaction was created
     #[serde(default)]
@@ -14525,6 +15657,11 @@ pub struct ClientConfigureRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ClientConfigureRejectTransaction {
     pub fn new() -> ClientConfigureRejectTransaction {
@@ -14539,6 +15676,8 @@ impl ClientConfigureRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14637,7 +15776,7 @@ impl ClientConfigureRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct InstrumentCommission {
     /// The commission amount (in the Account's home currency) charged per
     /// unitsTraded of the instrument
@@ -14673,6 +15812,11 @@ pub struct InstrumentCommission {
         with = "serfloats"
     )]
     pub minimum_commission: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl InstrumentCommission {
     pub fn new() -> InstrumentCommission {
@@ -14680,6 +15824,8 @@ impl InstrumentCommission {
             commission: None,
             units_traded: None,
             minimum_commission: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14718,7 +15864,7 @@ impl InstrumentCommission {
 
 /// The current state of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderState {
     #[serde(rename = "PENDING")]
     Pending,
@@ -14730,26 +15876,46 @@ pub enum OrderState {
     Cancelled,
 }
 
+impl OrderState {
+    /// Every variant of OrderState, in definition order.
+    pub const ALL: &[OrderState] = &[
+        OrderState::Pending,
+        OrderState::Filled,
+        OrderState::Triggered,
+        OrderState::Cancelled,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderState::Pending => "PENDING",
+            OrderState::Filled => "FILLED",
+            OrderState::Triggered => "TRIGGERED",
+            OrderState::Cancelled => "CANCELLED",
+        }
+    }
+}
+
 impl FromStr for OrderState {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderState, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderState, ParseEnumError> {
         match s {
             "PENDING" => Ok(OrderState::Pending),
             "FILLED" => Ok(OrderState::Filled),
             "TRIGGERED" => Ok(OrderState::Triggered),
             "CANCELLED" => Ok(OrderState::Cancelled),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderState", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderState {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ReopenTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -14801,6 +15967,11 @@ pub struct ReopenTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ReopenTransaction {
     pub fn new() -> ReopenTransaction {
@@ -14812,6 +15983,8 @@ impl ReopenTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -14884,7 +16057,7 @@ impl ReopenTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TrailingStopLossOrder {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -15109,6 +16282,11 @@ pub struct TrailingStopLossOrder {
     #[serde(default)]
     #[serde(rename = "replacedByOrderID", skip_serializing_if = "Option::is_none")]
     pub replaced_by_order_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TrailingStopLossOrder {
     pub fn new() -> TrailingStopLossOrder {
@@ -15134,6 +16312,8 @@ impl TrailingStopLossOrder {
             otype: None,
             id: None,
             replaced_by_order_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -15397,7 +16577,7 @@ impl TrailingStopLossOrder {
 
 /// The type of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderType {
     #[serde(rename = "MARKET")]
     Market,
@@ -15417,9 +16597,37 @@ pub enum OrderType {
     FixedPrice,
 }
 
+impl OrderType {
+    /// Every variant of OrderType, in definition order.
+    pub const ALL: &[OrderType] = &[
+        OrderType::Market,
+        OrderType::Limit,
+        OrderType::Stop,
+        OrderType::MarketIfTouched,
+        OrderType::TakeProfit,
+        OrderType::StopLoss,
+        OrderType::TrailingStopLoss,
+        OrderType::FixedPrice,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Market => "MARKET",
+            OrderType::Limit => "LIMIT",
+            OrderType::Stop => "STOP",
+            OrderType::MarketIfTouched => "MARKET_IF_TOUCHED",
+            OrderType::TakeProfit => "TAKE_PROFIT",
+            OrderType::StopLoss => "STOP_LOSS",
+            OrderType::TrailingStopLoss => "TRAILING_STOP_LOSS",
+            OrderType::FixedPrice => "FIXED_PRICE",
+        }
+    }
+}
+
 impl FromStr for OrderType {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderType, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderType, ParseEnumError> {
         match s {
             "MARKET" => Ok(OrderType::Market),
             "LIMIT" => Ok(OrderType::Limit),
@@ -15429,20 +16637,20 @@ impl FromStr for OrderType {
             "STOP_LOSS" => Ok(OrderType::StopLoss),
             "TRAILING_STOP_LOSS" => Ok(OrderType::TrailingStopLoss),
             "FIXED_PRICE" => Ok(OrderType::FixedPrice),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderType", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The reason that the Market-if-touched Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MarketIfTouchedOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -15450,26 +16658,42 @@ pub enum MarketIfTouchedOrderReason {
     Replacement,
 }
 
+impl MarketIfTouchedOrderReason {
+    /// Every variant of MarketIfTouchedOrderReason, in definition order.
+    pub const ALL: &[MarketIfTouchedOrderReason] = &[
+        MarketIfTouchedOrderReason::ClientOrder,
+        MarketIfTouchedOrderReason::Replacement,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketIfTouchedOrderReason::ClientOrder => "CLIENT_ORDER",
+            MarketIfTouchedOrderReason::Replacement => "REPLACEMENT",
+        }
+    }
+}
+
 impl FromStr for MarketIfTouchedOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<MarketIfTouchedOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<MarketIfTouchedOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(MarketIfTouchedOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(MarketIfTouchedOrderReason::Replacement),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("MarketIfTouchedOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for MarketIfTouchedOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The type of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CancellableOrderType {
     #[serde(rename = "LIMIT")]
     Limit,
@@ -15485,9 +16709,33 @@ pub enum CancellableOrderType {
     TrailingStopLoss,
 }
 
+impl CancellableOrderType {
+    /// Every variant of CancellableOrderType, in definition order.
+    pub const ALL: &[CancellableOrderType] = &[
+        CancellableOrderType::Limit,
+        CancellableOrderType::Stop,
+        CancellableOrderType::MarketIfTouched,
+        CancellableOrderType::TakeProfit,
+        CancellableOrderType::StopLoss,
+        CancellableOrderType::TrailingStopLoss,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CancellableOrderType::Limit => "LIMIT",
+            CancellableOrderType::Stop => "STOP",
+            CancellableOrderType::MarketIfTouched => "MARKET_IF_TOUCHED",
+            CancellableOrderType::TakeProfit => "TAKE_PROFIT",
+            CancellableOrderType::StopLoss => "STOP_LOSS",
+            CancellableOrderType::TrailingStopLoss => "TRAILING_STOP_LOSS",
+        }
+    }
+}
+
 impl FromStr for CancellableOrderType {
-    type Err = ();
-    fn from_str(s: &str) -> Result<CancellableOrderType, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<CancellableOrderType, ParseEnumError> {
         match s {
             "LIMIT" => Ok(CancellableOrderType::Limit),
             "STOP" => Ok(CancellableOrderType::Stop),
@@ -15495,21 +16743,21 @@ impl FromStr for CancellableOrderType {
             "TAKE_PROFIT" => Ok(CancellableOrderType::TakeProfit),
             "STOP_LOSS" => Ok(CancellableOrderType::StopLoss),
             "TRAILING_STOP_LOSS" => Ok(CancellableOrderType::TrailingStopLoss),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("CancellableOrderType", s)),
         }
     }
 }
 
 impl std::fmt::Display for CancellableOrderType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The way that position values for an Account are calculated and
 /// aggregated.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PositionAggregationMode {
     #[serde(rename = "ABSOLUTE_SUM")]
     AbsoluteSum,
@@ -15519,25 +16767,43 @@ pub enum PositionAggregationMode {
     NetSum,
 }
 
+impl PositionAggregationMode {
+    /// Every variant of PositionAggregationMode, in definition order.
+    pub const ALL: &[PositionAggregationMode] = &[
+        PositionAggregationMode::AbsoluteSum,
+        PositionAggregationMode::MaximalSide,
+        PositionAggregationMode::NetSum,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PositionAggregationMode::AbsoluteSum => "ABSOLUTE_SUM",
+            PositionAggregationMode::MaximalSide => "MAXIMAL_SIDE",
+            PositionAggregationMode::NetSum => "NET_SUM",
+        }
+    }
+}
+
 impl FromStr for PositionAggregationMode {
-    type Err = ();
-    fn from_str(s: &str) -> Result<PositionAggregationMode, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<PositionAggregationMode, ParseEnumError> {
         match s {
             "ABSOLUTE_SUM" => Ok(PositionAggregationMode::AbsoluteSum),
             "MAXIMAL_SIDE" => Ok(PositionAggregationMode::MaximalSide),
             "NET_SUM" => Ok(PositionAggregationMode::NetSum),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("PositionAggregationMode", s)),
         }
     }
 }
 
 impl std::fmt::Display for PositionAggregationMode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderTransaction {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -15701,6 +16967,11 @@ pub struct TrailingStopLossOrderTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TrailingStopLossOrderTransaction {
     pub fn new() -> TrailingStopLossOrderTransaction {
@@ -15723,6 +16994,8 @@ impl TrailingStopLossOrderTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -15926,7 +17199,7 @@ impl TrailingStopLossOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PositionBookBucket {
     /// The lowest price (inclusive) covered by the bucket. The bucket covers
     /// the price range from the price to price + the position book's
@@ -15964,6 +17237,11 @@ pub struct PositionBookBucket {
         with = "serfloats"
     )]
     pub long_count_percent: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PositionBookBucket {
     pub fn new() -> PositionBookBucket {
@@ -15971,6 +17249,8 @@ impl PositionBookBucket {
             price: None,
             short_count_percent: None,
             long_count_percent: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -16011,7 +17291,7 @@ impl PositionBookBucket {
 
 /// The possible types of a Transaction
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionType {
     #[serde(rename = "CREATE")]
     Create,
@@ -16085,9 +17365,91 @@ pub enum TransactionType {
     ResetResettablePl,
 }
 
+impl TransactionType {
+    /// Every variant of TransactionType, in definition order.
+    pub const ALL: &[TransactionType] = &[
+        TransactionType::Create,
+        TransactionType::Close,
+        TransactionType::Reopen,
+        TransactionType::ClientConfigure,
+        TransactionType::ClientConfigureReject,
+        TransactionType::TransferFunds,
+        TransactionType::TransferFundsReject,
+        TransactionType::MarketOrder,
+        TransactionType::MarketOrderReject,
+        TransactionType::FixedPriceOrder,
+        TransactionType::LimitOrder,
+        TransactionType::LimitOrderReject,
+        TransactionType::StopOrder,
+        TransactionType::StopOrderReject,
+        TransactionType::MarketIfTouchedOrder,
+        TransactionType::MarketIfTouchedOrderReject,
+        TransactionType::TakeProfitOrder,
+        TransactionType::TakeProfitOrderReject,
+        TransactionType::StopLossOrder,
+        TransactionType::StopLossOrderReject,
+        TransactionType::TrailingStopLossOrder,
+        TransactionType::TrailingStopLossOrderReject,
+        TransactionType::OrderFill,
+        TransactionType::OrderCancel,
+        TransactionType::OrderCancelReject,
+        TransactionType::OrderClientExtensionsModify,
+        TransactionType::OrderClientExtensionsModifyReject,
+        TransactionType::TradeClientExtensionsModify,
+        TransactionType::TradeClientExtensionsModifyReject,
+        TransactionType::MarginCallEnter,
+        TransactionType::MarginCallExtend,
+        TransactionType::MarginCallExit,
+        TransactionType::DelayedTradeClosure,
+        TransactionType::DailyFinancing,
+        TransactionType::ResetResettablePl,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Create => "CREATE",
+            TransactionType::Close => "CLOSE",
+            TransactionType::Reopen => "REOPEN",
+            TransactionType::ClientConfigure => "CLIENT_CONFIGURE",
+            TransactionType::ClientConfigureReject => "CLIENT_CONFIGURE_REJECT",
+            TransactionType::TransferFunds => "TRANSFER_FUNDS",
+            TransactionType::TransferFundsReject => "TRANSFER_FUNDS_REJECT",
+            TransactionType::MarketOrder => "MARKET_ORDER",
+            TransactionType::MarketOrderReject => "MARKET_ORDER_REJECT",
+            TransactionType::FixedPriceOrder => "FIXED_PRICE_ORDER",
+            TransactionType::LimitOrder => "LIMIT_ORDER",
+            TransactionType::LimitOrderReject => "LIMIT_ORDER_REJECT",
+            TransactionType::StopOrder => "STOP_ORDER",
+            TransactionType::StopOrderReject => "STOP_ORDER_REJECT",
+            TransactionType::MarketIfTouchedOrder => "MARKET_IF_TOUCHED_ORDER",
+            TransactionType::MarketIfTouchedOrderReject => "MARKET_IF_TOUCHED_ORDER_REJECT",
+            TransactionType::TakeProfitOrder => "TAKE_PROFIT_ORDER",
+            TransactionType::TakeProfitOrderReject => "TAKE_PROFIT_ORDER_REJECT",
+            TransactionType::StopLossOrder => "STOP_LOSS_ORDER",
+            TransactionType::StopLossOrderReject => "STOP_LOSS_ORDER_REJECT",
+            TransactionType::TrailingStopLossOrder => "TRAILING_STOP_LOSS_ORDER",
+            TransactionType::TrailingStopLossOrderReject => "TRAILING_STOP_LOSS_ORDER_REJECT",
+            TransactionType::OrderFill => "ORDER_FILL",
+            TransactionType::OrderCancel => "ORDER_CANCEL",
+            TransactionType::OrderCancelReject => "ORDER_CANCEL_REJECT",
+            TransactionType::OrderClientExtensionsModify => "ORDER_CLIENT_EXTENSIONS_MODIFY",
+            TransactionType::OrderClientExtensionsModifyReject => "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT",
+            TransactionType::TradeClientExtensionsModify => "TRADE_CLIENT_EXTENSIONS_MODIFY",
+            TransactionType::TradeClientExtensionsModifyReject => "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT",
+            TransactionType::MarginCallEnter => "MARGIN_CALL_ENTER",
+            TransactionType::MarginCallExtend => "MARGIN_CALL_EXTEND",
+            TransactionType::MarginCallExit => "MARGIN_CALL_EXIT",
+            TransactionType::DelayedTradeClosure => "DELAYED_TRADE_CLOSURE",
+            TransactionType::DailyFinancing => "DAILY_FINANCING",
+            TransactionType::ResetResettablePl => "RESET_RESETTABLE_PL",
+        }
+    }
+}
+
 impl FromStr for TransactionType {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TransactionType, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TransactionType, ParseEnumError> {
         match s {
             "CREATE" => Ok(TransactionType::Create),
             "CLOSE" => Ok(TransactionType::Close),
@@ -16115,31 +17477,27 @@ impl FromStr for TransactionType {
             "ORDER_CANCEL" => Ok(TransactionType::OrderCancel),
             "ORDER_CANCEL_REJECT" => Ok(TransactionType::OrderCancelReject),
             "ORDER_CLIENT_EXTENSIONS_MODIFY" => Ok(TransactionType::OrderClientExtensionsModify),
-            "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT" => {
-                Ok(TransactionType::OrderClientExtensionsModifyReject)
-            }
+            "ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT" => Ok(TransactionType::OrderClientExtensionsModifyReject),
             "TRADE_CLIENT_EXTENSIONS_MODIFY" => Ok(TransactionType::TradeClientExtensionsModify),
-            "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT" => {
-                Ok(TransactionType::TradeClientExtensionsModifyReject)
-            }
+            "TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT" => Ok(TransactionType::TradeClientExtensionsModifyReject),
             "MARGIN_CALL_ENTER" => Ok(TransactionType::MarginCallEnter),
             "MARGIN_CALL_EXTEND" => Ok(TransactionType::MarginCallExtend),
             "MARGIN_CALL_EXIT" => Ok(TransactionType::MarginCallExit),
             "DELAYED_TRADE_CLOSURE" => Ok(TransactionType::DelayedTradeClosure),
             "DAILY_FINANCING" => Ok(TransactionType::DailyFinancing),
             "RESET_RESETTABLE_PL" => Ok(TransactionType::ResetResettablePl),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TransactionType", s)),
         }
     }
 }
 
 impl std::fmt::Display for TransactionType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderTransaction {
     /// The time-in-force requested for the MarketIfTouched Order. Restricted
     /// to "GTC", "GFD" and "GTD" for MarketIfTouched Orders.
@@ -16358,6 +17716,11 @@ pub struct MarketIfTouchedOrderTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketIfTouchedOrderTransaction {
     pub fn new() -> MarketIfTouchedOrderTransaction {
@@ -16385,6 +17748,8 @@ impl MarketIfTouchedOrderTransaction {
             time: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -16647,7 +18012,7 @@ impl MarketIfTouchedOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PricingHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -16668,12 +18033,19 @@ pub struct PricingHeartbeat {
         with = "serdates"
     )]
     pub time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PricingHeartbeat {
     pub fn new() -> PricingHeartbeat {
         PricingHeartbeat {
             otype: None,
             time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -16700,7 +18072,7 @@ impl PricingHeartbeat {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -16971,6 +18343,11 @@ pub struct StopOrder {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopOrder {
     pub fn new() -> StopOrder {
@@ -17001,6 +18378,8 @@ impl StopOrder {
             trade_client_extensions: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -17315,7 +18694,7 @@ impl StopOrder {
 
 /// The granularity of a candlestick
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CandlestickGranularity {
     #[serde(rename = "S5")]
     S5,
@@ -17361,9 +18740,63 @@ pub enum CandlestickGranularity {
     M,
 }
 
+impl CandlestickGranularity {
+    /// Every variant of CandlestickGranularity, in definition order.
+    pub const ALL: &[CandlestickGranularity] = &[
+        CandlestickGranularity::S5,
+        CandlestickGranularity::S10,
+        CandlestickGranularity::S15,
+        CandlestickGranularity::S30,
+        CandlestickGranularity::M1,
+        CandlestickGranularity::M2,
+        CandlestickGranularity::M4,
+        CandlestickGranularity::M5,
+        CandlestickGranularity::M10,
+        CandlestickGranularity::M15,
+        CandlestickGranularity::M30,
+        CandlestickGranularity::H1,
+        CandlestickGranularity::H2,
+        CandlestickGranularity::H3,
+        CandlestickGranularity::H4,
+        CandlestickGranularity::H6,
+        CandlestickGranularity::H8,
+        CandlestickGranularity::H12,
+        CandlestickGranularity::D,
+        CandlestickGranularity::W,
+        CandlestickGranularity::M,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandlestickGranularity::S5 => "S5",
+            CandlestickGranularity::S10 => "S10",
+            CandlestickGranularity::S15 => "S15",
+            CandlestickGranularity::S30 => "S30",
+            CandlestickGranularity::M1 => "M1",
+            CandlestickGranularity::M2 => "M2",
+            CandlestickGranularity::M4 => "M4",
+            CandlestickGranularity::M5 => "M5",
+            CandlestickGranularity::M10 => "M10",
+            CandlestickGranularity::M15 => "M15",
+            CandlestickGranularity::M30 => "M30",
+            CandlestickGranularity::H1 => "H1",
+            CandlestickGranularity::H2 => "H2",
+            CandlestickGranularity::H3 => "H3",
+            CandlestickGranularity::H4 => "H4",
+            CandlestickGranularity::H6 => "H6",
+            CandlestickGranularity::H8 => "H8",
+            CandlestickGranularity::H12 => "H12",
+            CandlestickGranularity::D => "D",
+            CandlestickGranularity::W => "W",
+            CandlestickGranularity::M => "M",
+        }
+    }
+}
+
 impl FromStr for CandlestickGranularity {
-    type Err = ();
-    fn from_str(s: &str) -> Result<CandlestickGranularity, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<CandlestickGranularity, ParseEnumError> {
         match s {
             "S5" => Ok(CandlestickGranularity::S5),
             "S10" => Ok(CandlestickGranularity::S10),
@@ -17386,18 +18819,18 @@ impl FromStr for CandlestickGranularity {
             "D" => Ok(CandlestickGranularity::D),
             "W" => Ok(CandlestickGranularity::W),
             "M" => Ok(CandlestickGranularity::M),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("CandlestickGranularity", s)),
         }
     }
 }
 
 impl std::fmt::Display for CandlestickGranularity {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TakeProfitDetails {
     /// The time in force for the created Take Profit Order. This may only be
     /// GTC, GTD or GFD.
@@ -17439,6 +18872,11 @@ pub struct TakeProfitDetails {
     #[serde(default)]
     #[serde(rename = "clientExtensions", skip_serializing_if = "Option::is_none")]
     pub client_extensions: Option<ClientExtensions>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TakeProfitDetails {
     pub fn new() -> TakeProfitDetails {
@@ -17447,6 +18885,8 @@ impl TakeProfitDetails {
             price: None,
             gtd_time: None,
             client_extensions: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -17496,7 +18936,7 @@ impl TakeProfitDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ClientExtensions {
     /// A comment associated with the Order/Trade
     #[serde(default)]
@@ -17512,6 +18952,11 @@ pub struct ClientExtensions {
     #[serde(default)]
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ClientExtensions {
     pub fn new() -> ClientExtensions {
@@ -17519,6 +18964,8 @@ impl ClientExtensions {
             comment: None,
             tag: None,
             id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -17549,7 +18996,7 @@ impl ClientExtensions {
 
 /// The state to filter the Trades by
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TradeStateFilter {
     #[serde(rename = "OPEN")]
     Open,
@@ -17561,28 +19008,48 @@ pub enum TradeStateFilter {
     All,
 }
 
+impl TradeStateFilter {
+    /// Every variant of TradeStateFilter, in definition order.
+    pub const ALL: &[TradeStateFilter] = &[
+        TradeStateFilter::Open,
+        TradeStateFilter::Closed,
+        TradeStateFilter::CloseWhenTradeable,
+        TradeStateFilter::All,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeStateFilter::Open => "OPEN",
+            TradeStateFilter::Closed => "CLOSED",
+            TradeStateFilter::CloseWhenTradeable => "CLOSE_WHEN_TRADEABLE",
+            TradeStateFilter::All => "ALL",
+        }
+    }
+}
+
 impl FromStr for TradeStateFilter {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TradeStateFilter, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TradeStateFilter, ParseEnumError> {
         match s {
             "OPEN" => Ok(TradeStateFilter::Open),
             "CLOSED" => Ok(TradeStateFilter::Closed),
             "CLOSE_WHEN_TRADEABLE" => Ok(TradeStateFilter::CloseWhenTradeable),
             "ALL" => Ok(TradeStateFilter::All),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TradeStateFilter", s)),
         }
     }
 }
 
 impl std::fmt::Display for TradeStateFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The classification of TradePLs.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TradePL {
     #[serde(rename = "POSITIVE")]
     Positive,
@@ -17592,27 +19059,45 @@ pub enum TradePL {
     Zero,
 }
 
+impl TradePL {
+    /// Every variant of TradePL, in definition order.
+    pub const ALL: &[TradePL] = &[
+        TradePL::Positive,
+        TradePL::Negative,
+        TradePL::Zero,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradePL::Positive => "POSITIVE",
+            TradePL::Negative => "NEGATIVE",
+            TradePL::Zero => "ZERO",
+        }
+    }
+}
+
 impl FromStr for TradePL {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TradePL, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TradePL, ParseEnumError> {
         match s {
             "POSITIVE" => Ok(TradePL::Positive),
             "NEGATIVE" => Ok(TradePL::Negative),
             "ZERO" => Ok(TradePL::Zero),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TradePL", s)),
         }
     }
 }
 
 impl std::fmt::Display for TradePL {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
 /// The reason that the Stop Loss Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StopLossOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -17622,25 +19107,43 @@ pub enum StopLossOrderReason {
     OnFill,
 }
 
+impl StopLossOrderReason {
+    /// Every variant of StopLossOrderReason, in definition order.
+    pub const ALL: &[StopLossOrderReason] = &[
+        StopLossOrderReason::ClientOrder,
+        StopLossOrderReason::Replacement,
+        StopLossOrderReason::OnFill,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopLossOrderReason::ClientOrder => "CLIENT_ORDER",
+            StopLossOrderReason::Replacement => "REPLACEMENT",
+            StopLossOrderReason::OnFill => "ON_FILL",
+        }
+    }
+}
+
 impl FromStr for StopLossOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<StopLossOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<StopLossOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(StopLossOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(StopLossOrderReason::Replacement),
             "ON_FILL" => Ok(StopLossOrderReason::OnFill),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("StopLossOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for StopLossOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TakeProfitOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -17850,6 +19353,11 @@ pub struct TakeProfitOrder {
     #[serde(default)]
     #[serde(rename = "replacedByOrderID", skip_serializing_if = "Option::is_none")]
     pub replaced_by_order_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TakeProfitOrder {
     pub fn new() -> TakeProfitOrder {
@@ -17874,6 +19382,8 @@ impl TakeProfitOrder {
             otype: None,
             id: None,
             replaced_by_order_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -18123,7 +19633,7 @@ impl TakeProfitOrder {
 
 /// The reason that the Trailing Stop Loss Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TrailingStopLossOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -18133,25 +19643,43 @@ pub enum TrailingStopLossOrderReason {
     OnFill,
 }
 
+impl TrailingStopLossOrderReason {
+    /// Every variant of TrailingStopLossOrderReason, in definition order.
+    pub const ALL: &[TrailingStopLossOrderReason] = &[
+        TrailingStopLossOrderReason::ClientOrder,
+        TrailingStopLossOrderReason::Replacement,
+        TrailingStopLossOrderReason::OnFill,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrailingStopLossOrderReason::ClientOrder => "CLIENT_ORDER",
+            TrailingStopLossOrderReason::Replacement => "REPLACEMENT",
+            TrailingStopLossOrderReason::OnFill => "ON_FILL",
+        }
+    }
+}
+
 impl FromStr for TrailingStopLossOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TrailingStopLossOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TrailingStopLossOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(TrailingStopLossOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(TrailingStopLossOrderReason::Replacement),
             "ON_FILL" => Ok(TrailingStopLossOrderReason::OnFill),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TrailingStopLossOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for TrailingStopLossOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LimitOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -18285,6 +19813,11 @@ pub struct LimitOrderRequest {
     #[serde(default)]
     #[serde(rename = "positionFill", skip_serializing_if = "Option::is_none")]
     pub position_fill: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LimitOrderRequest {
     pub fn new() -> LimitOrderRequest {
@@ -18302,6 +19835,8 @@ impl LimitOrderRequest {
             gtd_time: None,
             otype: None,
             position_fill: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -18460,7 +19995,7 @@ impl LimitOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Instrument {
     /// The smallest number of units allowed to be traded for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -18577,6 +20112,11 @@ pub struct Instrument {
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Instrument {
     pub fn new() -> Instrument {
@@ -18594,6 +20134,8 @@ impl Instrument {
             maximum_order_units: None,
             maximum_position_size: None,
             otype: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -18726,7 +20268,7 @@ impl Instrument {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OpenTradeFinancing {
     /// The amount of financing paid/collected for the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -18746,12 +20288,19 @@ pub struct OpenTradeFinancing {
     #[serde(default)]
     #[serde(rename = "tradeID", skip_serializing_if = "Option::is_none")]
     pub trade_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OpenTradeFinancing {
     pub fn new() -> OpenTradeFinancing {
         OpenTradeFinancing {
             financing: None,
             trade_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -18779,7 +20328,7 @@ impl OpenTradeFinancing {
 
 /// The reason that an Order was cancelled.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderCancelReason {
     #[serde(rename = "INTERNAL_SERVER_ERROR")]
     InternalServerError,
@@ -18877,9 +20426,115 @@ pub enum OrderCancelReason {
     StopLossOnFillGuaranteedAskHalted,
 }
 
+impl OrderCancelReason {
+    /// Every variant of OrderCancelReason, in definition order.
+    pub const ALL: &[OrderCancelReason] = &[
+        OrderCancelReason::InternalServerError,
+        OrderCancelReason::AccountLocked,
+        OrderCancelReason::AccountNewPositionsLocked,
+        OrderCancelReason::AccountOrderCreationLocked,
+        OrderCancelReason::AccountOrderFillLocked,
+        OrderCancelReason::ClientRequest,
+        OrderCancelReason::Migration,
+        OrderCancelReason::MarketHalted,
+        OrderCancelReason::LinkedTradeClosed,
+        OrderCancelReason::TimeInForceExpired,
+        OrderCancelReason::InsufficientMargin,
+        OrderCancelReason::FifoViolation,
+        OrderCancelReason::BoundsViolation,
+        OrderCancelReason::ClientRequestReplaced,
+        OrderCancelReason::InsufficientLiquidity,
+        OrderCancelReason::TakeProfitOnFillGtdTimestampInPast,
+        OrderCancelReason::TakeProfitOnFillLoss,
+        OrderCancelReason::LosingTakeProfit,
+        OrderCancelReason::StopLossOnFillGtdTimestampInPast,
+        OrderCancelReason::StopLossOnFillLoss,
+        OrderCancelReason::StopLossOnFillPriceDistanceMaximumExceeded,
+        OrderCancelReason::StopLossOnFillRequired,
+        OrderCancelReason::StopLossOnFillGuaranteedRequired,
+        OrderCancelReason::StopLossOnFillGuaranteedNotAllowed,
+        OrderCancelReason::StopLossOnFillGuaranteedMinimumDistanceNotMet,
+        OrderCancelReason::StopLossOnFillGuaranteedLevelRestrictionExceeded,
+        OrderCancelReason::StopLossOnFillGuaranteedHedgingNotAllowed,
+        OrderCancelReason::StopLossOnFillTimeInForceInvalid,
+        OrderCancelReason::StopLossOnFillTriggerConditionInvalid,
+        OrderCancelReason::TakeProfitOnFillPriceDistanceMaximumExceeded,
+        OrderCancelReason::TrailingStopLossOnFillGtdTimestampInPast,
+        OrderCancelReason::ClientTradeIdAlreadyExists,
+        OrderCancelReason::PositionCloseoutFailed,
+        OrderCancelReason::OpenTradesAllowedExceeded,
+        OrderCancelReason::PendingOrdersAllowedExceeded,
+        OrderCancelReason::TakeProfitOnFillClientOrderIdAlreadyExists,
+        OrderCancelReason::StopLossOnFillClientOrderIdAlreadyExists,
+        OrderCancelReason::TrailingStopLossOnFillClientOrderIdAlreadyExists,
+        OrderCancelReason::PositionSizeExceeded,
+        OrderCancelReason::HedgingGsloViolation,
+        OrderCancelReason::AccountPositionValueLimitExceeded,
+        OrderCancelReason::InstrumentBidReduceOnly,
+        OrderCancelReason::InstrumentAskReduceOnly,
+        OrderCancelReason::InstrumentBidHalted,
+        OrderCancelReason::InstrumentAskHalted,
+        OrderCancelReason::StopLossOnFillGuaranteedBidHalted,
+        OrderCancelReason::StopLossOnFillGuaranteedAskHalted,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderCancelReason::InternalServerError => "INTERNAL_SERVER_ERROR",
+            OrderCancelReason::AccountLocked => "ACCOUNT_LOCKED",
+            OrderCancelReason::AccountNewPositionsLocked => "ACCOUNT_NEW_POSITIONS_LOCKED",
+            OrderCancelReason::AccountOrderCreationLocked => "ACCOUNT_ORDER_CREATION_LOCKED",
+            OrderCancelReason::AccountOrderFillLocked => "ACCOUNT_ORDER_FILL_LOCKED",
+            OrderCancelReason::ClientRequest => "CLIENT_REQUEST",
+            OrderCancelReason::Migration => "MIGRATION",
+            OrderCancelReason::MarketHalted => "MARKET_HALTED",
+            OrderCancelReason::LinkedTradeClosed => "LINKED_TRADE_CLOSED",
+            OrderCancelReason::TimeInForceExpired => "TIME_IN_FORCE_EXPIRED",
+            OrderCancelReason::InsufficientMargin => "INSUFFICIENT_MARGIN",
+            OrderCancelReason::FifoViolation => "FIFO_VIOLATION",
+            OrderCancelReason::BoundsViolation => "BOUNDS_VIOLATION",
+            OrderCancelReason::ClientRequestReplaced => "CLIENT_REQUEST_REPLACED",
+            OrderCancelReason::InsufficientLiquidity => "INSUFFICIENT_LIQUIDITY",
+            OrderCancelReason::TakeProfitOnFillGtdTimestampInPast => "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            OrderCancelReason::TakeProfitOnFillLoss => "TAKE_PROFIT_ON_FILL_LOSS",
+            OrderCancelReason::LosingTakeProfit => "LOSING_TAKE_PROFIT",
+            OrderCancelReason::StopLossOnFillGtdTimestampInPast => "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            OrderCancelReason::StopLossOnFillLoss => "STOP_LOSS_ON_FILL_LOSS",
+            OrderCancelReason::StopLossOnFillPriceDistanceMaximumExceeded => "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED",
+            OrderCancelReason::StopLossOnFillRequired => "STOP_LOSS_ON_FILL_REQUIRED",
+            OrderCancelReason::StopLossOnFillGuaranteedRequired => "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED",
+            OrderCancelReason::StopLossOnFillGuaranteedNotAllowed => "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED",
+            OrderCancelReason::StopLossOnFillGuaranteedMinimumDistanceNotMet => "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET",
+            OrderCancelReason::StopLossOnFillGuaranteedLevelRestrictionExceeded => "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED",
+            OrderCancelReason::StopLossOnFillGuaranteedHedgingNotAllowed => "STOP_LOSS_ON_FILL_GUARANTEED_HEDGING_NOT_ALLOWED",
+            OrderCancelReason::StopLossOnFillTimeInForceInvalid => "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID",
+            OrderCancelReason::StopLossOnFillTriggerConditionInvalid => "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID",
+            OrderCancelReason::TakeProfitOnFillPriceDistanceMaximumExceeded => "TAKE_PROFIT_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED",
+            OrderCancelReason::TrailingStopLossOnFillGtdTimestampInPast => "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            OrderCancelReason::ClientTradeIdAlreadyExists => "CLIENT_TRADE_ID_ALREADY_EXISTS",
+            OrderCancelReason::PositionCloseoutFailed => "POSITION_CLOSEOUT_FAILED",
+            OrderCancelReason::OpenTradesAllowedExceeded => "OPEN_TRADES_ALLOWED_EXCEEDED",
+            OrderCancelReason::PendingOrdersAllowedExceeded => "PENDING_ORDERS_ALLOWED_EXCEEDED",
+            OrderCancelReason::TakeProfitOnFillClientOrderIdAlreadyExists => "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS",
+            OrderCancelReason::StopLossOnFillClientOrderIdAlreadyExists => "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS",
+            OrderCancelReason::TrailingStopLossOnFillClientOrderIdAlreadyExists => "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS",
+            OrderCancelReason::PositionSizeExceeded => "POSITION_SIZE_EXCEEDED",
+            OrderCancelReason::HedgingGsloViolation => "HEDGING_GSLO_VIOLATION",
+            OrderCancelReason::AccountPositionValueLimitExceeded => "ACCOUNT_POSITION_VALUE_LIMIT_EXCEEDED",
+            OrderCancelReason::InstrumentBidReduceOnly => "INSTRUMENT_BID_REDUCE_ONLY",
+            OrderCancelReason::InstrumentAskReduceOnly => "INSTRUMENT_ASK_REDUCE_ONLY",
+            OrderCancelReason::InstrumentBidHalted => "INSTRUMENT_BID_HALTED",
+            OrderCancelReason::InstrumentAskHalted => "INSTRUMENT_ASK_HALTED",
+            OrderCancelReason::StopLossOnFillGuaranteedBidHalted => "STOP_LOSS_ON_FILL_GUARANTEED_BID_HALTED",
+            OrderCancelReason::StopLossOnFillGuaranteedAskHalted => "STOP_LOSS_ON_FILL_GUARANTEED_ASK_HALTED",
+        }
+    }
+}
+
 impl FromStr for OrderCancelReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderCancelReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderCancelReason, ParseEnumError> {
         match s {
             "INTERNAL_SERVER_ERROR" => Ok(OrderCancelReason::InternalServerError),
             "ACCOUNT_LOCKED" => Ok(OrderCancelReason::AccountLocked),
@@ -18896,88 +20551,50 @@ impl FromStr for OrderCancelReason {
             "BOUNDS_VIOLATION" => Ok(OrderCancelReason::BoundsViolation),
             "CLIENT_REQUEST_REPLACED" => Ok(OrderCancelReason::ClientRequestReplaced),
             "INSUFFICIENT_LIQUIDITY" => Ok(OrderCancelReason::InsufficientLiquidity),
-            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(OrderCancelReason::TakeProfitOnFillGtdTimestampInPast)
-            }
+            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(OrderCancelReason::TakeProfitOnFillGtdTimestampInPast),
             "TAKE_PROFIT_ON_FILL_LOSS" => Ok(OrderCancelReason::TakeProfitOnFillLoss),
             "LOSING_TAKE_PROFIT" => Ok(OrderCancelReason::LosingTakeProfit),
-            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(OrderCancelReason::StopLossOnFillGtdTimestampInPast)
-            }
+            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(OrderCancelReason::StopLossOnFillGtdTimestampInPast),
             "STOP_LOSS_ON_FILL_LOSS" => Ok(OrderCancelReason::StopLossOnFillLoss),
-            "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => {
-                Ok(OrderCancelReason::StopLossOnFillPriceDistanceMaximumExceeded)
-            }
+            "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => Ok(OrderCancelReason::StopLossOnFillPriceDistanceMaximumExceeded),
             "STOP_LOSS_ON_FILL_REQUIRED" => Ok(OrderCancelReason::StopLossOnFillRequired),
-            "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedRequired)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedNotAllowed)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedMinimumDistanceNotMet)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedLevelRestrictionExceeded)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_HEDGING_NOT_ALLOWED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedHedgingNotAllowed)
-            }
-            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => {
-                Ok(OrderCancelReason::StopLossOnFillTimeInForceInvalid)
-            }
-            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => {
-                Ok(OrderCancelReason::StopLossOnFillTriggerConditionInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => {
-                Ok(OrderCancelReason::TakeProfitOnFillPriceDistanceMaximumExceeded)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(OrderCancelReason::TrailingStopLossOnFillGtdTimestampInPast)
-            }
+            "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedRequired),
+            "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedNotAllowed),
+            "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => Ok(OrderCancelReason::StopLossOnFillGuaranteedMinimumDistanceNotMet),
+            "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedLevelRestrictionExceeded),
+            "STOP_LOSS_ON_FILL_GUARANTEED_HEDGING_NOT_ALLOWED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedHedgingNotAllowed),
+            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => Ok(OrderCancelReason::StopLossOnFillTimeInForceInvalid),
+            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => Ok(OrderCancelReason::StopLossOnFillTriggerConditionInvalid),
+            "TAKE_PROFIT_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => Ok(OrderCancelReason::TakeProfitOnFillPriceDistanceMaximumExceeded),
+            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(OrderCancelReason::TrailingStopLossOnFillGtdTimestampInPast),
             "CLIENT_TRADE_ID_ALREADY_EXISTS" => Ok(OrderCancelReason::ClientTradeIdAlreadyExists),
             "POSITION_CLOSEOUT_FAILED" => Ok(OrderCancelReason::PositionCloseoutFailed),
             "OPEN_TRADES_ALLOWED_EXCEEDED" => Ok(OrderCancelReason::OpenTradesAllowedExceeded),
-            "PENDING_ORDERS_ALLOWED_EXCEEDED" => {
-                Ok(OrderCancelReason::PendingOrdersAllowedExceeded)
-            }
-            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => {
-                Ok(OrderCancelReason::TakeProfitOnFillClientOrderIdAlreadyExists)
-            }
-            "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => {
-                Ok(OrderCancelReason::StopLossOnFillClientOrderIdAlreadyExists)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => {
-                Ok(OrderCancelReason::TrailingStopLossOnFillClientOrderIdAlreadyExists)
-            }
+            "PENDING_ORDERS_ALLOWED_EXCEEDED" => Ok(OrderCancelReason::PendingOrdersAllowedExceeded),
+            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => Ok(OrderCancelReason::TakeProfitOnFillClientOrderIdAlreadyExists),
+            "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => Ok(OrderCancelReason::StopLossOnFillClientOrderIdAlreadyExists),
+            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_ALREADY_EXISTS" => Ok(OrderCancelReason::TrailingStopLossOnFillClientOrderIdAlreadyExists),
             "POSITION_SIZE_EXCEEDED" => Ok(OrderCancelReason::PositionSizeExceeded),
             "HEDGING_GSLO_VIOLATION" => Ok(OrderCancelReason::HedgingGsloViolation),
-            "ACCOUNT_POSITION_VALUE_LIMIT_EXCEEDED" => {
-                Ok(OrderCancelReason::AccountPositionValueLimitExceeded)
-            }
+            "ACCOUNT_POSITION_VALUE_LIMIT_EXCEEDED" => Ok(OrderCancelReason::AccountPositionValueLimitExceeded),
             "INSTRUMENT_BID_REDUCE_ONLY" => Ok(OrderCancelReason::InstrumentBidReduceOnly),
             "INSTRUMENT_ASK_REDUCE_ONLY" => Ok(OrderCancelReason::InstrumentAskReduceOnly),
             "INSTRUMENT_BID_HALTED" => Ok(OrderCancelReason::InstrumentBidHalted),
             "INSTRUMENT_ASK_HALTED" => Ok(OrderCancelReason::InstrumentAskHalted),
-            "STOP_LOSS_ON_FILL_GUARANTEED_BID_HALTED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedBidHalted)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_ASK_HALTED" => {
-                Ok(OrderCancelReason::StopLossOnFillGuaranteedAskHalted)
-            }
-            _ => Err(()),
+            "STOP_LOSS_ON_FILL_GUARANTEED_BID_HALTED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedBidHalted),
+            "STOP_LOSS_ON_FILL_GUARANTEED_ASK_HALTED" => Ok(OrderCancelReason::StopLossOnFillGuaranteedAskHalted),
+            _ => Err(ParseEnumError::new("OrderCancelReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderCancelReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TrailingStopLossDetails {
     /// The time in force for the created Trailing Stop Loss Order. This may
     /// only be GTC, GTD or GFD.
@@ -19019,6 +20636,11 @@ pub struct TrailingStopLossDetails {
     #[serde(default)]
     #[serde(rename = "clientExtensions", skip_serializing_if = "Option::is_none")]
     pub client_extensions: Option<ClientExtensions>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TrailingStopLossDetails {
     pub fn new() -> TrailingStopLossDetails {
@@ -19027,6 +20649,8 @@ impl TrailingStopLossDetails {
             distance: None,
             gtd_time: None,
             client_extensions: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -19076,16 +20700,25 @@ impl TrailingStopLossDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LiquidityRegenerationSchedule {
     /// The steps in the Liquidity Regeneration Schedule
     #[serde(default)]
     #[serde(rename = "steps", skip_serializing_if = "Option::is_none")]
     pub steps: Option<Vec<LiquidityRegenerationScheduleStep>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LiquidityRegenerationSchedule {
     pub fn new() -> LiquidityRegenerationSchedule {
-        LiquidityRegenerationSchedule { steps: None }
+        LiquidityRegenerationSchedule {
+            steps: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
     }
 
     /// The steps in the Liquidity Regeneration Schedule
@@ -19097,7 +20730,7 @@ impl LiquidityRegenerationSchedule {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -19338,6 +20971,11 @@ pub struct MarketOrder {
         skip_serializing_if = "Option::is_none"
     )]
     pub cancelling_transaction_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrder {
     pub fn new() -> MarketOrder {
@@ -19368,6 +21006,8 @@ impl MarketOrder {
             trade_client_extensions: None,
             margin_closeout: None,
             cancelling_transaction_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -19652,7 +21292,7 @@ impl MarketOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderCancelRejectTransaction {
     /// The ID of the Order intended to be cancelled
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -19726,6 +21366,11 @@ pub struct OrderCancelRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderCancelRejectTransaction {
     pub fn new() -> OrderCancelRejectTransaction {
@@ -19740,6 +21385,8 @@ impl OrderCancelRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -19843,7 +21490,7 @@ impl OrderCancelRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopLossOrderTransaction {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -20044,6 +21691,11 @@ pub struct StopLossOrderTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopLossOrderTransaction {
     pub fn new() -> StopLossOrderTransaction {
@@ -20069,6 +21721,8 @@ impl StopLossOrderTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -20310,7 +21964,7 @@ impl StopLossOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Price {
     /// The list of prices and liquidity available on the Instrument's ask
     /// side. It is possible for this list to be empty if there is no ask
@@ -20400,6 +22054,11 @@ pub struct Price {
     #[serde(default)]
     #[serde(rename = "tradeable", skip_serializing_if = "Option::is_none")]
     pub tradeable: Option<bool>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl Price {
     pub fn new() -> Price {
@@ -20413,6 +22072,8 @@ impl Price {
             base_bid: None,
             closeout_ask: None,
             tradeable: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -20513,7 +22174,7 @@ impl Price {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LimitOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -20771,6 +22432,11 @@ pub struct LimitOrder {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LimitOrder {
     pub fn new() -> LimitOrder {
@@ -20800,6 +22466,8 @@ impl LimitOrder {
             trade_client_extensions: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -21100,7 +22768,7 @@ impl LimitOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderRejectTransaction {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -21263,6 +22931,11 @@ pub struct TrailingStopLossOrderRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TrailingStopLossOrderRejectTransaction {
     pub fn new() -> TrailingStopLossOrderRejectTransaction {
@@ -21285,6 +22958,8 @@ impl TrailingStopLossOrderRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -21487,7 +23162,7 @@ impl TrailingStopLossOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderClientExtensionsModifyTransaction {
     /// The ID of the Order who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -21574,6 +23249,11 @@ pub struct OrderClientExtensionsModifyTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderClientExtensionsModifyTransaction {
     pub fn new() -> OrderClientExtensionsModifyTransaction {
@@ -21589,6 +23269,8 @@ impl OrderClientExtensionsModifyTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -21702,7 +23384,7 @@ impl OrderClientExtensionsModifyTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderRequest {
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -21798,6 +23480,11 @@ pub struct MarketOrderRequest {
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderRequest {
     pub fn new() -> MarketOrderRequest {
@@ -21813,6 +23500,8 @@ impl MarketOrderRequest {
             units: None,
             client_extensions: None,
             otype: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -21931,7 +23620,7 @@ impl MarketOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UserInfo {
     /// The user-provided username.
     #[serde(default)]
@@ -21952,6 +23641,11 @@ pub struct UserInfo {
     #[serde(default)]
     #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
     pub user_id: Option<i32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl UserInfo {
     pub fn new() -> UserInfo {
@@ -21960,6 +23654,8 @@ impl UserInfo {
             country: None,
             email_address: None,
             user_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -21996,7 +23692,7 @@ impl UserInfo {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TransferFundsRejectTransaction {
     /// An optional comment that may be attached to a fund transfer for audit
     /// purposes
@@ -22077,6 +23773,11 @@ pub struct TransferFundsRejectTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TransferFundsRejectTransaction {
     pub fn new() -> TransferFundsRejectTransaction {
@@ -22092,6 +23793,8 @@ impl TransferFundsRejectTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -22201,7 +23904,7 @@ impl TransferFundsRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LiquidityRegenerationScheduleStep {
     /// The amount of bid liquidity used at this step in the schedule.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -22239,6 +23942,11 @@ pub struct LiquidityRegenerationScheduleStep {
         with = "serfloats"
     )]
     pub ask_liquidity_used: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LiquidityRegenerationScheduleStep {
     pub fn new() -> LiquidityRegenerationScheduleStep {
@@ -22246,6 +23954,8 @@ impl LiquidityRegenerationScheduleStep {
             bid_liquidity_used: None,
             timestamp: None,
             ask_liquidity_used: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -22284,7 +23994,7 @@ impl LiquidityRegenerationScheduleStep {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct OrderIdentifier {
     /// The OANDA-assigned Order ID
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -22298,12 +24008,19 @@ pub struct OrderIdentifier {
     #[serde(default)]
     #[serde(rename = "clientOrderID", skip_serializing_if = "Option::is_none")]
     pub client_order_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl OrderIdentifier {
     pub fn new() -> OrderIdentifier {
         OrderIdentifier {
             order_id: None,
             client_order_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -22327,7 +24044,7 @@ impl OrderIdentifier {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketOrderRejectTransaction {
     /// A MarketOrderPositionCloseout specifies the extensions to a Market
     /// Order when it has been created to closeout a specific Position.
@@ -22517,6 +24234,11 @@ pub struct MarketOrderRejectTransaction {
     #[serde(default)]
     #[serde(rename = "marginCloseout", skip_serializing_if = "Option::is_none")]
     pub margin_closeout: Option<MarketOrderMarginCloseout>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketOrderRejectTransaction {
     pub fn new() -> MarketOrderRejectTransaction {
@@ -22545,6 +24267,8 @@ impl MarketOrderRejectTransaction {
             trade_client_extensions: None,
             time: None,
             margin_closeout: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -22789,7 +24513,7 @@ impl MarketOrderRejectTransaction {
 /// Specification of how Positions in the Account are modified when the
 /// Order is filled.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderPositionFill {
     #[serde(rename = "OPEN_ONLY")]
     OpenOnly,
@@ -22801,26 +24525,46 @@ pub enum OrderPositionFill {
     Default,
 }
 
+impl OrderPositionFill {
+    /// Every variant of OrderPositionFill, in definition order.
+    pub const ALL: &[OrderPositionFill] = &[
+        OrderPositionFill::OpenOnly,
+        OrderPositionFill::ReduceFirst,
+        OrderPositionFill::ReduceOnly,
+        OrderPositionFill::Default,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderPositionFill::OpenOnly => "OPEN_ONLY",
+            OrderPositionFill::ReduceFirst => "REDUCE_FIRST",
+            OrderPositionFill::ReduceOnly => "REDUCE_ONLY",
+            OrderPositionFill::Default => "DEFAULT",
+        }
+    }
+}
+
 impl FromStr for OrderPositionFill {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderPositionFill, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderPositionFill, ParseEnumError> {
         match s {
             "OPEN_ONLY" => Ok(OrderPositionFill::OpenOnly),
             "REDUCE_FIRST" => Ok(OrderPositionFill::ReduceFirst),
             "REDUCE_ONLY" => Ok(OrderPositionFill::ReduceOnly),
             "DEFAULT" => Ok(OrderPositionFill::Default),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderPositionFill", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderPositionFill {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct AccountProperties {
     /// The Account's associated MT4 Account ID. This field will not be
     /// present if the Account is not an MT4 account.
@@ -22839,6 +24583,11 @@ pub struct AccountProperties {
     #[serde(default)]
     #[serde(rename = "tags", skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl AccountProperties {
     pub fn new() -> AccountProperties {
@@ -22846,6 +24595,8 @@ impl AccountProperties {
             mt4_account_id: None,
             id: None,
             tags: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -22879,7 +24630,7 @@ impl AccountProperties {
 
 /// The reason that the Stop Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StopOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -22887,24 +24638,40 @@ pub enum StopOrderReason {
     Replacement,
 }
 
+impl StopOrderReason {
+    /// Every variant of StopOrderReason, in definition order.
+    pub const ALL: &[StopOrderReason] = &[
+        StopOrderReason::ClientOrder,
+        StopOrderReason::Replacement,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopOrderReason::ClientOrder => "CLIENT_ORDER",
+            StopOrderReason::Replacement => "REPLACEMENT",
+        }
+    }
+}
+
 impl FromStr for StopOrderReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<StopOrderReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<StopOrderReason, ParseEnumError> {
         match s {
             "CLIENT_ORDER" => Ok(StopOrderReason::ClientOrder),
             "REPLACEMENT" => Ok(StopOrderReason::Replacement),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("StopOrderReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for StopOrderReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopOrderRejectTransaction {
     /// The time-in-force requested for the Stop Order.
     #[serde(default)]
@@ -23118,6 +24885,11 @@ pub struct StopOrderRejectTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopOrderRejectTransaction {
     pub fn new() -> StopOrderRejectTransaction {
@@ -23145,6 +24917,8 @@ impl StopOrderRejectTransaction {
             trade_client_extensions: None,
             time: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -23402,7 +25176,7 @@ impl StopOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarginCallEnterTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
@@ -23454,6 +25228,11 @@ pub struct MarginCallEnterTransaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarginCallEnterTransaction {
     pub fn new() -> MarginCallEnterTransaction {
@@ -23465,6 +25244,8 @@ impl MarginCallEnterTransaction {
             otype: None,
             id: None,
             account_id: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -23537,7 +25318,7 @@ impl MarginCallEnterTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderRejectTransaction {
     /// The time-in-force requested for the MarketIfTouched Order. Restricted
     /// to "GTC", "GFD" and "GTD" for MarketIfTouched Orders.
@@ -23755,6 +25536,11 @@ pub struct MarketIfTouchedOrderRejectTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl MarketIfTouchedOrderRejectTransaction {
     pub fn new() -> MarketIfTouchedOrderRejectTransaction {
@@ -23782,6 +25568,8 @@ impl MarketIfTouchedOrderRejectTransaction {
             trade_client_extensions: None,
             time: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -24043,7 +25831,7 @@ impl MarketIfTouchedOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UnitsAvailableDetails {
     /// The units available for short Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -24066,12 +25854,19 @@ pub struct UnitsAvailableDetails {
         with = "serfloats"
     )]
     pub long: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl UnitsAvailableDetails {
     pub fn new() -> UnitsAvailableDetails {
         UnitsAvailableDetails {
             short: None,
             long: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -24098,7 +25893,7 @@ impl UnitsAvailableDetails {
 
 /// The reason that a Transaction was rejected.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionRejectReason {
     #[serde(rename = "INTERNAL_SERVER_ERROR")]
     InternalServerError,
@@ -24388,37 +26183,325 @@ pub enum TransactionRejectReason {
     ReplacingTradeIdInvalid,
 }
 
+impl TransactionRejectReason {
+    /// Every variant of TransactionRejectReason, in definition order.
+    pub const ALL: &[TransactionRejectReason] = &[
+        TransactionRejectReason::InternalServerError,
+        TransactionRejectReason::InstrumentPriceUnknown,
+        TransactionRejectReason::AccountNotActive,
+        TransactionRejectReason::AccountLocked,
+        TransactionRejectReason::AccountOrderCreationLocked,
+        TransactionRejectReason::AccountConfigurationLocked,
+        TransactionRejectReason::AccountDepositLocked,
+        TransactionRejectReason::AccountWithdrawalLocked,
+        TransactionRejectReason::AccountOrderCancelLocked,
+        TransactionRejectReason::InstrumentNotTradeable,
+        TransactionRejectReason::PendingOrdersAllowedExceeded,
+        TransactionRejectReason::OrderIdUnspecified,
+        TransactionRejectReason::OrderDoesntExist,
+        TransactionRejectReason::OrderIdentifierInconsistency,
+        TransactionRejectReason::TradeIdUnspecified,
+        TransactionRejectReason::TradeDoesntExist,
+        TransactionRejectReason::TradeIdentifierInconsistency,
+        TransactionRejectReason::InsufficientMargin,
+        TransactionRejectReason::InstrumentMissing,
+        TransactionRejectReason::InstrumentUnknown,
+        TransactionRejectReason::UnitsMissing,
+        TransactionRejectReason::UnitsInvalid,
+        TransactionRejectReason::UnitsPrecisionExceeded,
+        TransactionRejectReason::UnitsLimitExceeded,
+        TransactionRejectReason::UnitsMimimumNotMet,
+        TransactionRejectReason::PriceMissing,
+        TransactionRejectReason::PriceInvalid,
+        TransactionRejectReason::PricePrecisionExceeded,
+        TransactionRejectReason::PriceDistanceMissing,
+        TransactionRejectReason::PriceDistanceInvalid,
+        TransactionRejectReason::PriceDistancePrecisionExceeded,
+        TransactionRejectReason::PriceDistanceMaximumExceeded,
+        TransactionRejectReason::PriceDistanceMinimumNotMet,
+        TransactionRejectReason::TimeInForceMissing,
+        TransactionRejectReason::TimeInForceInvalid,
+        TransactionRejectReason::TimeInForceGtdTimestampMissing,
+        TransactionRejectReason::TimeInForceGtdTimestampInPast,
+        TransactionRejectReason::PriceBoundInvalid,
+        TransactionRejectReason::PriceBoundPrecisionExceeded,
+        TransactionRejectReason::OrdersOnFillDuplicateClientOrderIds,
+        TransactionRejectReason::TradeOnFillClientExtensionsNotSupported,
+        TransactionRejectReason::ClientOrderIdInvalid,
+        TransactionRejectReason::ClientOrderIdAlreadyExists,
+        TransactionRejectReason::ClientOrderTagInvalid,
+        TransactionRejectReason::ClientOrderCommentInvalid,
+        TransactionRejectReason::ClientTradeIdInvalid,
+        TransactionRejectReason::ClientTradeIdAlreadyExists,
+        TransactionRejectReason::ClientTradeTagInvalid,
+        TransactionRejectReason::ClientTradeCommentInvalid,
+        TransactionRejectReason::OrderFillPositionActionMissing,
+        TransactionRejectReason::OrderFillPositionActionInvalid,
+        TransactionRejectReason::TriggerConditionMissing,
+        TransactionRejectReason::TriggerConditionInvalid,
+        TransactionRejectReason::OrderPartialFillOptionMissing,
+        TransactionRejectReason::OrderPartialFillOptionInvalid,
+        TransactionRejectReason::InvalidReissueImmediatePartialFill,
+        TransactionRejectReason::TakeProfitOrderAlreadyExists,
+        TransactionRejectReason::TakeProfitOnFillPriceMissing,
+        TransactionRejectReason::TakeProfitOnFillPriceInvalid,
+        TransactionRejectReason::TakeProfitOnFillPricePrecisionExceeded,
+        TransactionRejectReason::TakeProfitOnFillTimeInForceMissing,
+        TransactionRejectReason::TakeProfitOnFillTimeInForceInvalid,
+        TransactionRejectReason::TakeProfitOnFillGtdTimestampMissing,
+        TransactionRejectReason::TakeProfitOnFillGtdTimestampInPast,
+        TransactionRejectReason::TakeProfitOnFillClientOrderIdInvalid,
+        TransactionRejectReason::TakeProfitOnFillClientOrderTagInvalid,
+        TransactionRejectReason::TakeProfitOnFillClientOrderCommentInvalid,
+        TransactionRejectReason::TakeProfitOnFillTriggerConditionMissing,
+        TransactionRejectReason::TakeProfitOnFillTriggerConditionInvalid,
+        TransactionRejectReason::StopLossOrderAlreadyExists,
+        TransactionRejectReason::StopLossOrderGuaranteedRequired,
+        TransactionRejectReason::StopLossOrderGuaranteedPriceWithinSpread,
+        TransactionRejectReason::StopLossOrderGuaranteedNotAllowed,
+        TransactionRejectReason::StopLossOrderGuaranteedHaltedCreateViolation,
+        TransactionRejectReason::StopLossOrderGuaranteedHaltedTightenViolation,
+        TransactionRejectReason::StopLossOrderGuaranteedHedgingNotAllowed,
+        TransactionRejectReason::StopLossOrderGuaranteedMinimumDistanceNotMet,
+        TransactionRejectReason::StopLossOrderNotCancelable,
+        TransactionRejectReason::StopLossOrderNotReplaceable,
+        TransactionRejectReason::StopLossOrderGuaranteedLevelRestrictionExceeded,
+        TransactionRejectReason::StopLossOrderPriceAndDistanceBothSpecified,
+        TransactionRejectReason::StopLossOrderPriceAndDistanceBothMissing,
+        TransactionRejectReason::StopLossOnFillRequiredForPendingOrder,
+        TransactionRejectReason::StopLossOnFillGuaranteedNotAllowed,
+        TransactionRejectReason::StopLossOnFillGuaranteedRequired,
+        TransactionRejectReason::StopLossOnFillPriceMissing,
+        TransactionRejectReason::StopLossOnFillPriceInvalid,
+        TransactionRejectReason::StopLossOnFillPricePrecisionExceeded,
+        TransactionRejectReason::StopLossOnFillGuaranteedMinimumDistanceNotMet,
+        TransactionRejectReason::StopLossOnFillGuaranteedLevelRestrictionExceeded,
+        TransactionRejectReason::StopLossOnFillDistanceInvalid,
+        TransactionRejectReason::StopLossOnFillPriceDistanceMaximumExceeded,
+        TransactionRejectReason::StopLossOnFillDistancePrecisionExceeded,
+        TransactionRejectReason::StopLossOnFillPriceAndDistanceBothSpecified,
+        TransactionRejectReason::StopLossOnFillPriceAndDistanceBothMissing,
+        TransactionRejectReason::StopLossOnFillTimeInForceMissing,
+        TransactionRejectReason::StopLossOnFillTimeInForceInvalid,
+        TransactionRejectReason::StopLossOnFillGtdTimestampMissing,
+        TransactionRejectReason::StopLossOnFillGtdTimestampInPast,
+        TransactionRejectReason::StopLossOnFillClientOrderIdInvalid,
+        TransactionRejectReason::StopLossOnFillClientOrderTagInvalid,
+        TransactionRejectReason::StopLossOnFillClientOrderCommentInvalid,
+        TransactionRejectReason::StopLossOnFillTriggerConditionMissing,
+        TransactionRejectReason::StopLossOnFillTriggerConditionInvalid,
+        TransactionRejectReason::TrailingStopLossOrderAlreadyExists,
+        TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMissing,
+        TransactionRejectReason::TrailingStopLossOnFillPriceDistanceInvalid,
+        TransactionRejectReason::TrailingStopLossOnFillPriceDistancePrecisionExceeded,
+        TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMaximumExceeded,
+        TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMinimumNotMet,
+        TransactionRejectReason::TrailingStopLossOnFillTimeInForceMissing,
+        TransactionRejectReason::TrailingStopLossOnFillTimeInForceInvalid,
+        TransactionRejectReason::TrailingStopLossOnFillGtdTimestampMissing,
+        TransactionRejectReason::TrailingStopLossOnFillGtdTimestampInPast,
+        TransactionRejectReason::TrailingStopLossOnFillClientOrderIdInvalid,
+        TransactionRejectReason::TrailingStopLossOnFillClientOrderTagInvalid,
+        TransactionRejectReason::TrailingStopLossOnFillClientOrderCommentInvalid,
+        TransactionRejectReason::TrailingStopLossOrdersNotSupported,
+        TransactionRejectReason::TrailingStopLossOnFillTriggerConditionMissing,
+        TransactionRejectReason::TrailingStopLossOnFillTriggerConditionInvalid,
+        TransactionRejectReason::CloseTradeTypeMissing,
+        TransactionRejectReason::CloseTradePartialUnitsMissing,
+        TransactionRejectReason::CloseTradeUnitsExceedTradeSize,
+        TransactionRejectReason::CloseoutPositionDoesntExist,
+        TransactionRejectReason::CloseoutPositionIncompleteSpecification,
+        TransactionRejectReason::CloseoutPositionUnitsExceedPositionSize,
+        TransactionRejectReason::CloseoutPositionReject,
+        TransactionRejectReason::CloseoutPositionPartialUnitsMissing,
+        TransactionRejectReason::MarkupGroupIdInvalid,
+        TransactionRejectReason::PositionAggregationModeInvalid,
+        TransactionRejectReason::AdminConfigureDataMissing,
+        TransactionRejectReason::MarginRateInvalid,
+        TransactionRejectReason::MarginRateWouldTriggerCloseout,
+        TransactionRejectReason::AliasInvalid,
+        TransactionRejectReason::ClientConfigureDataMissing,
+        TransactionRejectReason::MarginRateWouldTriggerMarginCall,
+        TransactionRejectReason::AmountInvalid,
+        TransactionRejectReason::InsufficientFunds,
+        TransactionRejectReason::AmountMissing,
+        TransactionRejectReason::FundingReasonMissing,
+        TransactionRejectReason::ClientExtensionsDataMissing,
+        TransactionRejectReason::ReplacingOrderInvalid,
+        TransactionRejectReason::ReplacingTradeIdInvalid,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionRejectReason::InternalServerError => "INTERNAL_SERVER_ERROR",
+            TransactionRejectReason::InstrumentPriceUnknown => "INSTRUMENT_PRICE_UNKNOWN",
+            TransactionRejectReason::AccountNotActive => "ACCOUNT_NOT_ACTIVE",
+            TransactionRejectReason::AccountLocked => "ACCOUNT_LOCKED",
+            TransactionRejectReason::AccountOrderCreationLocked => "ACCOUNT_ORDER_CREATION_LOCKED",
+            TransactionRejectReason::AccountConfigurationLocked => "ACCOUNT_CONFIGURATION_LOCKED",
+            TransactionRejectReason::AccountDepositLocked => "ACCOUNT_DEPOSIT_LOCKED",
+            TransactionRejectReason::AccountWithdrawalLocked => "ACCOUNT_WITHDRAWAL_LOCKED",
+            TransactionRejectReason::AccountOrderCancelLocked => "ACCOUNT_ORDER_CANCEL_LOCKED",
+            TransactionRejectReason::InstrumentNotTradeable => "INSTRUMENT_NOT_TRADEABLE",
+            TransactionRejectReason::PendingOrdersAllowedExceeded => "PENDING_ORDERS_ALLOWED_EXCEEDED",
+            TransactionRejectReason::OrderIdUnspecified => "ORDER_ID_UNSPECIFIED",
+            TransactionRejectReason::OrderDoesntExist => "ORDER_DOESNT_EXIST",
+            TransactionRejectReason::OrderIdentifierInconsistency => "ORDER_IDENTIFIER_INCONSISTENCY",
+            TransactionRejectReason::TradeIdUnspecified => "TRADE_ID_UNSPECIFIED",
+            TransactionRejectReason::TradeDoesntExist => "TRADE_DOESNT_EXIST",
+            TransactionRejectReason::TradeIdentifierInconsistency => "TRADE_IDENTIFIER_INCONSISTENCY",
+            TransactionRejectReason::InsufficientMargin => "INSUFFICIENT_MARGIN",
+            TransactionRejectReason::InstrumentMissing => "INSTRUMENT_MISSING",
+            TransactionRejectReason::InstrumentUnknown => "INSTRUMENT_UNKNOWN",
+            TransactionRejectReason::UnitsMissing => "UNITS_MISSING",
+            TransactionRejectReason::UnitsInvalid => "UNITS_INVALID",
+            TransactionRejectReason::UnitsPrecisionExceeded => "UNITS_PRECISION_EXCEEDED",
+            TransactionRejectReason::UnitsLimitExceeded => "UNITS_LIMIT_EXCEEDED",
+            TransactionRejectReason::UnitsMimimumNotMet => "UNITS_MIMIMUM_NOT_MET",
+            TransactionRejectReason::PriceMissing => "PRICE_MISSING",
+            TransactionRejectReason::PriceInvalid => "PRICE_INVALID",
+            TransactionRejectReason::PricePrecisionExceeded => "PRICE_PRECISION_EXCEEDED",
+            TransactionRejectReason::PriceDistanceMissing => "PRICE_DISTANCE_MISSING",
+            TransactionRejectReason::PriceDistanceInvalid => "PRICE_DISTANCE_INVALID",
+            TransactionRejectReason::PriceDistancePrecisionExceeded => "PRICE_DISTANCE_PRECISION_EXCEEDED",
+            TransactionRejectReason::PriceDistanceMaximumExceeded => "PRICE_DISTANCE_MAXIMUM_EXCEEDED",
+            TransactionRejectReason::PriceDistanceMinimumNotMet => "PRICE_DISTANCE_MINIMUM_NOT_MET",
+            TransactionRejectReason::TimeInForceMissing => "TIME_IN_FORCE_MISSING",
+            TransactionRejectReason::TimeInForceInvalid => "TIME_IN_FORCE_INVALID",
+            TransactionRejectReason::TimeInForceGtdTimestampMissing => "TIME_IN_FORCE_GTD_TIMESTAMP_MISSING",
+            TransactionRejectReason::TimeInForceGtdTimestampInPast => "TIME_IN_FORCE_GTD_TIMESTAMP_IN_PAST",
+            TransactionRejectReason::PriceBoundInvalid => "PRICE_BOUND_INVALID",
+            TransactionRejectReason::PriceBoundPrecisionExceeded => "PRICE_BOUND_PRECISION_EXCEEDED",
+            TransactionRejectReason::OrdersOnFillDuplicateClientOrderIds => "ORDERS_ON_FILL_DUPLICATE_CLIENT_ORDER_IDS",
+            TransactionRejectReason::TradeOnFillClientExtensionsNotSupported => "TRADE_ON_FILL_CLIENT_EXTENSIONS_NOT_SUPPORTED",
+            TransactionRejectReason::ClientOrderIdInvalid => "CLIENT_ORDER_ID_INVALID",
+            TransactionRejectReason::ClientOrderIdAlreadyExists => "CLIENT_ORDER_ID_ALREADY_EXISTS",
+            TransactionRejectReason::ClientOrderTagInvalid => "CLIENT_ORDER_TAG_INVALID",
+            TransactionRejectReason::ClientOrderCommentInvalid => "CLIENT_ORDER_COMMENT_INVALID",
+            TransactionRejectReason::ClientTradeIdInvalid => "CLIENT_TRADE_ID_INVALID",
+            TransactionRejectReason::ClientTradeIdAlreadyExists => "CLIENT_TRADE_ID_ALREADY_EXISTS",
+            TransactionRejectReason::ClientTradeTagInvalid => "CLIENT_TRADE_TAG_INVALID",
+            TransactionRejectReason::ClientTradeCommentInvalid => "CLIENT_TRADE_COMMENT_INVALID",
+            TransactionRejectReason::OrderFillPositionActionMissing => "ORDER_FILL_POSITION_ACTION_MISSING",
+            TransactionRejectReason::OrderFillPositionActionInvalid => "ORDER_FILL_POSITION_ACTION_INVALID",
+            TransactionRejectReason::TriggerConditionMissing => "TRIGGER_CONDITION_MISSING",
+            TransactionRejectReason::TriggerConditionInvalid => "TRIGGER_CONDITION_INVALID",
+            TransactionRejectReason::OrderPartialFillOptionMissing => "ORDER_PARTIAL_FILL_OPTION_MISSING",
+            TransactionRejectReason::OrderPartialFillOptionInvalid => "ORDER_PARTIAL_FILL_OPTION_INVALID",
+            TransactionRejectReason::InvalidReissueImmediatePartialFill => "INVALID_REISSUE_IMMEDIATE_PARTIAL_FILL",
+            TransactionRejectReason::TakeProfitOrderAlreadyExists => "TAKE_PROFIT_ORDER_ALREADY_EXISTS",
+            TransactionRejectReason::TakeProfitOnFillPriceMissing => "TAKE_PROFIT_ON_FILL_PRICE_MISSING",
+            TransactionRejectReason::TakeProfitOnFillPriceInvalid => "TAKE_PROFIT_ON_FILL_PRICE_INVALID",
+            TransactionRejectReason::TakeProfitOnFillPricePrecisionExceeded => "TAKE_PROFIT_ON_FILL_PRICE_PRECISION_EXCEEDED",
+            TransactionRejectReason::TakeProfitOnFillTimeInForceMissing => "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_MISSING",
+            TransactionRejectReason::TakeProfitOnFillTimeInForceInvalid => "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_INVALID",
+            TransactionRejectReason::TakeProfitOnFillGtdTimestampMissing => "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_MISSING",
+            TransactionRejectReason::TakeProfitOnFillGtdTimestampInPast => "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            TransactionRejectReason::TakeProfitOnFillClientOrderIdInvalid => "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_INVALID",
+            TransactionRejectReason::TakeProfitOnFillClientOrderTagInvalid => "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_TAG_INVALID",
+            TransactionRejectReason::TakeProfitOnFillClientOrderCommentInvalid => "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_COMMENT_INVALID",
+            TransactionRejectReason::TakeProfitOnFillTriggerConditionMissing => "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_MISSING",
+            TransactionRejectReason::TakeProfitOnFillTriggerConditionInvalid => "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_INVALID",
+            TransactionRejectReason::StopLossOrderAlreadyExists => "STOP_LOSS_ORDER_ALREADY_EXISTS",
+            TransactionRejectReason::StopLossOrderGuaranteedRequired => "STOP_LOSS_ORDER_GUARANTEED_REQUIRED",
+            TransactionRejectReason::StopLossOrderGuaranteedPriceWithinSpread => "STOP_LOSS_ORDER_GUARANTEED_PRICE_WITHIN_SPREAD",
+            TransactionRejectReason::StopLossOrderGuaranteedNotAllowed => "STOP_LOSS_ORDER_GUARANTEED_NOT_ALLOWED",
+            TransactionRejectReason::StopLossOrderGuaranteedHaltedCreateViolation => "STOP_LOSS_ORDER_GUARANTEED_HALTED_CREATE_VIOLATION",
+            TransactionRejectReason::StopLossOrderGuaranteedHaltedTightenViolation => "STOP_LOSS_ORDER_GUARANTEED_HALTED_TIGHTEN_VIOLATION",
+            TransactionRejectReason::StopLossOrderGuaranteedHedgingNotAllowed => "STOP_LOSS_ORDER_GUARANTEED_HEDGING_NOT_ALLOWED",
+            TransactionRejectReason::StopLossOrderGuaranteedMinimumDistanceNotMet => "STOP_LOSS_ORDER_GUARANTEED_MINIMUM_DISTANCE_NOT_MET",
+            TransactionRejectReason::StopLossOrderNotCancelable => "STOP_LOSS_ORDER_NOT_CANCELABLE",
+            TransactionRejectReason::StopLossOrderNotReplaceable => "STOP_LOSS_ORDER_NOT_REPLACEABLE",
+            TransactionRejectReason::StopLossOrderGuaranteedLevelRestrictionExceeded => "STOP_LOSS_ORDER_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED",
+            TransactionRejectReason::StopLossOrderPriceAndDistanceBothSpecified => "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_SPECIFIED",
+            TransactionRejectReason::StopLossOrderPriceAndDistanceBothMissing => "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_MISSING",
+            TransactionRejectReason::StopLossOnFillRequiredForPendingOrder => "STOP_LOSS_ON_FILL_REQUIRED_FOR_PENDING_ORDER",
+            TransactionRejectReason::StopLossOnFillGuaranteedNotAllowed => "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED",
+            TransactionRejectReason::StopLossOnFillGuaranteedRequired => "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED",
+            TransactionRejectReason::StopLossOnFillPriceMissing => "STOP_LOSS_ON_FILL_PRICE_MISSING",
+            TransactionRejectReason::StopLossOnFillPriceInvalid => "STOP_LOSS_ON_FILL_PRICE_INVALID",
+            TransactionRejectReason::StopLossOnFillPricePrecisionExceeded => "STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED",
+            TransactionRejectReason::StopLossOnFillGuaranteedMinimumDistanceNotMet => "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET",
+            TransactionRejectReason::StopLossOnFillGuaranteedLevelRestrictionExceeded => "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED",
+            TransactionRejectReason::StopLossOnFillDistanceInvalid => "STOP_LOSS_ON_FILL_DISTANCE_INVALID",
+            TransactionRejectReason::StopLossOnFillPriceDistanceMaximumExceeded => "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED",
+            TransactionRejectReason::StopLossOnFillDistancePrecisionExceeded => "STOP_LOSS_ON_FILL_DISTANCE_PRECISION_EXCEEDED",
+            TransactionRejectReason::StopLossOnFillPriceAndDistanceBothSpecified => "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_SPECIFIED",
+            TransactionRejectReason::StopLossOnFillPriceAndDistanceBothMissing => "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_MISSING",
+            TransactionRejectReason::StopLossOnFillTimeInForceMissing => "STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING",
+            TransactionRejectReason::StopLossOnFillTimeInForceInvalid => "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID",
+            TransactionRejectReason::StopLossOnFillGtdTimestampMissing => "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING",
+            TransactionRejectReason::StopLossOnFillGtdTimestampInPast => "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            TransactionRejectReason::StopLossOnFillClientOrderIdInvalid => "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID",
+            TransactionRejectReason::StopLossOnFillClientOrderTagInvalid => "STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID",
+            TransactionRejectReason::StopLossOnFillClientOrderCommentInvalid => "STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID",
+            TransactionRejectReason::StopLossOnFillTriggerConditionMissing => "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING",
+            TransactionRejectReason::StopLossOnFillTriggerConditionInvalid => "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID",
+            TransactionRejectReason::TrailingStopLossOrderAlreadyExists => "TRAILING_STOP_LOSS_ORDER_ALREADY_EXISTS",
+            TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMissing => "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MISSING",
+            TransactionRejectReason::TrailingStopLossOnFillPriceDistanceInvalid => "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_INVALID",
+            TransactionRejectReason::TrailingStopLossOnFillPriceDistancePrecisionExceeded => "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_PRECISION_EXCEEDED",
+            TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMaximumExceeded => "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED",
+            TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMinimumNotMet => "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MINIMUM_NOT_MET",
+            TransactionRejectReason::TrailingStopLossOnFillTimeInForceMissing => "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING",
+            TransactionRejectReason::TrailingStopLossOnFillTimeInForceInvalid => "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID",
+            TransactionRejectReason::TrailingStopLossOnFillGtdTimestampMissing => "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING",
+            TransactionRejectReason::TrailingStopLossOnFillGtdTimestampInPast => "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST",
+            TransactionRejectReason::TrailingStopLossOnFillClientOrderIdInvalid => "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID",
+            TransactionRejectReason::TrailingStopLossOnFillClientOrderTagInvalid => "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID",
+            TransactionRejectReason::TrailingStopLossOnFillClientOrderCommentInvalid => "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID",
+            TransactionRejectReason::TrailingStopLossOrdersNotSupported => "TRAILING_STOP_LOSS_ORDERS_NOT_SUPPORTED",
+            TransactionRejectReason::TrailingStopLossOnFillTriggerConditionMissing => "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING",
+            TransactionRejectReason::TrailingStopLossOnFillTriggerConditionInvalid => "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID",
+            TransactionRejectReason::CloseTradeTypeMissing => "CLOSE_TRADE_TYPE_MISSING",
+            TransactionRejectReason::CloseTradePartialUnitsMissing => "CLOSE_TRADE_PARTIAL_UNITS_MISSING",
+            TransactionRejectReason::CloseTradeUnitsExceedTradeSize => "CLOSE_TRADE_UNITS_EXCEED_TRADE_SIZE",
+            TransactionRejectReason::CloseoutPositionDoesntExist => "CLOSEOUT_POSITION_DOESNT_EXIST",
+            TransactionRejectReason::CloseoutPositionIncompleteSpecification => "CLOSEOUT_POSITION_INCOMPLETE_SPECIFICATION",
+            TransactionRejectReason::CloseoutPositionUnitsExceedPositionSize => "CLOSEOUT_POSITION_UNITS_EXCEED_POSITION_SIZE",
+            TransactionRejectReason::CloseoutPositionReject => "CLOSEOUT_POSITION_REJECT",
+            TransactionRejectReason::CloseoutPositionPartialUnitsMissing => "CLOSEOUT_POSITION_PARTIAL_UNITS_MISSING",
+            TransactionRejectReason::MarkupGroupIdInvalid => "MARKUP_GROUP_ID_INVALID",
+            TransactionRejectReason::PositionAggregationModeInvalid => "POSITION_AGGREGATION_MODE_INVALID",
+            TransactionRejectReason::AdminConfigureDataMissing => "ADMIN_CONFIGURE_DATA_MISSING",
+            TransactionRejectReason::MarginRateInvalid => "MARGIN_RATE_INVALID",
+            TransactionRejectReason::MarginRateWouldTriggerCloseout => "MARGIN_RATE_WOULD_TRIGGER_CLOSEOUT",
+            TransactionRejectReason::AliasInvalid => "ALIAS_INVALID",
+            TransactionRejectReason::ClientConfigureDataMissing => "CLIENT_CONFIGURE_DATA_MISSING",
+            TransactionRejectReason::MarginRateWouldTriggerMarginCall => "MARGIN_RATE_WOULD_TRIGGER_MARGIN_CALL",
+            TransactionRejectReason::AmountInvalid => "AMOUNT_INVALID",
+            TransactionRejectReason::InsufficientFunds => "INSUFFICIENT_FUNDS",
+            TransactionRejectReason::AmountMissing => "AMOUNT_MISSING",
+            TransactionRejectReason::FundingReasonMissing => "FUNDING_REASON_MISSING",
+            TransactionRejectReason::ClientExtensionsDataMissing => "CLIENT_EXTENSIONS_DATA_MISSING",
+            TransactionRejectReason::ReplacingOrderInvalid => "REPLACING_ORDER_INVALID",
+            TransactionRejectReason::ReplacingTradeIdInvalid => "REPLACING_TRADE_ID_INVALID",
+        }
+    }
+}
+
 impl FromStr for TransactionRejectReason {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TransactionRejectReason, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TransactionRejectReason, ParseEnumError> {
         match s {
             "INTERNAL_SERVER_ERROR" => Ok(TransactionRejectReason::InternalServerError),
             "INSTRUMENT_PRICE_UNKNOWN" => Ok(TransactionRejectReason::InstrumentPriceUnknown),
             "ACCOUNT_NOT_ACTIVE" => Ok(TransactionRejectReason::AccountNotActive),
             "ACCOUNT_LOCKED" => Ok(TransactionRejectReason::AccountLocked),
-            "ACCOUNT_ORDER_CREATION_LOCKED" => {
-                Ok(TransactionRejectReason::AccountOrderCreationLocked)
-            }
-            "ACCOUNT_CONFIGURATION_LOCKED" => {
-                Ok(TransactionRejectReason::AccountConfigurationLocked)
-            }
+            "ACCOUNT_ORDER_CREATION_LOCKED" => Ok(TransactionRejectReason::AccountOrderCreationLocked),
+            "ACCOUNT_CONFIGURATION_LOCKED" => Ok(TransactionRejectReason::AccountConfigurationLocked),
             "ACCOUNT_DEPOSIT_LOCKED" => Ok(TransactionRejectReason::AccountDepositLocked),
             "ACCOUNT_WITHDRAWAL_LOCKED" => Ok(TransactionRejectReason::AccountWithdrawalLocked),
             "ACCOUNT_ORDER_CANCEL_LOCKED" => Ok(TransactionRejectReason::AccountOrderCancelLocked),
             "INSTRUMENT_NOT_TRADEABLE" => Ok(TransactionRejectReason::InstrumentNotTradeable),
-            "PENDING_ORDERS_ALLOWED_EXCEEDED" => {
-                Ok(TransactionRejectReason::PendingOrdersAllowedExceeded)
-            }
+            "PENDING_ORDERS_ALLOWED_EXCEEDED" => Ok(TransactionRejectReason::PendingOrdersAllowedExceeded),
             "ORDER_ID_UNSPECIFIED" => Ok(TransactionRejectReason::OrderIdUnspecified),
             "ORDER_DOESNT_EXIST" => Ok(TransactionRejectReason::OrderDoesntExist),
-            "ORDER_IDENTIFIER_INCONSISTENCY" => {
-                Ok(TransactionRejectReason::OrderIdentifierInconsistency)
-            }
+            "ORDER_IDENTIFIER_INCONSISTENCY" => Ok(TransactionRejectReason::OrderIdentifierInconsistency),
             "TRADE_ID_UNSPECIFIED" => Ok(TransactionRejectReason::TradeIdUnspecified),
             "TRADE_DOESNT_EXIST" => Ok(TransactionRejectReason::TradeDoesntExist),
-            "TRADE_IDENTIFIER_INCONSISTENCY" => {
-                Ok(TransactionRejectReason::TradeIdentifierInconsistency)
-            }
+            "TRADE_IDENTIFIER_INCONSISTENCY" => Ok(TransactionRejectReason::TradeIdentifierInconsistency),
             "INSUFFICIENT_MARGIN" => Ok(TransactionRejectReason::InsufficientMargin),
             "INSTRUMENT_MISSING" => Ok(TransactionRejectReason::InstrumentMissing),
             "INSTRUMENT_UNKNOWN" => Ok(TransactionRejectReason::InstrumentUnknown),
@@ -24432,317 +26515,131 @@ impl FromStr for TransactionRejectReason {
             "PRICE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::PricePrecisionExceeded),
             "PRICE_DISTANCE_MISSING" => Ok(TransactionRejectReason::PriceDistanceMissing),
             "PRICE_DISTANCE_INVALID" => Ok(TransactionRejectReason::PriceDistanceInvalid),
-            "PRICE_DISTANCE_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::PriceDistancePrecisionExceeded)
-            }
-            "PRICE_DISTANCE_MAXIMUM_EXCEEDED" => {
-                Ok(TransactionRejectReason::PriceDistanceMaximumExceeded)
-            }
-            "PRICE_DISTANCE_MINIMUM_NOT_MET" => {
-                Ok(TransactionRejectReason::PriceDistanceMinimumNotMet)
-            }
+            "PRICE_DISTANCE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::PriceDistancePrecisionExceeded),
+            "PRICE_DISTANCE_MAXIMUM_EXCEEDED" => Ok(TransactionRejectReason::PriceDistanceMaximumExceeded),
+            "PRICE_DISTANCE_MINIMUM_NOT_MET" => Ok(TransactionRejectReason::PriceDistanceMinimumNotMet),
             "TIME_IN_FORCE_MISSING" => Ok(TransactionRejectReason::TimeInForceMissing),
             "TIME_IN_FORCE_INVALID" => Ok(TransactionRejectReason::TimeInForceInvalid),
-            "TIME_IN_FORCE_GTD_TIMESTAMP_MISSING" => {
-                Ok(TransactionRejectReason::TimeInForceGtdTimestampMissing)
-            }
-            "TIME_IN_FORCE_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(TransactionRejectReason::TimeInForceGtdTimestampInPast)
-            }
+            "TIME_IN_FORCE_GTD_TIMESTAMP_MISSING" => Ok(TransactionRejectReason::TimeInForceGtdTimestampMissing),
+            "TIME_IN_FORCE_GTD_TIMESTAMP_IN_PAST" => Ok(TransactionRejectReason::TimeInForceGtdTimestampInPast),
             "PRICE_BOUND_INVALID" => Ok(TransactionRejectReason::PriceBoundInvalid),
-            "PRICE_BOUND_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::PriceBoundPrecisionExceeded)
-            }
-            "ORDERS_ON_FILL_DUPLICATE_CLIENT_ORDER_IDS" => {
-                Ok(TransactionRejectReason::OrdersOnFillDuplicateClientOrderIds)
-            }
-            "TRADE_ON_FILL_CLIENT_EXTENSIONS_NOT_SUPPORTED" => {
-                Ok(TransactionRejectReason::TradeOnFillClientExtensionsNotSupported)
-            }
+            "PRICE_BOUND_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::PriceBoundPrecisionExceeded),
+            "ORDERS_ON_FILL_DUPLICATE_CLIENT_ORDER_IDS" => Ok(TransactionRejectReason::OrdersOnFillDuplicateClientOrderIds),
+            "TRADE_ON_FILL_CLIENT_EXTENSIONS_NOT_SUPPORTED" => Ok(TransactionRejectReason::TradeOnFillClientExtensionsNotSupported),
             "CLIENT_ORDER_ID_INVALID" => Ok(TransactionRejectReason::ClientOrderIdInvalid),
-            "CLIENT_ORDER_ID_ALREADY_EXISTS" => {
-                Ok(TransactionRejectReason::ClientOrderIdAlreadyExists)
-            }
+            "CLIENT_ORDER_ID_ALREADY_EXISTS" => Ok(TransactionRejectReason::ClientOrderIdAlreadyExists),
             "CLIENT_ORDER_TAG_INVALID" => Ok(TransactionRejectReason::ClientOrderTagInvalid),
-            "CLIENT_ORDER_COMMENT_INVALID" => {
-                Ok(TransactionRejectReason::ClientOrderCommentInvalid)
-            }
+            "CLIENT_ORDER_COMMENT_INVALID" => Ok(TransactionRejectReason::ClientOrderCommentInvalid),
             "CLIENT_TRADE_ID_INVALID" => Ok(TransactionRejectReason::ClientTradeIdInvalid),
-            "CLIENT_TRADE_ID_ALREADY_EXISTS" => {
-                Ok(TransactionRejectReason::ClientTradeIdAlreadyExists)
-            }
+            "CLIENT_TRADE_ID_ALREADY_EXISTS" => Ok(TransactionRejectReason::ClientTradeIdAlreadyExists),
             "CLIENT_TRADE_TAG_INVALID" => Ok(TransactionRejectReason::ClientTradeTagInvalid),
-            "CLIENT_TRADE_COMMENT_INVALID" => {
-                Ok(TransactionRejectReason::ClientTradeCommentInvalid)
-            }
-            "ORDER_FILL_POSITION_ACTION_MISSING" => {
-                Ok(TransactionRejectReason::OrderFillPositionActionMissing)
-            }
-            "ORDER_FILL_POSITION_ACTION_INVALID" => {
-                Ok(TransactionRejectReason::OrderFillPositionActionInvalid)
-            }
+            "CLIENT_TRADE_COMMENT_INVALID" => Ok(TransactionRejectReason::ClientTradeCommentInvalid),
+            "ORDER_FILL_POSITION_ACTION_MISSING" => Ok(TransactionRejectReason::OrderFillPositionActionMissing),
+            "ORDER_FILL_POSITION_ACTION_INVALID" => Ok(TransactionRejectReason::OrderFillPositionActionInvalid),
             "TRIGGER_CONDITION_MISSING" => Ok(TransactionRejectReason::TriggerConditionMissing),
             "TRIGGER_CONDITION_INVALID" => Ok(TransactionRejectReason::TriggerConditionInvalid),
-            "ORDER_PARTIAL_FILL_OPTION_MISSING" => {
-                Ok(TransactionRejectReason::OrderPartialFillOptionMissing)
-            }
-            "ORDER_PARTIAL_FILL_OPTION_INVALID" => {
-                Ok(TransactionRejectReason::OrderPartialFillOptionInvalid)
-            }
-            "INVALID_REISSUE_IMMEDIATE_PARTIAL_FILL" => {
-                Ok(TransactionRejectReason::InvalidReissueImmediatePartialFill)
-            }
-            "TAKE_PROFIT_ORDER_ALREADY_EXISTS" => {
-                Ok(TransactionRejectReason::TakeProfitOrderAlreadyExists)
-            }
-            "TAKE_PROFIT_ON_FILL_PRICE_MISSING" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillPriceMissing)
-            }
-            "TAKE_PROFIT_ON_FILL_PRICE_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillPriceInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_PRICE_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillPricePrecisionExceeded)
-            }
-            "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_MISSING" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillTimeInForceMissing)
-            }
-            "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillTimeInForceInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_MISSING" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillGtdTimestampMissing)
-            }
-            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillGtdTimestampInPast)
-            }
-            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillClientOrderIdInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_TAG_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillClientOrderTagInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillClientOrderCommentInvalid)
-            }
-            "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_MISSING" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillTriggerConditionMissing)
-            }
-            "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_INVALID" => {
-                Ok(TransactionRejectReason::TakeProfitOnFillTriggerConditionInvalid)
-            }
-            "STOP_LOSS_ORDER_ALREADY_EXISTS" => {
-                Ok(TransactionRejectReason::StopLossOrderAlreadyExists)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_REQUIRED" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedRequired)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_PRICE_WITHIN_SPREAD" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedPriceWithinSpread)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_NOT_ALLOWED" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedNotAllowed)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_HALTED_CREATE_VIOLATION" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedHaltedCreateViolation)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_HALTED_TIGHTEN_VIOLATION" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedHaltedTightenViolation)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_HEDGING_NOT_ALLOWED" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedHedgingNotAllowed)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedMinimumDistanceNotMet)
-            }
-            "STOP_LOSS_ORDER_NOT_CANCELABLE" => {
-                Ok(TransactionRejectReason::StopLossOrderNotCancelable)
-            }
-            "STOP_LOSS_ORDER_NOT_REPLACEABLE" => {
-                Ok(TransactionRejectReason::StopLossOrderNotReplaceable)
-            }
-            "STOP_LOSS_ORDER_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => {
-                Ok(TransactionRejectReason::StopLossOrderGuaranteedLevelRestrictionExceeded)
-            }
-            "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_SPECIFIED" => {
-                Ok(TransactionRejectReason::StopLossOrderPriceAndDistanceBothSpecified)
-            }
-            "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOrderPriceAndDistanceBothMissing)
-            }
-            "STOP_LOSS_ON_FILL_REQUIRED_FOR_PENDING_ORDER" => {
-                Ok(TransactionRejectReason::StopLossOnFillRequiredForPendingOrder)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED" => {
-                Ok(TransactionRejectReason::StopLossOnFillGuaranteedNotAllowed)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED" => {
-                Ok(TransactionRejectReason::StopLossOnFillGuaranteedRequired)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOnFillPriceMissing)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillPriceInvalid)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::StopLossOnFillPricePrecisionExceeded)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => {
-                Ok(TransactionRejectReason::StopLossOnFillGuaranteedMinimumDistanceNotMet)
-            }
-            "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => {
-                Ok(TransactionRejectReason::StopLossOnFillGuaranteedLevelRestrictionExceeded)
-            }
-            "STOP_LOSS_ON_FILL_DISTANCE_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillDistanceInvalid)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => {
-                Ok(TransactionRejectReason::StopLossOnFillPriceDistanceMaximumExceeded)
-            }
-            "STOP_LOSS_ON_FILL_DISTANCE_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::StopLossOnFillDistancePrecisionExceeded)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_SPECIFIED" => {
-                Ok(TransactionRejectReason::StopLossOnFillPriceAndDistanceBothSpecified)
-            }
-            "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOnFillPriceAndDistanceBothMissing)
-            }
-            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOnFillTimeInForceMissing)
-            }
-            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillTimeInForceInvalid)
-            }
-            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOnFillGtdTimestampMissing)
-            }
-            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(TransactionRejectReason::StopLossOnFillGtdTimestampInPast)
-            }
-            "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillClientOrderIdInvalid)
-            }
-            "STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillClientOrderTagInvalid)
-            }
-            "STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillClientOrderCommentInvalid)
-            }
-            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING" => {
-                Ok(TransactionRejectReason::StopLossOnFillTriggerConditionMissing)
-            }
-            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => {
-                Ok(TransactionRejectReason::StopLossOnFillTriggerConditionInvalid)
-            }
-            "TRAILING_STOP_LOSS_ORDER_ALREADY_EXISTS" => {
-                Ok(TransactionRejectReason::TrailingStopLossOrderAlreadyExists)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MISSING" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMissing)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceInvalid)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_PRECISION_EXCEEDED" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistancePrecisionExceeded)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMaximumExceeded)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MINIMUM_NOT_MET" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMinimumNotMet)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillTimeInForceMissing)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillTimeInForceInvalid)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillGtdTimestampMissing)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillGtdTimestampInPast)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderIdInvalid)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderTagInvalid)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderCommentInvalid)
-            }
-            "TRAILING_STOP_LOSS_ORDERS_NOT_SUPPORTED" => {
-                Ok(TransactionRejectReason::TrailingStopLossOrdersNotSupported)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillTriggerConditionMissing)
-            }
-            "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => {
-                Ok(TransactionRejectReason::TrailingStopLossOnFillTriggerConditionInvalid)
-            }
+            "ORDER_PARTIAL_FILL_OPTION_MISSING" => Ok(TransactionRejectReason::OrderPartialFillOptionMissing),
+            "ORDER_PARTIAL_FILL_OPTION_INVALID" => Ok(TransactionRejectReason::OrderPartialFillOptionInvalid),
+            "INVALID_REISSUE_IMMEDIATE_PARTIAL_FILL" => Ok(TransactionRejectReason::InvalidReissueImmediatePartialFill),
+            "TAKE_PROFIT_ORDER_ALREADY_EXISTS" => Ok(TransactionRejectReason::TakeProfitOrderAlreadyExists),
+            "TAKE_PROFIT_ON_FILL_PRICE_MISSING" => Ok(TransactionRejectReason::TakeProfitOnFillPriceMissing),
+            "TAKE_PROFIT_ON_FILL_PRICE_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillPriceInvalid),
+            "TAKE_PROFIT_ON_FILL_PRICE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::TakeProfitOnFillPricePrecisionExceeded),
+            "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_MISSING" => Ok(TransactionRejectReason::TakeProfitOnFillTimeInForceMissing),
+            "TAKE_PROFIT_ON_FILL_TIME_IN_FORCE_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillTimeInForceInvalid),
+            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_MISSING" => Ok(TransactionRejectReason::TakeProfitOnFillGtdTimestampMissing),
+            "TAKE_PROFIT_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(TransactionRejectReason::TakeProfitOnFillGtdTimestampInPast),
+            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_ID_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillClientOrderIdInvalid),
+            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_TAG_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillClientOrderTagInvalid),
+            "TAKE_PROFIT_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillClientOrderCommentInvalid),
+            "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_MISSING" => Ok(TransactionRejectReason::TakeProfitOnFillTriggerConditionMissing),
+            "TAKE_PROFIT_ON_FILL_TRIGGER_CONDITION_INVALID" => Ok(TransactionRejectReason::TakeProfitOnFillTriggerConditionInvalid),
+            "STOP_LOSS_ORDER_ALREADY_EXISTS" => Ok(TransactionRejectReason::StopLossOrderAlreadyExists),
+            "STOP_LOSS_ORDER_GUARANTEED_REQUIRED" => Ok(TransactionRejectReason::StopLossOrderGuaranteedRequired),
+            "STOP_LOSS_ORDER_GUARANTEED_PRICE_WITHIN_SPREAD" => Ok(TransactionRejectReason::StopLossOrderGuaranteedPriceWithinSpread),
+            "STOP_LOSS_ORDER_GUARANTEED_NOT_ALLOWED" => Ok(TransactionRejectReason::StopLossOrderGuaranteedNotAllowed),
+            "STOP_LOSS_ORDER_GUARANTEED_HALTED_CREATE_VIOLATION" => Ok(TransactionRejectReason::StopLossOrderGuaranteedHaltedCreateViolation),
+            "STOP_LOSS_ORDER_GUARANTEED_HALTED_TIGHTEN_VIOLATION" => Ok(TransactionRejectReason::StopLossOrderGuaranteedHaltedTightenViolation),
+            "STOP_LOSS_ORDER_GUARANTEED_HEDGING_NOT_ALLOWED" => Ok(TransactionRejectReason::StopLossOrderGuaranteedHedgingNotAllowed),
+            "STOP_LOSS_ORDER_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => Ok(TransactionRejectReason::StopLossOrderGuaranteedMinimumDistanceNotMet),
+            "STOP_LOSS_ORDER_NOT_CANCELABLE" => Ok(TransactionRejectReason::StopLossOrderNotCancelable),
+            "STOP_LOSS_ORDER_NOT_REPLACEABLE" => Ok(TransactionRejectReason::StopLossOrderNotReplaceable),
+            "STOP_LOSS_ORDER_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => Ok(TransactionRejectReason::StopLossOrderGuaranteedLevelRestrictionExceeded),
+            "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_SPECIFIED" => Ok(TransactionRejectReason::StopLossOrderPriceAndDistanceBothSpecified),
+            "STOP_LOSS_ORDER_PRICE_AND_DISTANCE_BOTH_MISSING" => Ok(TransactionRejectReason::StopLossOrderPriceAndDistanceBothMissing),
+            "STOP_LOSS_ON_FILL_REQUIRED_FOR_PENDING_ORDER" => Ok(TransactionRejectReason::StopLossOnFillRequiredForPendingOrder),
+            "STOP_LOSS_ON_FILL_GUARANTEED_NOT_ALLOWED" => Ok(TransactionRejectReason::StopLossOnFillGuaranteedNotAllowed),
+            "STOP_LOSS_ON_FILL_GUARANTEED_REQUIRED" => Ok(TransactionRejectReason::StopLossOnFillGuaranteedRequired),
+            "STOP_LOSS_ON_FILL_PRICE_MISSING" => Ok(TransactionRejectReason::StopLossOnFillPriceMissing),
+            "STOP_LOSS_ON_FILL_PRICE_INVALID" => Ok(TransactionRejectReason::StopLossOnFillPriceInvalid),
+            "STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::StopLossOnFillPricePrecisionExceeded),
+            "STOP_LOSS_ON_FILL_GUARANTEED_MINIMUM_DISTANCE_NOT_MET" => Ok(TransactionRejectReason::StopLossOnFillGuaranteedMinimumDistanceNotMet),
+            "STOP_LOSS_ON_FILL_GUARANTEED_LEVEL_RESTRICTION_EXCEEDED" => Ok(TransactionRejectReason::StopLossOnFillGuaranteedLevelRestrictionExceeded),
+            "STOP_LOSS_ON_FILL_DISTANCE_INVALID" => Ok(TransactionRejectReason::StopLossOnFillDistanceInvalid),
+            "STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => Ok(TransactionRejectReason::StopLossOnFillPriceDistanceMaximumExceeded),
+            "STOP_LOSS_ON_FILL_DISTANCE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::StopLossOnFillDistancePrecisionExceeded),
+            "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_SPECIFIED" => Ok(TransactionRejectReason::StopLossOnFillPriceAndDistanceBothSpecified),
+            "STOP_LOSS_ON_FILL_PRICE_AND_DISTANCE_BOTH_MISSING" => Ok(TransactionRejectReason::StopLossOnFillPriceAndDistanceBothMissing),
+            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING" => Ok(TransactionRejectReason::StopLossOnFillTimeInForceMissing),
+            "STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => Ok(TransactionRejectReason::StopLossOnFillTimeInForceInvalid),
+            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING" => Ok(TransactionRejectReason::StopLossOnFillGtdTimestampMissing),
+            "STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(TransactionRejectReason::StopLossOnFillGtdTimestampInPast),
+            "STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID" => Ok(TransactionRejectReason::StopLossOnFillClientOrderIdInvalid),
+            "STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID" => Ok(TransactionRejectReason::StopLossOnFillClientOrderTagInvalid),
+            "STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => Ok(TransactionRejectReason::StopLossOnFillClientOrderCommentInvalid),
+            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING" => Ok(TransactionRejectReason::StopLossOnFillTriggerConditionMissing),
+            "STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => Ok(TransactionRejectReason::StopLossOnFillTriggerConditionInvalid),
+            "TRAILING_STOP_LOSS_ORDER_ALREADY_EXISTS" => Ok(TransactionRejectReason::TrailingStopLossOrderAlreadyExists),
+            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MISSING" => Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMissing),
+            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceInvalid),
+            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_PRECISION_EXCEEDED" => Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistancePrecisionExceeded),
+            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MAXIMUM_EXCEEDED" => Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMaximumExceeded),
+            "TRAILING_STOP_LOSS_ON_FILL_PRICE_DISTANCE_MINIMUM_NOT_MET" => Ok(TransactionRejectReason::TrailingStopLossOnFillPriceDistanceMinimumNotMet),
+            "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_MISSING" => Ok(TransactionRejectReason::TrailingStopLossOnFillTimeInForceMissing),
+            "TRAILING_STOP_LOSS_ON_FILL_TIME_IN_FORCE_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillTimeInForceInvalid),
+            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_MISSING" => Ok(TransactionRejectReason::TrailingStopLossOnFillGtdTimestampMissing),
+            "TRAILING_STOP_LOSS_ON_FILL_GTD_TIMESTAMP_IN_PAST" => Ok(TransactionRejectReason::TrailingStopLossOnFillGtdTimestampInPast),
+            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_ID_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderIdInvalid),
+            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_TAG_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderTagInvalid),
+            "TRAILING_STOP_LOSS_ON_FILL_CLIENT_ORDER_COMMENT_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillClientOrderCommentInvalid),
+            "TRAILING_STOP_LOSS_ORDERS_NOT_SUPPORTED" => Ok(TransactionRejectReason::TrailingStopLossOrdersNotSupported),
+            "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_MISSING" => Ok(TransactionRejectReason::TrailingStopLossOnFillTriggerConditionMissing),
+            "TRAILING_STOP_LOSS_ON_FILL_TRIGGER_CONDITION_INVALID" => Ok(TransactionRejectReason::TrailingStopLossOnFillTriggerConditionInvalid),
             "CLOSE_TRADE_TYPE_MISSING" => Ok(TransactionRejectReason::CloseTradeTypeMissing),
-            "CLOSE_TRADE_PARTIAL_UNITS_MISSING" => {
-                Ok(TransactionRejectReason::CloseTradePartialUnitsMissing)
-            }
-            "CLOSE_TRADE_UNITS_EXCEED_TRADE_SIZE" => {
-                Ok(TransactionRejectReason::CloseTradeUnitsExceedTradeSize)
-            }
-            "CLOSEOUT_POSITION_DOESNT_EXIST" => {
-                Ok(TransactionRejectReason::CloseoutPositionDoesntExist)
-            }
-            "CLOSEOUT_POSITION_INCOMPLETE_SPECIFICATION" => {
-                Ok(TransactionRejectReason::CloseoutPositionIncompleteSpecification)
-            }
-            "CLOSEOUT_POSITION_UNITS_EXCEED_POSITION_SIZE" => {
-                Ok(TransactionRejectReason::CloseoutPositionUnitsExceedPositionSize)
-            }
+            "CLOSE_TRADE_PARTIAL_UNITS_MISSING" => Ok(TransactionRejectReason::CloseTradePartialUnitsMissing),
+            "CLOSE_TRADE_UNITS_EXCEED_TRADE_SIZE" => Ok(TransactionRejectReason::CloseTradeUnitsExceedTradeSize),
+            "CLOSEOUT_POSITION_DOESNT_EXIST" => Ok(TransactionRejectReason::CloseoutPositionDoesntExist),
+            "CLOSEOUT_POSITION_INCOMPLETE_SPECIFICATION" => Ok(TransactionRejectReason::CloseoutPositionIncompleteSpecification),
+            "CLOSEOUT_POSITION_UNITS_EXCEED_POSITION_SIZE" => Ok(TransactionRejectReason::CloseoutPositionUnitsExceedPositionSize),
             "CLOSEOUT_POSITION_REJECT" => Ok(TransactionRejectReason::CloseoutPositionReject),
-            "CLOSEOUT_POSITION_PARTIAL_UNITS_MISSING" => {
-                Ok(TransactionRejectReason::CloseoutPositionPartialUnitsMissing)
-            }
+            "CLOSEOUT_POSITION_PARTIAL_UNITS_MISSING" => Ok(TransactionRejectReason::CloseoutPositionPartialUnitsMissing),
             "MARKUP_GROUP_ID_INVALID" => Ok(TransactionRejectReason::MarkupGroupIdInvalid),
-            "POSITION_AGGREGATION_MODE_INVALID" => {
-                Ok(TransactionRejectReason::PositionAggregationModeInvalid)
-            }
-            "ADMIN_CONFIGURE_DATA_MISSING" => {
-                Ok(TransactionRejectReason::AdminConfigureDataMissing)
-            }
+            "POSITION_AGGREGATION_MODE_INVALID" => Ok(TransactionRejectReason::PositionAggregationModeInvalid),
+            "ADMIN_CONFIGURE_DATA_MISSING" => Ok(TransactionRejectReason::AdminConfigureDataMissing),
             "MARGIN_RATE_INVALID" => Ok(TransactionRejectReason::MarginRateInvalid),
-            "MARGIN_RATE_WOULD_TRIGGER_CLOSEOUT" => {
-                Ok(TransactionRejectReason::MarginRateWouldTriggerCloseout)
-            }
+            "MARGIN_RATE_WOULD_TRIGGER_CLOSEOUT" => Ok(TransactionRejectReason::MarginRateWouldTriggerCloseout),
             "ALIAS_INVALID" => Ok(TransactionRejectReason::AliasInvalid),
-            "CLIENT_CONFIGURE_DATA_MISSING" => {
-                Ok(TransactionRejectReason::ClientConfigureDataMissing)
-            }
-            "MARGIN_RATE_WOULD_TRIGGER_MARGIN_CALL" => {
-                Ok(TransactionRejectReason::MarginRateWouldTriggerMarginCall)
-            }
+            "CLIENT_CONFIGURE_DATA_MISSING" => Ok(TransactionRejectReason::ClientConfigureDataMissing),
+            "MARGIN_RATE_WOULD_TRIGGER_MARGIN_CALL" => Ok(TransactionRejectReason::MarginRateWouldTriggerMarginCall),
             "AMOUNT_INVALID" => Ok(TransactionRejectReason::AmountInvalid),
             "INSUFFICIENT_FUNDS" => Ok(TransactionRejectReason::InsufficientFunds),
             "AMOUNT_MISSING" => Ok(TransactionRejectReason::AmountMissing),
             "FUNDING_REASON_MISSING" => Ok(TransactionRejectReason::FundingReasonMissing),
-            "CLIENT_EXTENSIONS_DATA_MISSING" => {
-                Ok(TransactionRejectReason::ClientExtensionsDataMissing)
-            }
+            "CLIENT_EXTENSIONS_DATA_MISSING" => Ok(TransactionRejectReason::ClientExtensionsDataMissing),
             "REPLACING_ORDER_INVALID" => Ok(TransactionRejectReason::ReplacingOrderInvalid),
             "REPLACING_TRADE_ID_INVALID" => Ok(TransactionRejectReason::ReplacingTradeIdInvalid),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TransactionRejectReason", s)),
         }
     }
 }
 
 impl std::fmt::Display for TransactionRejectReason {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -24889,6 +26786,11 @@ pub struct StopOrderRequest {
     #[serde(default)]
     #[serde(rename = "positionFill", skip_serializing_if = "Option::is_none")]
     pub position_fill: Option<String>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopOrderRequest {
     pub fn new() -> StopOrderRequest {
@@ -24907,6 +26809,8 @@ impl StopOrderRequest {
             gtd_time: None,
             otype: None,
             position_fill: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -25077,7 +26981,7 @@ impl StopOrderRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UserInfoExternal {
     /// The country that the user is based in.
     #[serde(default)]
@@ -25094,6 +26998,11 @@ pub struct UserInfoExternal {
     #[serde(default)]
     #[serde(rename = "FIFO", skip_serializing_if = "Option::is_none")]
     pub fifo: Option<bool>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl UserInfoExternal {
     pub fn new() -> UserInfoExternal {
@@ -25101,6 +27010,8 @@ impl UserInfoExternal {
             country: None,
             user_id: None,
             fifo: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -25130,7 +27041,7 @@ impl UserInfoExternal {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct CalculatedTradeState {
     /// The Trade's unrealized profit/loss.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25161,6 +27072,11 @@ pub struct CalculatedTradeState {
         with = "serfloats"
     )]
     pub margin_used: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl CalculatedTradeState {
     pub fn new() -> CalculatedTradeState {
@@ -25168,6 +27084,8 @@ impl CalculatedTradeState {
             unrealized_pl: None,
             id: None,
             margin_used: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -25203,7 +27121,7 @@ impl CalculatedTradeState {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct TradeReduce {
     /// The financing paid/collected when reducing the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25282,6 +27200,11 @@ pub struct TradeReduce {
         with = "serfloats"
     )]
     pub half_spread_cost: Option<f32>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl TradeReduce {
     pub fn new() -> TradeReduce {
@@ -25293,6 +27216,8 @@ impl TradeReduce {
             realized_pl: None,
             units: None,
             half_spread_cost: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -25391,7 +27316,7 @@ impl TradeReduce {
 /// "DEFAULT" and "BID", and for short trades "DEFAULT" and "ASK" are
 /// valid.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderTriggerCondition {
     #[serde(rename = "DEFAULT")]
     Default,
@@ -25405,23 +27330,45 @@ pub enum OrderTriggerCondition {
     Mid,
 }
 
+impl OrderTriggerCondition {
+    /// Every variant of OrderTriggerCondition, in definition order.
+    pub const ALL: &[OrderTriggerCondition] = &[
+        OrderTriggerCondition::Default,
+        OrderTriggerCondition::Inverse,
+        OrderTriggerCondition::Bid,
+        OrderTriggerCondition::Ask,
+        OrderTriggerCondition::Mid,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderTriggerCondition::Default => "DEFAULT",
+            OrderTriggerCondition::Inverse => "INVERSE",
+            OrderTriggerCondition::Bid => "BID",
+            OrderTriggerCondition::Ask => "ASK",
+            OrderTriggerCondition::Mid => "MID",
+        }
+    }
+}
+
 impl FromStr for OrderTriggerCondition {
-    type Err = ();
-    fn from_str(s: &str) -> Result<OrderTriggerCondition, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<OrderTriggerCondition, ParseEnumError> {
         match s {
             "DEFAULT" => Ok(OrderTriggerCondition::Default),
             "INVERSE" => Ok(OrderTriggerCondition::Inverse),
             "BID" => Ok(OrderTriggerCondition::Bid),
             "ASK" => Ok(OrderTriggerCondition::Ask),
             "MID" => Ok(OrderTriggerCondition::Mid),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("OrderTriggerCondition", s)),
         }
     }
 }
 
 impl std::fmt::Display for OrderTriggerCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
@@ -25429,7 +27376,7 @@ impl std::fmt::Display for OrderTriggerCondition {
 /// should remain pending before being automatically cancelled by the
 /// execution system.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TimeInForce {
     #[serde(rename = "GTC")]
     Gtc,
@@ -25443,27 +27390,49 @@ pub enum TimeInForce {
     Ioc,
 }
 
+impl TimeInForce {
+    /// Every variant of TimeInForce, in definition order.
+    pub const ALL: &[TimeInForce] = &[
+        TimeInForce::Gtc,
+        TimeInForce::Gtd,
+        TimeInForce::Gfd,
+        TimeInForce::Fok,
+        TimeInForce::Ioc,
+    ];
+
+    /// The string used for this variant by the OANDA API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeInForce::Gtc => "GTC",
+            TimeInForce::Gtd => "GTD",
+            TimeInForce::Gfd => "GFD",
+            TimeInForce::Fok => "FOK",
+            TimeInForce::Ioc => "IOC",
+        }
+    }
+}
+
 impl FromStr for TimeInForce {
-    type Err = ();
-    fn from_str(s: &str) -> Result<TimeInForce, ()> {
+    type Err = ParseEnumError;
+    fn from_str(s: &str) -> Result<TimeInForce, ParseEnumError> {
         match s {
             "GTC" => Ok(TimeInForce::Gtc),
             "GTD" => Ok(TimeInForce::Gtd),
             "GFD" => Ok(TimeInForce::Gfd),
             "FOK" => Ok(TimeInForce::Fok),
             "IOC" => Ok(TimeInForce::Ioc),
-            _ => Err(()),
+            _ => Err(ParseEnumError::new("TimeInForce", s)),
         }
     }
 }
 
 impl std::fmt::Display for TimeInForce {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct ClientPrice {
     /// The status of the Price.
     #[serde(default)]
@@ -25558,6 +27527,11 @@ pub struct ClientPrice {
     #[serde(default)]
     #[serde(rename = "tradeable", skip_serializing_if = "Option::is_none")]
     pub tradeable: Option<bool>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl ClientPrice {
     pub fn new() -> ClientPrice {
@@ -25573,6 +27547,8 @@ impl ClientPrice {
             closeout_ask: None,
             otype: None,
             tradeable: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -25689,7 +27665,7 @@ impl ClientPrice {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct LimitOrderTransaction {
     /// The time-in-force requested for the Limit Order.
     #[serde(default)]
@@ -25892,6 +27868,11 @@ pub struct LimitOrderTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl LimitOrderTransaction {
     pub fn new() -> LimitOrderTransaction {
@@ -25918,6 +27899,8 @@ impl LimitOrderTransaction {
             time: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -26165,7 +28148,7 @@ impl LimitOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PositionBook {
     /// The partitioned position book, divided into buckets using a default
     /// bucket width. These buckets are only provided for price ranges which
@@ -26219,6 +28202,11 @@ pub struct PositionBook {
         with = "serdates"
     )]
     pub time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl PositionBook {
     pub fn new() -> PositionBook {
@@ -26228,6 +28216,8 @@ impl PositionBook {
             price: None,
             bucket_width: None,
             time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -26288,7 +28278,7 @@ impl PositionBook {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct StopOrderTransaction {
     /// The time-in-force requested for the Stop Order.
     #[serde(default)]
@@ -26504,6 +28494,11 @@ pub struct StopOrderTransaction {
         with = "serdates"
     )]
     pub gtd_time: Option<DateTime<Utc>>,
+
+    /// Fields returned by the API that are not part of this definition.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 impl StopOrderTransaction {
     pub fn new() -> StopOrderTransaction {
@@ -26531,6 +28526,8 @@ impl StopOrderTransaction {
             time: None,
             cancelling_transaction_id: None,
             gtd_time: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }
     }
 
@@ -26789,3 +28786,42 @@ impl StopOrderTransaction {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every variant serializes to its wire name, and parses back from it
+    // through serde, `FromStr` and `Display`.
+    fn round_trip<T>(all: &[T], as_str: fn(&T) -> &'static str)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + FromStr<Err = ParseEnumError> + std::fmt::Display + PartialEq + std::fmt::Debug,
+    {
+        for variant in all {
+            let wire = as_str(variant);
+            assert_eq!(serde_json::to_string(variant).unwrap(), format!("\"{}\"", wire));
+            assert_eq!(&serde_json::from_str::<T>(&format!("\"{}\"", wire)).unwrap(), variant);
+            assert_eq!(&wire.parse::<T>().unwrap(), variant);
+            assert_eq!(variant.to_string(), wire);
+        }
+    }
+
+    #[test]
+    fn renamed_variants_round_trip() {
+        round_trip(PriceStatus::ALL, PriceStatus::as_str);
+        round_trip(TimeInForce::ALL, TimeInForce::as_str);
+        round_trip(OrderPositionFill::ALL, OrderPositionFill::as_str);
+        round_trip(CandlestickGranularity::ALL, CandlestickGranularity::as_str);
+        assert_eq!(PriceStatus::Nontradeable.as_str(), "non-tradeable");
+        assert_eq!(serde_json::to_string(&TimeInForce::Gtc).unwrap(), "\"GTC\"");
+        assert!(serde_json::from_str::<PriceStatus>("\"Nontradeable\"").is_err());
+    }
+
+    #[test]
+    fn parse_errors_name_the_enum_and_input() {
+        let error = "nontradeable".parse::<PriceStatus>().unwrap_err();
+        assert_eq!(error, ParseEnumError::new("PriceStatus", "nontradeable"));
+        assert_eq!(error.to_string(), "invalid PriceStatus value: \"nontradeable\"");
+        assert_eq!("gtc".parse::<TimeInForce>().unwrap_err().enum_name, "TimeInForce");
+    }
+}
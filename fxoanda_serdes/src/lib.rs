@@ -3,6 +3,7 @@ pub mod serfloats;
 pub use self::serfloats::*;
 pub mod serdates;
 pub use self::serdates::*;
+pub mod serdoubles;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Like `serfloats`, for the decimals that need more precision than an
+/// `f32` holds, e.g. account balances.
+pub fn serialize<S>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(ref v) = *value {
+        return serializer.collect_str(&v.to_string());
+    }
+    serializer.serialize_none()
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    if let Some(s) = s {
+        return Ok(Some(s.parse::<f64>().map_err(serde::de::Error::custom)?));
+    }
+    Ok(None)
+}
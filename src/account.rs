@@ -95,24 +95,19 @@ pub mod list_positions {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<ListPositionsResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListPositionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListPositionsResponse>(response).await,
             }
         }
     }
@@ -120,7 +115,7 @@ pub mod list_positions {
     pub type ListPositionsResponse = ListPositionsResponse200Body;
 
     /// The Account's Positions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListPositionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -128,7 +123,7 @@ pub mod list_positions {
     }
 
     /// The Account's Positions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListPositionsResponse200Body {
         /// The list of Account Positions.
         #[serde(rename = "positions", skip_serializing_if = "Option::is_none")]
@@ -236,24 +231,19 @@ pub mod list_open_positions {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<ListOpenPositionsResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListOpenPositionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListOpenPositionsResponse>(response).await,
             }
         }
     }
@@ -261,7 +251,7 @@ pub mod list_open_positions {
     pub type ListOpenPositionsResponse = ListOpenPositionsResponse200Body;
 
     /// The Account's open Positions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOpenPositionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -269,7 +259,7 @@ pub mod list_open_positions {
     }
 
     /// The Account's open Positions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOpenPositionsResponse200Body {
         /// The list of open Positions in the Account.
         #[serde(rename = "positions", skip_serializing_if = "Option::is_none")]
@@ -393,24 +383,19 @@ pub mod get_position {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetPositionResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetPositionResponse, Box<dyn Error>> {
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetPositionResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetPositionResponse>(response).await,
             }
         }
     }
@@ -418,7 +403,7 @@ pub mod get_position {
     pub type GetPositionResponse = GetPositionResponse200Body;
 
     /// The Position is provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPositionResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -426,7 +411,7 @@ pub mod get_position {
     }
 
     /// The Position is provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPositionResponse200Body {
         /// The specification of a Position within an Account.
         #[serde(rename = "position", skip_serializing_if = "Option::is_none")]
@@ -444,7 +429,7 @@ pub mod close_position {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, CloseUnits, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -483,13 +468,13 @@ pub mod close_position {
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestBody {
         #[serde(rename = "longUnits", skip_serializing_if = "Option::is_none")]
-        pub long_units: Option<String>,
+        pub long_units: Option<CloseUnits>,
 
         #[serde(rename = "longClientExtensions", skip_serializing_if = "Option::is_none")]
         pub long_client_extensions: Option<ClientExtensions>,
 
         #[serde(rename = "shortUnits", skip_serializing_if = "Option::is_none")]
-        pub short_units: Option<String>,
+        pub short_units: Option<CloseUnits>,
 
         #[serde(rename = "shortClientExtensions", skip_serializing_if = "Option::is_none")]
         pub short_client_extensions: Option<ClientExtensions>,
@@ -582,13 +567,26 @@ pub mod close_position {
         /// string "ALL", the string "NONE", or a DecimalNumber representing how
         /// many units of the long position to close using a PositionCloseout
         /// MarketOrder. The units specified must always be positive.
-        /// - param String
+        /// - param CloseUnits
         /// - return ClosePositionRequest
-        pub fn with_long_units(mut self, x: String) -> Self {
+        pub fn with_long_units(mut self, x: CloseUnits) -> Self {
             self.body.long_units = Some(x);
             self
         }
 
+        /// Close `fraction` of the long side of `position`, truncated to
+        /// `precision` decimals (the instrument's tradeUnitsPrecision). A
+        /// fraction of 1.0 or more closes the whole side.
+        /// - param Position, f32, u32
+        /// - return ClosePositionRequest
+        pub fn with_long_close_fraction(self, position: &Position, fraction: f32, precision: u32) -> Self {
+            let units = match position.long {
+                Some(ref side) => CloseUnits::position_side_fraction(side, fraction, precision),
+                None => CloseUnits::None,
+            };
+            self.with_long_units(units)
+        }
+
         /// A ClientExtensions object allows a client to attach a clientID, tag
         /// and comment to Orders and Trades in their Account.  Do not set,
         /// modify, or delete this field if your account is associated with MT4.
@@ -603,13 +601,26 @@ pub mod close_position {
         /// string "ALL", the string "NONE", or a DecimalNumber representing how
         /// many units of the short position to close using a PositionCloseout
         /// MarketOrder. The units specified must always be positive.
-        /// - param String
+        /// - param CloseUnits
         /// - return ClosePositionRequest
-        pub fn with_short_units(mut self, x: String) -> Self {
+        pub fn with_short_units(mut self, x: CloseUnits) -> Self {
             self.body.short_units = Some(x);
             self
         }
 
+        /// Close `fraction` of the short side of `position`, truncated to
+        /// `precision` decimals (the instrument's tradeUnitsPrecision). A
+        /// fraction of 1.0 or more closes the whole side.
+        /// - param Position, f32, u32
+        /// - return ClosePositionRequest
+        pub fn with_short_close_fraction(self, position: &Position, fraction: f32, precision: u32) -> Self {
+            let units = match position.short {
+                Some(ref side) => CloseUnits::position_side_fraction(side, fraction, precision),
+                None => CloseUnits::None,
+            };
+            self.with_short_units(units)
+        }
+
         /// A ClientExtensions object allows a client to attach a clientID, tag
         /// and comment to Orders and Trades in their Account.  Do not set,
         /// modify, or delete this field if your account is associated with MT4.
@@ -622,26 +633,23 @@ pub mod close_position {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<ClosePositionResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ClosePositionResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ClosePositionResponse>(response).await,
             }
         }
     }
@@ -649,7 +657,7 @@ pub mod close_position {
     pub type ClosePositionResponse = ClosePositionResponse200Body;
 
     /// The Position closeout request has been successfully processed.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ClosePositionResponse200Header {
         /// A link to the Position that was just closed out
         #[serde(rename = "Location", skip_serializing_if = "Option::is_none")]
@@ -660,7 +668,7 @@ pub mod close_position {
     }
 
     /// The Position closeout request has been successfully processed.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ClosePositionResponse200Body {
         /// A MarketOrderTransaction represents the creation of a Market Order in
         /// the user's account. A Market Order is an Order that is filled
@@ -879,21 +887,16 @@ pub mod list_trades {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<ListTradesResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<ListTradesResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListTradesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListTradesResponse>(response).await,
             }
         }
     }
@@ -901,7 +904,7 @@ pub mod list_trades {
     pub type ListTradesResponse = ListTradesResponse200Body;
 
     /// The list of Trades requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListTradesResponse200Header {
         /// A link to the next page of Trades if the results were paginated
         #[serde(rename = "Link", skip_serializing_if = "Option::is_none")]
@@ -912,7 +915,7 @@ pub mod list_trades {
     }
 
     /// The list of Trades requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListTradesResponse200Body {
         /// The list of Trade detail objects
         #[serde(rename = "trades", skip_serializing_if = "Option::is_none")]
@@ -1031,24 +1034,19 @@ pub mod list_open_trades {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<ListOpenTradesResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListOpenTradesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListOpenTradesResponse>(response).await,
             }
         }
     }
@@ -1056,7 +1054,7 @@ pub mod list_open_trades {
     pub type ListOpenTradesResponse = ListOpenTradesResponse200Body;
 
     /// The Account's list of open Trades is provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOpenTradesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -1064,7 +1062,7 @@ pub mod list_open_trades {
     }
 
     /// The Account's list of open Trades is provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOpenTradesResponse200Body {
         /// The Account's list of open Trades
         #[serde(rename = "trades", skip_serializing_if = "Option::is_none")]
@@ -1199,24 +1197,19 @@ pub mod get_trade {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetTradeResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetTradeResponse, Box<dyn Error>> {
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{tradeSpecifier}", &self.path.trade_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetTradeResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetTradeResponse>(response).await,
             }
         }
     }
@@ -1224,7 +1217,7 @@ pub mod get_trade {
     pub type GetTradeResponse = GetTradeResponse200Body;
 
     /// The details for the requested Trade is provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTradeResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -1232,7 +1225,7 @@ pub mod get_trade {
     }
 
     /// The details for the requested Trade is provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTradeResponse200Body {
         /// The specification of a Trade within an Account. This includes the full
         /// representation of the Trade's dependent Orders in addition to the IDs
@@ -1252,7 +1245,7 @@ pub mod close_trade {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, CloseUnits, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -1291,7 +1284,7 @@ pub mod close_trade {
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestBody {
         #[serde(rename = "units", skip_serializing_if = "Option::is_none")]
-        pub units: Option<String>,
+        pub units: Option<CloseUnits>,
     }
     impl RequestBody {
         fn new() -> RequestBody {
@@ -1378,32 +1371,38 @@ pub mod close_trade {
         /// Close using a TradeClose MarketOrder. The units specified must always
         /// be positive, and the magnitude of the value cannot exceed the
         /// magnitude of the Trade's open units.
-        /// - param String
+        /// - param CloseUnits
         /// - return CloseTradeRequest
-        pub fn with_units(mut self, x: String) -> Self {
+        pub fn with_units(mut self, x: CloseUnits) -> Self {
             self.body.units = Some(x);
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<CloseTradeResponse, Box<dyn Error>> {
+        /// Close `fraction` of the current units of `trade`, truncated to
+        /// `precision` decimals (the instrument's tradeUnitsPrecision). A
+        /// fraction of 1.0 or more closes the whole Trade.
+        /// - param Trade, f32, u32
+        /// - return CloseTradeRequest
+        pub fn with_close_fraction(self, trade: &Trade, fraction: f32, precision: u32) -> Self {
+            self.with_units(CloseUnits::trade_fraction(trade, fraction, precision))
+        }
+
+        pub async fn remote(self, client: &Client<Trading>) -> Result<CloseTradeResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{tradeSpecifier}", &self.path.trade_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<CloseTradeResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<CloseTradeResponse>(response).await,
             }
         }
     }
@@ -1411,7 +1410,7 @@ pub mod close_trade {
     pub type CloseTradeResponse = CloseTradeResponse200Body;
 
     /// The Trade has been closed as requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CloseTradeResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -1419,7 +1418,7 @@ pub mod close_trade {
     }
 
     /// The Trade has been closed as requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CloseTradeResponse200Body {
         /// A MarketOrderTransaction represents the creation of a Market Order in
         /// the user's account. A Market Order is an Order that is filled
@@ -1454,7 +1453,7 @@ pub mod set_trade_client_extensions {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -1591,26 +1590,23 @@ pub mod set_trade_client_extensions {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<SetTradeClientExtensionsResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{tradeSpecifier}", &self.path.trade_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<SetTradeClientExtensionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<SetTradeClientExtensionsResponse>(response).await,
             }
         }
     }
@@ -1618,7 +1614,7 @@ pub mod set_trade_client_extensions {
     pub type SetTradeClientExtensionsResponse = SetTradeClientExtensionsResponse200Body;
 
     /// The Trade's Client Extensions have been updated as requested.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetTradeClientExtensionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -1626,7 +1622,7 @@ pub mod set_trade_client_extensions {
     }
 
     /// The Trade's Client Extensions have been updated as requested.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetTradeClientExtensionsResponse200Body {
         /// A TradeClientExtensionsModifyTransaction represents the modification
         /// of a Trade's Client Extensions.
@@ -1652,7 +1648,7 @@ pub mod set_trade_dependent_orders {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -1819,26 +1815,23 @@ pub mod set_trade_dependent_orders {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<SetTradeDependentOrdersResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{tradeSpecifier}", &self.path.trade_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<SetTradeDependentOrdersResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<SetTradeDependentOrdersResponse>(response).await,
             }
         }
     }
@@ -1846,7 +1839,7 @@ pub mod set_trade_dependent_orders {
     pub type SetTradeDependentOrdersResponse = SetTradeDependentOrdersResponse200Body;
 
     /// The Trade's dependent Orders have been modified as requested.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetTradeDependentOrdersResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -1854,7 +1847,7 @@ pub mod set_trade_dependent_orders {
     }
 
     /// The Trade's dependent Orders have been modified as requested.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetTradeDependentOrdersResponse200Body {
         /// An OrderCancelTransaction represents the cancellation of an Order in
         /// the client's Account.
@@ -2004,21 +1997,16 @@ pub mod list_accounts {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<ListAccountsResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<ListAccountsResponse, Box<dyn Error>> {
             let uri = self.uri.clone();
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListAccountsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListAccountsResponse>(response).await,
             }
         }
     }
@@ -2026,7 +2014,7 @@ pub mod list_accounts {
     pub type ListAccountsResponse = ListAccountsResponse200Body;
 
     /// The list of authorized Accounts has been provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListAccountsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2034,7 +2022,7 @@ pub mod list_accounts {
     }
 
     /// The list of authorized Accounts has been provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListAccountsResponse200Body {
         /// The list of Accounts the client is authorized to access and their
         /// associated properties.
@@ -2152,21 +2140,16 @@ pub mod get_account {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetAccountResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetAccountResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetAccountResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetAccountResponse>(response).await,
             }
         }
     }
@@ -2174,7 +2157,7 @@ pub mod get_account {
     pub type GetAccountResponse = GetAccountResponse200Body;
 
     /// The full Account details are provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2182,7 +2165,7 @@ pub mod get_account {
     }
 
     /// The full Account details are provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountResponse200Body {
         /// The full details of a client's Account. This includes full open Trade,
         /// open Position and pending Order representation.
@@ -2302,24 +2285,19 @@ pub mod get_account_summary {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetAccountSummaryResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetAccountSummaryResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetAccountSummaryResponse>(response).await,
             }
         }
     }
@@ -2327,7 +2305,7 @@ pub mod get_account_summary {
     pub type GetAccountSummaryResponse = GetAccountSummaryResponse200Body;
 
     /// The Account summary  are provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountSummaryResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2335,7 +2313,7 @@ pub mod get_account_summary {
     }
 
     /// The Account summary  are provided
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountSummaryResponse200Body {
         /// A summary representation of a client's Account. The AccountSummary
         /// does not provide to full specification of pending Orders, open Trades
@@ -2458,24 +2436,19 @@ pub mod get_account_instruments {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetAccountInstrumentsResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetAccountInstrumentsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetAccountInstrumentsResponse>(response).await,
             }
         }
     }
@@ -2483,7 +2456,7 @@ pub mod get_account_instruments {
     pub type GetAccountInstrumentsResponse = GetAccountInstrumentsResponse200Body;
 
     /// The list of tradeable instruments for the Account has been provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountInstrumentsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2491,7 +2464,7 @@ pub mod get_account_instruments {
     }
 
     /// The list of tradeable instruments for the Account has been provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountInstrumentsResponse200Body {
         /// The requested list of instruments.
         #[serde(rename = "instruments", skip_serializing_if = "Option::is_none")]
@@ -2509,7 +2482,7 @@ pub mod configure_account {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -2643,23 +2616,20 @@ pub mod configure_account {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<ConfigureAccountResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Configure).await?;
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .patch(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PATCH, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ConfigureAccountResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ConfigureAccountResponse>(response).await,
             }
         }
     }
@@ -2667,7 +2637,7 @@ pub mod configure_account {
     pub type ConfigureAccountResponse = ConfigureAccountResponse200Body;
 
     /// The Account was configured successfully.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ConfigureAccountResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2675,7 +2645,7 @@ pub mod configure_account {
     }
 
     /// The Account was configured successfully.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ConfigureAccountResponse200Body {
         /// A ClientConfigureTransaction represents the configuration of an
         /// Account by a client.
@@ -2810,24 +2780,19 @@ pub mod get_account_changes {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetAccountChangesResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetAccountChangesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetAccountChangesResponse>(response).await,
             }
         }
     }
@@ -2835,7 +2800,7 @@ pub mod get_account_changes {
     pub type GetAccountChangesResponse = GetAccountChangesResponse200Body;
 
     /// The Account state and changes are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountChangesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -2843,7 +2808,7 @@ pub mod get_account_changes {
     }
 
     /// The Account state and changes are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountChangesResponse200Body {
         /// An AccountChanges Object is used to represent the changes to an
         /// Account's Orders, Trades and Positions since a specified Account
@@ -3044,24 +3009,19 @@ pub mod list_transactions {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<ListTransactionsResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListTransactionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListTransactionsResponse>(response).await,
             }
         }
     }
@@ -3069,7 +3029,7 @@ pub mod list_transactions {
     pub type ListTransactionsResponse = ListTransactionsResponse200Body;
 
     /// The requested time range of Transaction pages are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListTransactionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -3077,7 +3037,7 @@ pub mod list_transactions {
     }
 
     /// The requested time range of Transaction pages are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListTransactionsResponse200Body {
         /// The starting time provided in the request.
         /// format: The RFC 3339 representation is a string conforming to
@@ -3247,27 +3207,22 @@ pub mod get_transaction {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetTransactionResponse, Box<dyn Error>> {
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{transactionID}", &self.path.transaction_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetTransactionResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetTransactionResponse>(response).await,
             }
         }
     }
@@ -3275,7 +3230,7 @@ pub mod get_transaction {
     pub type GetTransactionResponse = GetTransactionResponse200Body;
 
     /// The details of the requested Transaction are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -3283,7 +3238,7 @@ pub mod get_transaction {
     }
 
     /// The details of the requested Transaction are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionResponse200Body {
         /// The base Transaction specification. Specifies properties that are
         /// common between all Transaction.
@@ -3443,24 +3398,19 @@ pub mod get_transaction_range {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetTransactionRangeResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetTransactionRangeResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetTransactionRangeResponse>(response).await,
             }
         }
     }
@@ -3468,7 +3418,7 @@ pub mod get_transaction_range {
     pub type GetTransactionRangeResponse = GetTransactionRangeResponse200Body;
 
     /// The requested time range of Transactions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionRangeResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -3476,7 +3426,7 @@ pub mod get_transaction_range {
     }
 
     /// The requested time range of Transactions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionRangeResponse200Body {
         /// The list of Transactions that satisfy the request.
         #[serde(rename = "transactions", skip_serializing_if = "Option::is_none")]
@@ -3609,24 +3559,19 @@ pub mod get_transactions_since_id {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetTransactionsSinceIdResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetTransactionsSinceIdResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetTransactionsSinceIdResponse>(response).await,
             }
         }
     }
@@ -3634,7 +3579,7 @@ pub mod get_transactions_since_id {
     pub type GetTransactionsSinceIdResponse = GetTransactionsSinceIdResponse200Body;
 
     /// The requested time range of Transactions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionsSinceIdResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -3642,7 +3587,7 @@ pub mod get_transactions_since_id {
     }
 
     /// The requested time range of Transactions are provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetTransactionsSinceIdResponse200Body {
         /// The list of Transactions that satisfy the request.
         #[serde(rename = "transactions", skip_serializing_if = "Option::is_none")]
@@ -3750,24 +3695,19 @@ pub mod stream_transactions {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<StreamTransactionsResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<StreamTransactionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<StreamTransactionsResponse>(response).await,
             }
         }
     }
@@ -3775,7 +3715,7 @@ pub mod stream_transactions {
     pub type StreamTransactionsResponse = StreamTransactionsResponse200Body;
 
     /// Connecting to the Transaction Stream was successful.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct StreamTransactionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -3783,7 +3723,7 @@ pub mod stream_transactions {
     }
 
     /// Connecting to the Transaction Stream was successful.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct StreamTransactionsResponse200Body {
         /// The base Transaction specification. Specifies properties that are
         /// common between all Transaction.
@@ -3970,21 +3910,16 @@ pub mod get_prices {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetPricesResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetPricesResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetPricesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetPricesResponse>(response).await,
             }
         }
     }
@@ -3992,7 +3927,7 @@ pub mod get_prices {
     pub type GetPricesResponse = GetPricesResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPricesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -4000,7 +3935,7 @@ pub mod get_prices {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPricesResponse200Body {
         /// The list of Price objects requested.
         #[serde(rename = "prices", skip_serializing_if = "Option::is_none")]
@@ -4171,24 +4106,19 @@ pub mod stream_pricing {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<StreamPricingResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<StreamPricingResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<StreamPricingResponse>(response).await,
             }
         }
     }
@@ -4196,7 +4126,7 @@ pub mod stream_pricing {
     pub type StreamPricingResponse = StreamPricingResponse200Body;
 
     /// Connecting to the Price Stream was successful.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct StreamPricingResponse200Header {
         /// A link to the next/previous order book snapshot.
         #[serde(rename = "Link", skip_serializing_if = "Option::is_none")]
@@ -4207,7 +4137,7 @@ pub mod stream_pricing {
     }
 
     /// Connecting to the Price Stream was successful.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct StreamPricingResponse200Body {
         /// The specification of an Account-specific Price.
         #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
@@ -4246,12 +4176,18 @@ pub mod get_account_instrument_candles {
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestPath {
+        #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
+        pub account_id: Option<String>,
+
         #[serde(rename = "instrument", skip_serializing_if = "Option::is_none")]
         pub instrument: Option<String>,
     }
     impl RequestPath {
         fn new() -> RequestPath {
-            RequestPath { instrument: None }
+            RequestPath {
+                account_id: None,
+                instrument: None,
+            }
         }
     }
 
@@ -4356,6 +4292,16 @@ pub mod get_account_instrument_candles {
             self
         }
 
+        /// Account Identifier
+        /// format: "-"-delimited string with format
+        /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
+        /// - param String
+        /// - return GetAccountInstrumentCandlesRequest
+        pub fn with_account_id(mut self, x: String) -> Self {
+            self.path.account_id = Some(x);
+            self
+        }
+
         /// Name of the Instrument
         /// format: A string containing the base currency and quote currency delimited by
         /// a "_".
@@ -4502,24 +4448,22 @@ pub mod get_account_instrument_candles {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetAccountInstrumentCandlesResponse, Box<dyn Error>> {
-            let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let uri = self.uri
+                .clone()
+                .replace("{accountID}", &self.path.account_id.unwrap())
+                .replace("{instrument}", &self.path.instrument.unwrap());
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetAccountInstrumentCandlesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetAccountInstrumentCandlesResponse>(response).await,
             }
         }
     }
@@ -4527,7 +4471,7 @@ pub mod get_account_instrument_candles {
     pub type GetAccountInstrumentCandlesResponse = GetAccountInstrumentCandlesResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountInstrumentCandlesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -4535,7 +4479,7 @@ pub mod get_account_instrument_candles {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetAccountInstrumentCandlesResponse200Body {
         /// The instrument whose Prices are represented by the candlesticks.
         /// format: A string containing the base currency and quote currency delimited by
@@ -4557,7 +4501,7 @@ pub mod create_market_order {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, OrderIntent, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -4670,23 +4614,23 @@ pub mod create_market_order {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<CreateMarketOrderResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(
+                self.path.account_id.as_deref().unwrap_or_default(),
+                RiskAction::Order(self.body.order.as_ref().map(OrderIntent::from))
+            ).await?;
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .post(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::POST, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<CreateMarketOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<CreateMarketOrderResponse>(response).await,
             }
         }
     }
@@ -4694,7 +4638,7 @@ pub mod create_market_order {
     pub type CreateMarketOrderResponse = CreateMarketOrderResponse200Body;
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateMarketOrderResponse200Header {
         /// A link to the Order that was just created
         #[serde(rename = "Location", skip_serializing_if = "Option::is_none")]
@@ -4705,7 +4649,7 @@ pub mod create_market_order {
     }
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateMarketOrderResponse200Body {
         /// The base Transaction specification. Specifies properties that are
         /// common between all Transaction.
@@ -4744,7 +4688,7 @@ pub mod create_limit_order {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, OrderIntent, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -4857,23 +4801,23 @@ pub mod create_limit_order {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<CreateLimitOrderResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(
+                self.path.account_id.as_deref().unwrap_or_default(),
+                RiskAction::Order(self.body.order.as_ref().map(OrderIntent::from))
+            ).await?;
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .post(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::POST, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<CreateLimitOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<CreateLimitOrderResponse>(response).await,
             }
         }
     }
@@ -4881,7 +4825,7 @@ pub mod create_limit_order {
     pub type CreateLimitOrderResponse = CreateLimitOrderResponse200Body;
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateLimitOrderResponse200Header {
         /// A link to the Order that was just created
         #[serde(rename = "Location", skip_serializing_if = "Option::is_none")]
@@ -4892,7 +4836,7 @@ pub mod create_limit_order {
     }
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateLimitOrderResponse200Body {
         /// The base Transaction specification. Specifies properties that are
         /// common between all Transaction.
@@ -4931,7 +4875,7 @@ pub mod create_stop_order {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, OrderIntent, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -5044,23 +4988,23 @@ pub mod create_stop_order {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<CreateStopOrderResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(
+                self.path.account_id.as_deref().unwrap_or_default(),
+                RiskAction::Order(self.body.order.as_ref().map(OrderIntent::from))
+            ).await?;
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .post(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::POST, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<CreateStopOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<CreateStopOrderResponse>(response).await,
             }
         }
     }
@@ -5068,7 +5012,7 @@ pub mod create_stop_order {
     pub type CreateStopOrderResponse = CreateStopOrderResponse200Body;
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateStopOrderResponse200Header {
         /// A link to the Order that was just created
         #[serde(rename = "Location", skip_serializing_if = "Option::is_none")]
@@ -5079,7 +5023,7 @@ pub mod create_stop_order {
     }
 
     /// The Order was created as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CreateStopOrderResponse200Body {
         /// The base Transaction specification. Specifies properties that are
         /// common between all Transaction.
@@ -5286,21 +5230,16 @@ pub mod list_orders {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<ListOrdersResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<ListOrdersResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListOrdersResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListOrdersResponse>(response).await,
             }
         }
     }
@@ -5308,7 +5247,7 @@ pub mod list_orders {
     pub type ListOrdersResponse = ListOrdersResponse200Body;
 
     /// The list of Orders requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOrdersResponse200Header {
         /// A link to the next page of results if the results were paginated
         #[serde(rename = "Link", skip_serializing_if = "Option::is_none")]
@@ -5319,7 +5258,7 @@ pub mod list_orders {
     }
 
     /// The list of Orders requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListOrdersResponse200Body {
         /// The list of Order detail objects
         #[serde(rename = "orders", skip_serializing_if = "Option::is_none")]
@@ -5438,24 +5377,19 @@ pub mod list_pending_orders {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<ListPendingOrdersResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{accountID}", &self.path.account_id.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ListPendingOrdersResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ListPendingOrdersResponse>(response).await,
             }
         }
     }
@@ -5463,7 +5397,7 @@ pub mod list_pending_orders {
     pub type ListPendingOrdersResponse = ListPendingOrdersResponse200Body;
 
     /// List of pending Orders for the Account
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListPendingOrdersResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -5471,7 +5405,7 @@ pub mod list_pending_orders {
     }
 
     /// List of pending Orders for the Account
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ListPendingOrdersResponse200Body {
         /// The list of pending Order details
         #[serde(rename = "orders", skip_serializing_if = "Option::is_none")]
@@ -5606,24 +5540,19 @@ pub mod get_order {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetOrderResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetOrderResponse, Box<dyn Error>> {
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{orderSpecifier}", &self.path.order_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetOrderResponse>(response).await,
             }
         }
     }
@@ -5631,7 +5560,7 @@ pub mod get_order {
     pub type GetOrderResponse = GetOrderResponse200Body;
 
     /// The details of the Order requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetOrderResponse200Header {
         /// A link to the next page of results if the results were paginated
         #[serde(rename = "Link", skip_serializing_if = "Option::is_none")]
@@ -5642,7 +5571,7 @@ pub mod get_order {
     }
 
     /// The details of the Order requested
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetOrderResponse200Body {
         /// The base Order definition specifies the properties that are common to
         /// all Orders.
@@ -5661,7 +5590,7 @@ pub mod replace_order {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, OrderIntent, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -5803,25 +5732,25 @@ pub mod replace_order {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<ReplaceOrderResponse, Box<dyn Error>> {
+        pub async fn remote(self, client: &Client<Trading>) -> Result<ReplaceOrderResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(
+                self.path.account_id.as_deref().unwrap_or_default(),
+                RiskAction::Order(self.body.order.as_ref().and_then(OrderIntent::from_replacement))
+            ).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{orderSpecifier}", &self.path.order_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<ReplaceOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<ReplaceOrderResponse>(response).await,
             }
         }
     }
@@ -5829,7 +5758,7 @@ pub mod replace_order {
     pub type ReplaceOrderResponse = ReplaceOrderResponse200Body;
 
     /// The Order was successfully cancelled and replaced
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ReplaceOrderResponse200Header {
         /// A link to the replacing Order
         #[serde(rename = "Location", skip_serializing_if = "Option::is_none")]
@@ -5840,7 +5769,7 @@ pub mod replace_order {
     }
 
     /// The Order was successfully cancelled and replaced
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct ReplaceOrderResponse200Body {
         /// An OrderCancelTransaction represents the cancellation of an Order in
         /// the client's Account.
@@ -5886,7 +5815,7 @@ pub mod cancel_order {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -6015,25 +5944,22 @@ pub mod cancel_order {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<CancelOrderResponse, Box<dyn Error>> {
+        pub async fn remote(self, client: &Client<Trading>) -> Result<CancelOrderResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{orderSpecifier}", &self.path.order_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<CancelOrderResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<CancelOrderResponse>(response).await,
             }
         }
     }
@@ -6041,7 +5967,7 @@ pub mod cancel_order {
     pub type CancelOrderResponse = CancelOrderResponse200Body;
 
     /// The Order was cancelled as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CancelOrderResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -6049,7 +5975,7 @@ pub mod cancel_order {
     }
 
     /// The Order was cancelled as specified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct CancelOrderResponse200Body {
         /// An OrderCancelTransaction represents the cancellation of an Order in
         /// the client's Account.
@@ -6072,7 +5998,7 @@ pub mod set_order_client_extensions {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{Client, RiskAction, Trading};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -6224,26 +6150,23 @@ pub mod set_order_client_extensions {
 
         pub async fn remote(
             self,
-            client: &Client
+            client: &Client<Trading>
         ) -> Result<SetOrderClientExtensionsResponse, Box<dyn Error>> {
+            client.authorize(self.path.account_id.as_deref().unwrap_or_default())?;
+            client.guard(self.path.account_id.as_deref().unwrap_or_default(), RiskAction::Reduce).await?;
             let uri = self.uri
                 .clone()
                 .replace("{accountID}", &self.path.account_id.unwrap())
                 .replace("{orderSpecifier}", &self.path.order_specifier.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .put(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::PUT, &url)
                 .query(&self.query)
                 .json::<RequestBody>(&self.body)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<SetOrderClientExtensionsResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<SetOrderClientExtensionsResponse>(response).await,
             }
         }
     }
@@ -6251,7 +6174,7 @@ pub mod set_order_client_extensions {
     pub type SetOrderClientExtensionsResponse = SetOrderClientExtensionsResponse200Body;
 
     /// The Order's Client Extensions were successfully modified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetOrderClientExtensionsResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -6259,7 +6182,7 @@ pub mod set_order_client_extensions {
     }
 
     /// The Order's Client Extensions were successfully modified
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct SetOrderClientExtensionsResponse200Body {
         /// A OrderClientExtensionsModifyTransaction represents the modification
         /// of an Order's Client Extensions.
@@ -0,0 +1,363 @@
+use std::error::Error;
+use std::future::Future;
+
+use crate::*;
+
+/// The identifier of an OANDA account, such as "101-004-1234567-001".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountId(String);
+
+impl AccountId {
+    pub fn new(x: String) -> AccountId {
+        AccountId(x)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for AccountId {
+    fn from(x: String) -> AccountId {
+        AccountId(x)
+    }
+}
+
+impl From<&str> for AccountId {
+    fn from(x: &str) -> AccountId {
+        AccountId(x.to_string())
+    }
+}
+
+impl std::fmt::Display for AccountId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<C> Client<C> {
+    /// A handle for calling the endpoints of one account without passing
+    /// its ID every time.
+    pub fn account<A: Into<AccountId>>(&self, id: A) -> AccountHandle<'_, C> {
+        AccountHandle {
+            client: self,
+            id: id.into(),
+        }
+    }
+}
+
+/// A `Client` bound to one account.
+///
+/// Every method fills in the account ID. The methods taking a request
+/// builder accept every option of that endpoint; the others are shortcuts
+/// for the common cases. Endpoints that change the account are only
+/// available through a `Client<Trading>`.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn run(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let account = client.account("101-004-1234567-001");
+/// let summary = account.summary().await?;
+/// let trades = account.trades().await?;
+/// let order = MarketOrder::new().with_instrument("EUR_USD".to_string()).with_units(100.0);
+/// account.place(order).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountHandle<'a, C = Trading> {
+    client: &'a Client<C>,
+    id: AccountId,
+}
+
+// A generated request with an account ID path parameter.
+pub(crate) trait AccountRequest {
+    fn for_account(self, x: String) -> Self;
+}
+
+macro_rules! account_requests {
+    (@impl $($func:ident($request:ident) -> $response:ident),*) => {
+      $(
+        pub async fn $func(&self, x: $request) -> Result<$response, Box<dyn Error>> {
+          self.bind(x).remote(self.client).await
+        }
+      )*
+    };
+    (@request $($func:ident($request:ident) -> $response:ident),*) => {
+      $(
+        impl AccountRequest for $request {
+          fn for_account(self, x: String) -> Self {
+            self.with_account_id(x)
+          }
+        }
+      )*
+    };
+    (read; $($body:tt)*) => {
+        account_requests!(@request $($body)*);
+        impl<'a, C> AccountHandle<'a, C> {
+          account_requests!(@impl $($body)*);
+        }
+    };
+    (trading; $($body:tt)*) => {
+        account_requests!(@request $($body)*);
+        impl<'a> AccountHandle<'a, Trading> {
+          account_requests!(@impl $($body)*);
+        }
+    };
+}
+
+account_requests!(read;
+                  list_trades(ListTradesRequest) -> ListTradesResponse,
+                  list_orders(ListOrdersRequest) -> ListOrdersResponse,
+                  list_transactions(ListTransactionsRequest) -> ListTransactionsResponse,
+                  prices(GetPricesRequest) -> GetPricesResponse,
+                  account_instruments(GetAccountInstrumentsRequest) -> GetAccountInstrumentsResponse,
+                  instrument_candles(GetAccountInstrumentCandlesRequest) -> GetAccountInstrumentCandlesResponse);
+
+impl<'a, C> AccountHandle<'a, C> {
+    pub fn id(&self) -> &AccountId {
+        &self.id
+    }
+
+    pub fn client(&self) -> &'a Client<C> {
+        self.client
+    }
+
+    fn bind<R: AccountRequest>(&self, x: R) -> R {
+        x.for_account(self.id.to_string())
+    }
+
+    /// The full details of the account.
+    pub async fn details(&self) -> Result<GetAccountResponse, Box<dyn Error>> {
+        GetAccountRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    pub async fn summary(&self) -> Result<GetAccountSummaryResponse, Box<dyn Error>> {
+        GetAccountSummaryRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    /// The tradeable instruments of the account.
+    pub async fn instruments(&self) -> Result<GetAccountInstrumentsResponse, Box<dyn Error>> {
+        self.account_instruments(GetAccountInstrumentsRequest::new()).await
+    }
+
+    /// The changes to the account since a transaction.
+    pub async fn changes_since(
+        &self,
+        transaction_id: String
+    ) -> Result<GetAccountChangesResponse, Box<dyn Error>> {
+        GetAccountChangesRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_since_transaction_id(transaction_id)
+            .remote(self.client).await
+    }
+
+    /// The open trades.
+    pub async fn trades(&self) -> Result<ListOpenTradesResponse, Box<dyn Error>> {
+        ListOpenTradesRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    /// A trade by ID or "@" followed by its client ID.
+    pub async fn trade(&self, specifier: String) -> Result<GetTradeResponse, Box<dyn Error>> {
+        GetTradeRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_trade_specifier(specifier)
+            .remote(self.client).await
+    }
+
+    /// Every position the account has held.
+    pub async fn positions(&self) -> Result<ListPositionsResponse, Box<dyn Error>> {
+        ListPositionsRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    pub async fn open_positions(&self) -> Result<ListOpenPositionsResponse, Box<dyn Error>> {
+        ListOpenPositionsRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    pub async fn position(&self, instrument: String) -> Result<GetPositionResponse, Box<dyn Error>> {
+        GetPositionRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_instrument(instrument)
+            .remote(self.client).await
+    }
+
+    /// The pending orders.
+    pub async fn orders(&self) -> Result<ListPendingOrdersResponse, Box<dyn Error>> {
+        ListPendingOrdersRequest::new().with_account_id(self.id.to_string()).remote(self.client).await
+    }
+
+    /// An order by ID or "@" followed by its client ID.
+    pub async fn order(&self, specifier: String) -> Result<GetOrderResponse, Box<dyn Error>> {
+        GetOrderRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_order_specifier(specifier)
+            .remote(self.client).await
+    }
+
+    pub async fn transaction(&self, id: String) -> Result<GetTransactionResponse, Box<dyn Error>> {
+        GetTransactionRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_transaction_id(id)
+            .remote(self.client).await
+    }
+
+    /// The transactions after (not including) `id`.
+    pub async fn transactions_since(
+        &self,
+        id: String
+    ) -> Result<GetTransactionsSinceIdResponse, Box<dyn Error>> {
+        GetTransactionsSinceIdRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_id(id)
+            .remote(self.client).await
+    }
+
+    /// The transactions from `from` to `to`, both included.
+    pub async fn transaction_range(
+        &self,
+        from: String,
+        to: String
+    ) -> Result<GetTransactionRangeResponse, Box<dyn Error>> {
+        GetTransactionRangeRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_from(from)
+            .with_to(to)
+            .remote(self.client).await
+    }
+
+    /// The current prices of `instruments`.
+    pub async fn pricing(&self, instruments: &[&str]) -> Result<GetPricesResponse, Box<dyn Error>> {
+        self.prices(GetPricesRequest::new().with_instruments(instruments.join(","))).await
+    }
+
+    /// The candles of an instrument, priced for the account.
+    pub async fn candles(
+        &self,
+        instrument: String,
+        granularity: CandlestickGranularity
+    ) -> Result<GetAccountInstrumentCandlesResponse, Box<dyn Error>> {
+        self.instrument_candles(
+            GetAccountInstrumentCandlesRequest::new()
+                .with_instrument(instrument)
+                .with_granularity(granularity)
+        ).await
+    }
+
+    /// Follow the prices of `instruments`, see `PriceStream`.
+    pub async fn price_stream(&self, instruments: &[&str]) -> Result<PriceStream, Box<dyn Error>> {
+        PriceStream::connect(self.client, self.id.clone(), instruments).await
+    }
+
+    /// Follow the account's transactions, see `TransactionStream`.
+    pub async fn transaction_stream(&self) -> Result<TransactionStream, Box<dyn Error>> {
+        TransactionStream::connect(self.client, self.id.clone()).await
+    }
+}
+
+account_requests!(trading;
+                  configure(ConfigureAccountRequest) -> ConfigureAccountResponse,
+                  close_position_with(ClosePositionRequest) -> ClosePositionResponse,
+                  close_trade_with(CloseTradeRequest) -> CloseTradeResponse,
+                  replace_order_with(ReplaceOrderRequest) -> ReplaceOrderResponse,
+                  set_trade_dependent_orders(SetTradeDependentOrdersRequest) -> SetTradeDependentOrdersResponse,
+                  set_trade_client_extensions(SetTradeClientExtensionsRequest) -> SetTradeClientExtensionsResponse,
+                  set_order_client_extensions(SetOrderClientExtensionsRequest) -> SetOrderClientExtensionsResponse);
+
+impl<'a> AccountHandle<'a, Trading> {
+    /// Submit a `MarketOrder`, `LimitOrder` or `StopOrder`.
+    pub async fn place<O: PlaceOrder>(&self, order: O) -> Result<O::Response, Box<dyn Error>> {
+        order.place(self.client, self.id.to_string()).await
+    }
+
+    /// Cancel a pending order by ID or "@" followed by its client ID.
+    pub async fn cancel_order(&self, specifier: String) -> Result<CancelOrderResponse, Box<dyn Error>> {
+        CancelOrderRequest::new()
+            .with_account_id(self.id.to_string())
+            .with_order_specifier(specifier)
+            .remote(self.client).await
+    }
+
+    /// Replace a pending order with a new one.
+    pub async fn replace_order(
+        &self,
+        specifier: String,
+        order: OrderRequest
+    ) -> Result<ReplaceOrderResponse, Box<dyn Error>> {
+        self.replace_order_with(
+            ReplaceOrderRequest::new().with_order_specifier(specifier).with_order(order)
+        ).await
+    }
+
+    /// Close all or part of a trade.
+    pub async fn close_trade(
+        &self,
+        specifier: String,
+        units: CloseUnits
+    ) -> Result<CloseTradeResponse, Box<dyn Error>> {
+        self.close_trade_with(CloseTradeRequest::new().with_trade_specifier(specifier).with_units(units)).await
+    }
+
+    /// Close the long and/or short side of a position.
+    pub async fn close_position(
+        &self,
+        instrument: String,
+        long: CloseUnits,
+        short: CloseUnits
+    ) -> Result<ClosePositionResponse, Box<dyn Error>> {
+        self.close_position_with(
+            ClosePositionRequest::new()
+                .with_instrument(instrument)
+                .with_long_units(long)
+                .with_short_units(short)
+        ).await
+    }
+}
+
+/// An order `AccountHandle::place` can submit.
+pub trait PlaceOrder {
+    type Response;
+    fn place(
+        self,
+        client: &Client<Trading>,
+        account_id: String
+    ) -> impl Future<Output = Result<Self::Response, Box<dyn Error>>>;
+}
+
+macro_rules! place_order {
+    ($($order:ident($request:ident) -> $response:ident),*) => {
+      $(
+        impl PlaceOrder for $order {
+          type Response = $response;
+          fn place(
+            self,
+            client: &Client<Trading>,
+            account_id: String
+          ) -> impl Future<Output = Result<$response, Box<dyn Error>>> {
+            $request::new().with_account_id(account_id).with_order(self).remote(client)
+          }
+        }
+      )*
+    };
+}
+
+place_order!(MarketOrder(CreateMarketOrderRequest) -> CreateMarketOrderResponse,
+             LimitOrder(CreateLimitOrderRequest) -> CreateLimitOrderResponse,
+             StopOrder(CreateStopOrderRequest) -> CreateStopOrderResponse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_in_the_account_id() {
+        let client = Client::new_read_only("api-fxpractice.oanda.com".to_string(), String::new());
+        let account = client.account("101-004-1234567-001");
+        let trades = serde_json::to_value(account.bind(ListTradesRequest::new())).unwrap();
+        assert_eq!(trades["path"]["accountID"], "101-004-1234567-001");
+        let candles = serde_json::to_value(
+            account.bind(GetAccountInstrumentCandlesRequest::new().with_instrument("EUR_USD".to_string()))
+        ).unwrap();
+        assert_eq!(candles["path"]["accountID"], "101-004-1234567-001");
+        assert_eq!(candles["path"]["instrument"], "EUR_USD");
+    }
+}
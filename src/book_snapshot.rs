@@ -0,0 +1,354 @@
+use chrono::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::*;
+
+/// Why an `OrderBook` or `PositionBook` could not be analysed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BookError {
+    /// The book has no `time`, `price` or `bucketWidth`.
+    MissingField(&'static str),
+    /// The bucket width is not a positive number.
+    InvalidBucketWidth(f32),
+    /// A bucket has no `price`.
+    MissingBucketPrice,
+    /// Two snapshots with different bucket widths cannot be compared.
+    WidthMismatch(f32, f32),
+}
+
+impl std::fmt::Display for BookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BookError::MissingField(x) => write!(f, "book has no {}", x),
+            BookError::InvalidBucketWidth(x) => write!(f, "invalid bucket width {}", x),
+            BookError::MissingBucketPrice => write!(f, "book bucket has no price"),
+            BookError::WidthMismatch(a, b) => {
+                write!(f, "cannot compare books with bucket widths {} and {}", a, b)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {}
+
+/// One price bucket of a book. `price` is the lower bound of the bucket,
+/// which covers `[price, price + bucket_width)`; `long` and `short` are
+/// percentages of all orders or positions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookLevel {
+    pub price: f32,
+    pub long: f32,
+    pub short: f32,
+}
+
+impl BookLevel {
+    /// `long + short`.
+    pub fn total(&self) -> f32 {
+        self.long + self.short
+    }
+}
+
+/// How the long and short percentages split around the current price.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BookDistribution {
+    /// Buckets entirely above the bucket holding the current price.
+    pub long_above: f32,
+    pub short_above: f32,
+    /// The bucket holding the current price.
+    pub long_at: f32,
+    pub short_at: f32,
+    /// Buckets entirely below the bucket holding the current price.
+    pub long_below: f32,
+    pub short_below: f32,
+}
+
+/// A validated order book or position book snapshot.
+///
+/// Works the same for both books since their buckets carry the same data.
+/// Buckets are kept sorted by price and are identified by their index,
+/// `price / bucket_width` rounded, so that float noise in the bucket prices
+/// never splits or merges buckets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookSnapshot {
+    pub instrument: Option<String>,
+    pub time: DateTime<Utc>,
+    pub price: f32,
+    pub bucket_width: f32,
+    levels: Vec<BookLevel>,
+}
+
+impl BookSnapshot {
+    fn build(
+        instrument: Option<String>,
+        time: Option<DateTime<Utc>>,
+        price: Option<f32>,
+        bucket_width: Option<f32>,
+        buckets: impl Iterator<Item = (Option<f32>, Option<f32>, Option<f32>)>
+    ) -> Result<BookSnapshot, BookError> {
+        let time = time.ok_or(BookError::MissingField("time"))?;
+        let price = price.ok_or(BookError::MissingField("price"))?;
+        let bucket_width = bucket_width.ok_or(BookError::MissingField("bucketWidth"))?;
+        if !(bucket_width > 0.0 && bucket_width.is_finite()) {
+            return Err(BookError::InvalidBucketWidth(bucket_width));
+        }
+        let mut levels: BTreeMap<i64, BookLevel> = BTreeMap::new();
+        for (bucket_price, long, short) in buckets {
+            let bucket_price = bucket_price.ok_or(BookError::MissingBucketPrice)?;
+            let index = (bucket_price / bucket_width).round() as i64;
+            let level = levels.entry(index).or_insert(BookLevel {
+                price: bucket_price,
+                long: 0.0,
+                short: 0.0,
+            });
+            level.long += long.unwrap_or(0.0);
+            level.short += short.unwrap_or(0.0);
+        }
+        Ok(BookSnapshot {
+            instrument,
+            time,
+            price,
+            bucket_width,
+            levels: levels.into_values().collect(),
+        })
+    }
+
+    pub fn from_order_book(book: &OrderBook) -> Result<BookSnapshot, BookError> {
+        BookSnapshot::build(
+            book.instrument.clone(),
+            book.time,
+            book.price,
+            book.bucket_width,
+            book.buckets
+                .iter()
+                .flatten()
+                .map(|b| (b.price, b.long_count_percent, b.short_count_percent))
+        )
+    }
+
+    pub fn from_position_book(book: &PositionBook) -> Result<BookSnapshot, BookError> {
+        BookSnapshot::build(
+            book.instrument.clone(),
+            book.time,
+            book.price,
+            book.bucket_width,
+            book.buckets
+                .iter()
+                .flatten()
+                .map(|b| (b.price, b.long_count_percent, b.short_count_percent))
+        )
+    }
+
+    /// Every bucket, lowest price first.
+    pub fn levels(&self) -> &[BookLevel] {
+        &self.levels
+    }
+
+    fn index(&self, price: f32) -> i64 {
+        (price / self.bucket_width).round() as i64
+    }
+
+    /// The index of the bucket holding `price`.
+    fn containing(&self, price: f32) -> i64 {
+        // Nudge by a fraction of a bucket so a price sitting exactly on a
+        // boundary is not pushed into the lower bucket by float error.
+        (price / self.bucket_width + 1e-4).floor() as i64
+    }
+
+    /// The lower bound of the bucket holding `price`.
+    pub fn bucket_price(&self, price: f32) -> f32 {
+        self.containing(price) as f32 * self.bucket_width
+    }
+
+    /// The long and short totals above, at and below the current price.
+    pub fn distribution(&self) -> BookDistribution {
+        let at = self.containing(self.price);
+        let mut result = BookDistribution::default();
+        for level in &self.levels {
+            let i = self.index(level.price);
+            let (long, short) = if i > at {
+                (&mut result.long_above, &mut result.short_above)
+            } else if i < at {
+                (&mut result.long_below, &mut result.short_below)
+            } else {
+                (&mut result.long_at, &mut result.short_at)
+            };
+            *long += level.long;
+            *short += level.short;
+        }
+        result
+    }
+
+    /// Running long and short totals of the buckets above the current price,
+    /// nearest first. Each level's `price` is that of the bucket reached.
+    pub fn cumulative_above(&self) -> Vec<BookLevel> {
+        let at = self.containing(self.price);
+        let above = self.levels.iter().filter(|l| self.index(l.price) > at);
+        cumulative(above)
+    }
+
+    /// Running long and short totals of the buckets below the current price,
+    /// nearest first. Each level's `price` is that of the bucket reached.
+    pub fn cumulative_below(&self) -> Vec<BookLevel> {
+        let at = self.containing(self.price);
+        let below = self.levels.iter().rev().filter(|l| self.index(l.price) < at);
+        cumulative(below)
+    }
+
+    /// Up to `count` buckets within `distance` of the current price with the
+    /// largest `long + short`, largest first.
+    pub fn clusters(&self, distance: f32, count: usize) -> Vec<BookLevel> {
+        let mut near: Vec<BookLevel> = self.levels
+            .iter()
+            .filter(|l| {
+                // Distance to the nearest edge of the bucket.
+                let low = l.price;
+                let high = l.price + self.bucket_width;
+                let gap = if self.price < low {
+                    low - self.price
+                } else if self.price >= high {
+                    self.price - high
+                } else {
+                    0.0
+                };
+                gap <= distance
+            })
+            .copied()
+            .collect();
+        near.sort_by(|a, b| b.total().total_cmp(&a.total()));
+        near.truncate(count);
+        near
+    }
+
+    /// Total long and short percentages across the whole book.
+    pub fn totals(&self) -> (f32, f32) {
+        self.levels.iter().fold((0.0, 0.0), |(l, s), x| (l + x.long, s + x.short))
+    }
+
+    /// `(long - short) / (long + short)`: 1 when everything is long, -1 when
+    /// everything is short and 0 for an empty book.
+    pub fn net_sentiment(&self) -> f32 {
+        let (long, short) = self.totals();
+        if long + short > 0.0 {
+            (long - short) / (long + short)
+        } else {
+            0.0
+        }
+    }
+
+    /// `long / short`, or `None` when there is nothing short.
+    pub fn long_short_ratio(&self) -> Option<f32> {
+        let (long, short) = self.totals();
+        if short > 0.0 {
+            Some(long / short)
+        } else {
+            None
+        }
+    }
+
+    /// The change of every bucket from `earlier` to this snapshot, lowest
+    /// price first. Buckets present in only one snapshot count as zero in
+    /// the other; buckets that did not change are left out.
+    pub fn diff(&self, earlier: &BookSnapshot) -> Result<Vec<BookLevel>, BookError> {
+        let tolerance = self.bucket_width * 1e-3;
+        if (self.bucket_width - earlier.bucket_width).abs() > tolerance {
+            return Err(BookError::WidthMismatch(earlier.bucket_width, self.bucket_width));
+        }
+        let mut changes: BTreeMap<i64, BookLevel> = BTreeMap::new();
+        for level in &self.levels {
+            changes.insert(self.index(level.price), *level);
+        }
+        for level in &earlier.levels {
+            let change = changes.entry(self.index(level.price)).or_insert(BookLevel {
+                price: level.price,
+                long: 0.0,
+                short: 0.0,
+            });
+            change.long -= level.long;
+            change.short -= level.short;
+        }
+        Ok(
+            changes
+                .into_values()
+                .filter(|c| c.long != 0.0 || c.short != 0.0)
+                .collect()
+        )
+    }
+}
+
+impl TryFrom<&OrderBook> for BookSnapshot {
+    type Error = BookError;
+    fn try_from(book: &OrderBook) -> Result<BookSnapshot, BookError> {
+        BookSnapshot::from_order_book(book)
+    }
+}
+
+impl TryFrom<&PositionBook> for BookSnapshot {
+    type Error = BookError;
+    fn try_from(book: &PositionBook) -> Result<BookSnapshot, BookError> {
+        BookSnapshot::from_position_book(book)
+    }
+}
+
+fn cumulative<'a>(levels: impl Iterator<Item = &'a BookLevel>) -> Vec<BookLevel> {
+    let (mut long, mut short) = (0.0, 0.0);
+    levels
+        .map(|level| {
+            long += level.long;
+            short += level.short;
+            BookLevel {
+                price: level.price,
+                long,
+                short,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(price: f32, buckets: &[(f32, f32, f32)]) -> OrderBook {
+        OrderBook::new()
+            .with_time(Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap())
+            .with_price(price)
+            .with_bucket_width(0.0005)
+            .with_buckets(
+                buckets
+                    .iter()
+                    .map(|(p, l, s)| {
+                        OrderBookBucket::new()
+                            .with_price(*p)
+                            .with_long_count_percent(*l)
+                            .with_short_count_percent(*s)
+                    })
+                    .collect()
+            )
+    }
+
+    #[test]
+    fn splits_around_price() {
+        let snapshot = BookSnapshot::try_from(
+            &book(1.1012, &[(1.1, 1.0, 2.0), (1.1005, 3.0, 1.0), (1.101, 2.0, 2.0), (1.1015, 0.5, 4.0)])
+        ).unwrap();
+        let d = snapshot.distribution();
+        assert_eq!((d.long_below, d.short_below), (4.0, 3.0));
+        assert_eq!((d.long_at, d.short_at), (2.0, 2.0));
+        assert_eq!((d.long_above, d.short_above), (0.5, 4.0));
+        let below = snapshot.cumulative_below();
+        assert_eq!(below.iter().map(|l| (l.long, l.short)).collect::<Vec<_>>(), vec![(3.0, 1.0), (4.0, 3.0)]);
+        assert_eq!(snapshot.clusters(0.0005, 1)[0].price, 1.1015);
+        assert_eq!(snapshot.net_sentiment(), (6.5 - 9.0) / 15.5);
+    }
+
+    #[test]
+    fn diffs_snapshots() {
+        let earlier = BookSnapshot::try_from(&book(1.1012, &[(1.1, 1.0, 2.0), (1.1005, 3.0, 1.0)])).unwrap();
+        let later = BookSnapshot::try_from(&book(1.1012, &[(1.1005, 3.0, 1.5), (1.101, 1.0, 0.0)])).unwrap();
+        let diff = later.diff(&earlier).unwrap();
+        assert_eq!(
+            diff.iter().map(|l| (l.long, l.short)).collect::<Vec<_>>(),
+            vec![(-1.0, -2.0), (0.0, 0.5), (1.0, 0.0)]
+        );
+    }
+}
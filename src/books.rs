@@ -0,0 +1,238 @@
+use chrono::prelude::*;
+use chrono::Duration;
+use futures::stream::{self, Stream};
+use std::error::Error;
+use std::future::Future;
+
+use crate::*;
+
+/// The snapshot times a book response's `Link` header points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BookLinks {
+    pub prev: Option<DateTime<Utc>>,
+    pub next: Option<DateTime<Utc>>,
+}
+
+impl BookLinks {
+    /// Parse a `Link` header such as
+    /// `<https://.../orderBook?time=2017-06-28T20%3A40%3A00Z>; rel="prev"`.
+    /// Links without a readable `time` parameter are ignored.
+    pub fn parse(header: &str) -> BookLinks {
+        let mut links = BookLinks::default();
+        for part in header.split(',') {
+            let url = match (part.find('<'), part.find('>')) {
+                (Some(a), Some(b)) if a < b => &part[a + 1..b],
+                _ => continue,
+            };
+            let time = reqwest::Url::parse(url)
+                .ok()
+                .and_then(|url| {
+                    url.query_pairs()
+                        .find(|(k, _)| k == "time")
+                        .and_then(|(_, v)| parse_time(&v))
+                });
+            let rel = part
+                .split(';')
+                .skip(1)
+                .filter_map(|x| x.trim().strip_prefix("rel="))
+                .map(|x| x.trim_matches('"'))
+                .next();
+            match rel {
+                Some("prev") => {
+                    links.prev = time;
+                }
+                Some("next") => {
+                    links.next = time;
+                }
+                _ => {}
+            }
+        }
+        links
+    }
+}
+
+// RFC 3339, or Unix seconds when the request used that datetime format.
+fn parse_time(x: &str) -> Option<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(x) {
+        return Some(t.with_timezone(&Utc));
+    }
+    let seconds = x.parse::<f64>().ok()?;
+    Utc.timestamp_opt(seconds.trunc() as i64, (seconds.fract() * 1e9) as u32).single()
+}
+
+/// Walks the order book or position book snapshots of an instrument
+/// between two times.
+///
+/// Each snapshot is fetched with its `time` parameter and the `Link` header
+/// of the response decides which snapshot comes next. When the header is
+/// missing the walk steps by a fixed interval instead (20 minutes, OANDA's
+/// usual snapshot spacing). Snapshots outside the range are skipped and each
+/// snapshot is returned only once.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use chrono::prelude::*;
+/// # use futures::TryStreamExt;
+/// # async fn sentiment(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let history = BookHistory::new(
+///     "EUR_USD".to_string(),
+///     Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2020, 1, 7, 0, 0, 0).unwrap()
+/// );
+/// let books: Vec<OrderBook> = history.order_books(client).try_collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BookHistory {
+    instrument: String,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    backward: bool,
+    interval: Duration,
+}
+
+impl BookHistory {
+    pub fn new(instrument: String, from: DateTime<Utc>, to: DateTime<Utc>) -> BookHistory {
+        BookHistory {
+            instrument,
+            from,
+            to,
+            backward: false,
+            interval: Duration::minutes(20),
+        }
+    }
+
+    /// Walk from `to` back to `from` instead of forward from `from`.
+    pub fn backward(mut self) -> Self {
+        self.backward = true;
+        self
+    }
+
+    /// The step used when a response carries no usable `Link`.
+    pub fn with_interval(mut self, x: Duration) -> Self {
+        if x > Duration::zero() {
+            self.interval = x;
+        }
+        self
+    }
+
+    /// Stream the order book snapshots of the range.
+    pub fn order_books<'a, C>(
+        &self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<OrderBook, Box<dyn Error>>> + 'a {
+        let instrument = self.instrument.clone();
+        self.walk(
+            move |time| {
+                let request = GetOrderBookRequest::new()
+                    .with_instrument(instrument.clone())
+                    .with_time(time);
+                async move {
+                    let (body, header) = request.remote_with_header(client).await?;
+                    match body.order_book {
+                        Some(book) => Ok((book, header.link)),
+                        None => Err(format!("no order book returned for {}", time).into()),
+                    }
+                }
+            },
+            |book: &OrderBook| book.time
+        )
+    }
+
+    /// Stream the position book snapshots of the range.
+    pub fn position_books<'a, C>(
+        &self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<PositionBook, Box<dyn Error>>> + 'a {
+        let instrument = self.instrument.clone();
+        self.walk(
+            move |time| {
+                let request = GetPositionBookRequest::new()
+                    .with_instrument(instrument.clone())
+                    .with_time(time);
+                async move {
+                    let (body, header) = request.remote_with_header(client).await?;
+                    match body.position_book {
+                        Some(book) => Ok((book, header.link)),
+                        None => Err(format!("no position book returned for {}", time).into()),
+                    }
+                }
+            },
+            |book: &PositionBook| book.time
+        )
+    }
+
+    fn walk<'a, T, F, Fut>(
+        &self,
+        fetch: F,
+        time_of: fn(&T) -> Option<DateTime<Utc>>
+    ) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a
+        where
+            T: 'a,
+            F: Fn(DateTime<Utc>) -> Fut + 'a,
+            Fut: Future<Output = Result<(T, Option<String>), Box<dyn Error>>> + 'a
+    {
+        let (from, to, backward, interval) = (self.from, self.to, self.backward, self.interval);
+        let start = if backward { to } else { from };
+        let state: (Option<DateTime<Utc>>, Option<DateTime<Utc>>, F) = (Some(start), None, fetch);
+        stream::unfold(state, move |(cursor, last, fetch)| async move {
+            let mut cursor = cursor?;
+            loop {
+                let (book, link) = match fetch(cursor).await {
+                    Ok(x) => x,
+                    Err(e) => {
+                        return Some((Err(e), (None, last, fetch)));
+                    }
+                };
+                let time = match time_of(&book) {
+                    Some(x) => x,
+                    None => {
+                        let e = format!("book snapshot for {} has no time", cursor).into();
+                        return Some((Err(e), (None, last, fetch)));
+                    }
+                };
+                let links = link.as_deref().map(BookLinks::parse).unwrap_or_default();
+                // Always move past both the requested and the returned time so
+                // the walk cannot revisit a snapshot forever.
+                let next = if backward {
+                    let floor = time.min(cursor);
+                    links.prev.filter(|t| *t < floor).unwrap_or(floor - interval)
+                } else {
+                    let ceiling = time.max(cursor);
+                    links.next.filter(|t| *t > ceiling).unwrap_or(ceiling + interval)
+                };
+                let more = if backward { next >= from } else { next <= to };
+                let fresh = match last {
+                    Some(l) if backward => time < l,
+                    Some(l) => time > l,
+                    None => true,
+                };
+                if fresh && time >= from && time <= to {
+                    return Some((Ok(book), (more.then_some(next), Some(time), fetch)));
+                }
+                if !more {
+                    return None;
+                }
+                cursor = next;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_link_header() {
+        let header = concat!(
+            "<https://api-fxtrade.oanda.com/v3/instruments/EUR_USD/orderBook?time=2017-06-28T20%3A40%3A00Z>; rel=\"prev\", ",
+            "<https://api-fxtrade.oanda.com/v3/instruments/EUR_USD/orderBook?time=1498683600.000000000>; rel=\"next\""
+        );
+        let links = BookLinks::parse(header);
+        assert_eq!(links.prev, Some(Utc.with_ymd_and_hms(2017, 6, 28, 20, 40, 0).unwrap()));
+        assert_eq!(links.next, Some(Utc.with_ymd_and_hms(2017, 6, 28, 21, 0, 0).unwrap()));
+        assert_eq!(BookLinks::parse(""), BookLinks::default());
+    }
+}
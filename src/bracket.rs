@@ -0,0 +1,464 @@
+use chrono::prelude::*;
+use std::error::Error;
+
+use crate::*;
+
+/// Where a take profit, stop loss or trailing stop sits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExitLevel {
+    /// An absolute price.
+    Price(f32),
+    /// A price distance from the entry price.
+    Distance(f32),
+    /// A distance in pips of the instrument, see `with_pip_location`.
+    Pips(f32),
+}
+
+/// Why a `BracketOrder` could not be built.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BracketError {
+    /// `units` is zero or not finite.
+    InvalidUnits(f32),
+    /// A price or distance is zero, negative or not finite.
+    InvalidLevel(&'static str),
+    /// A leg is on the wrong side of the entry price (or, without an entry
+    /// price, the take profit and stop loss are the wrong way round).
+    WrongSide(&'static str),
+    /// A distance needs an entry price to become a price; set one with
+    /// `with_reference_price`.
+    NeedsEntryPrice(&'static str),
+    /// A distance in pips needs the instrument's pip location.
+    NeedsPipLocation(&'static str),
+    /// A price worked out from a distance needs the instrument's display
+    /// precision, or its pip location to derive it from.
+    NeedsPrecision(&'static str),
+    /// Trailing stops can only be given as a distance.
+    TrailingStopPrice,
+}
+
+impl std::fmt::Display for BracketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BracketError::InvalidUnits(x) => write!(f, "invalid units {}", x),
+            BracketError::InvalidLevel(x) => write!(f, "invalid {} level", x),
+            BracketError::WrongSide(x) => write!(f, "{} is on the wrong side of the entry", x),
+            BracketError::NeedsEntryPrice(x) => write!(f, "{} distance needs an entry price", x),
+            BracketError::NeedsPipLocation(x) => write!(f, "{} in pips needs a pip location", x),
+            BracketError::NeedsPrecision(x) => write!(f, "{} needs a display precision", x),
+            BracketError::TrailingStopPrice => write!(f, "trailing stop must be a distance"),
+        }
+    }
+}
+
+impl std::error::Error for BracketError {}
+
+/// The order request a `BracketOrder` builds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BracketRequest {
+    Market(MarketOrderRequest),
+    Limit(LimitOrderRequest),
+}
+
+impl ManagedOrder for BracketRequest {
+    fn client_extensions_mut(&mut self) -> &mut Option<ClientExtensions> {
+        match self {
+            BracketRequest::Market(x) => x.client_extensions_mut(),
+            BracketRequest::Limit(x) => x.client_extensions_mut(),
+        }
+    }
+
+    async fn create(self, client: &Client<Trading>, account_id: &AccountId) -> Result<Submitted, Box<dyn Error>> {
+        match self {
+            BracketRequest::Market(x) => x.create(client, account_id).await,
+            BracketRequest::Limit(x) => x.create(client, account_id).await,
+        }
+    }
+}
+
+// The request types are sent as the order types the generated create
+// requests take, which have the same wire format.
+macro_rules! managed_request {
+    ($($request:ident => $order:ident),*) => {
+      $(
+        impl ManagedOrder for $request {
+          fn client_extensions_mut(&mut self) -> &mut Option<ClientExtensions> {
+            &mut self.client_extensions
+          }
+
+          async fn create(
+            self,
+            client: &Client<Trading>,
+            account_id: &AccountId
+          ) -> Result<Submitted, Box<dyn Error>> {
+            let order: $order = serde_json::from_value(serde_json::to_value(&self)?)?;
+            order.create(client, account_id).await
+          }
+        }
+      )*
+    };
+}
+
+managed_request!(MarketOrderRequest => MarketOrder, LimitOrderRequest => LimitOrder);
+
+/// A market or limit entry with take profit, stop loss and trailing stop
+/// orders to be created when it fills.
+///
+/// Positive `units` buy, negative `units` sell. OANDA only accepts a price
+/// for the take profit, a price or a distance for the stop loss and a
+/// distance for the trailing stop; other forms are converted using the
+/// entry price (the limit price, or `with_reference_price` for market
+/// entries) and `with_pip_location`, and rounded to the instrument's display
+/// precision, which OANDA requires. `build` checks that the take profit is
+/// on the profitable side of the entry and the stop loss on the other.
+///
+/// ```
+/// # use fxoanda::*;
+/// let request = BracketOrder::limit("EUR_USD".to_string(), 1000.0, 1.1)
+///     .with_pip_location(-4)
+///     .with_take_profit(ExitLevel::Pips(50.0))
+///     .with_stop_loss(ExitLevel::Price(1.095))
+///     .with_trailing_stop(ExitLevel::Pips(20.0))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketOrder {
+    instrument: String,
+    units: f32,
+    limit_price: Option<f32>,
+    reference_price: Option<f32>,
+    pip_location: Option<i32>,
+    display_precision: Option<i32>,
+    take_profit: Option<ExitLevel>,
+    stop_loss: Option<ExitLevel>,
+    trailing_stop: Option<ExitLevel>,
+    guaranteed_stop: Option<bool>,
+    time_in_force: Option<TimeInForce>,
+    gtd_time: Option<DateTime<Utc>>,
+    price_bound: Option<f32>,
+    position_fill: Option<OrderPositionFill>,
+    client_extensions: Option<ClientExtensions>,
+    trade_client_extensions: Option<ClientExtensions>,
+}
+
+impl BracketOrder {
+    fn new(instrument: String, units: f32, limit_price: Option<f32>) -> BracketOrder {
+        BracketOrder {
+            instrument,
+            units,
+            limit_price,
+            reference_price: None,
+            pip_location: None,
+            display_precision: None,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
+            guaranteed_stop: None,
+            time_in_force: None,
+            gtd_time: None,
+            price_bound: None,
+            position_fill: None,
+            client_extensions: None,
+            trade_client_extensions: None,
+        }
+    }
+
+    /// Enter at the market price.
+    pub fn market(instrument: String, units: f32) -> BracketOrder {
+        BracketOrder::new(instrument, units, None)
+    }
+
+    /// Enter at `price` or better.
+    pub fn limit(instrument: String, units: f32, price: f32) -> BracketOrder {
+        BracketOrder::new(instrument, units, Some(price))
+    }
+
+    /// The expected fill price of a market entry, used to convert distances
+    /// to prices and to check the sides of the exits.
+    pub fn with_reference_price(mut self, x: f32) -> Self {
+        self.reference_price = Some(x);
+        self
+    }
+
+    /// The instrument's `pipLocation`, e.g. -4 for EUR_USD.
+    pub fn with_pip_location(mut self, x: i32) -> Self {
+        self.pip_location = Some(x);
+        self
+    }
+
+    /// The instrument's `displayPrecision`, e.g. 5 for EUR_USD. Defaults to
+    /// one more digit than the pip location.
+    pub fn with_display_precision(mut self, x: i32) -> Self {
+        self.display_precision = Some(x);
+        self
+    }
+
+    pub fn with_take_profit(mut self, x: ExitLevel) -> Self {
+        self.take_profit = Some(x);
+        self
+    }
+
+    pub fn with_stop_loss(mut self, x: ExitLevel) -> Self {
+        self.stop_loss = Some(x);
+        self
+    }
+
+    /// A trailing stop loss, as a distance or in pips.
+    pub fn with_trailing_stop(mut self, x: ExitLevel) -> Self {
+        self.trailing_stop = Some(x);
+        self
+    }
+
+    /// Make the stop loss a guaranteed stop loss.
+    pub fn with_guaranteed_stop(mut self, x: bool) -> Self {
+        self.guaranteed_stop = Some(x);
+        self
+    }
+
+    /// The time in force of the entry.
+    pub fn with_time_in_force(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x);
+        self
+    }
+
+    /// When a GTD limit entry expires.
+    pub fn with_gtd_time(mut self, x: DateTime<Utc>) -> Self {
+        self.gtd_time = Some(x);
+        self
+    }
+
+    /// The worst price a market entry may fill at.
+    pub fn with_price_bound(mut self, x: f32) -> Self {
+        self.price_bound = Some(x);
+        self
+    }
+
+    pub fn with_position_fill(mut self, x: OrderPositionFill) -> Self {
+        self.position_fill = Some(x);
+        self
+    }
+
+    pub fn with_client_extensions(mut self, x: ClientExtensions) -> Self {
+        self.client_extensions = Some(x);
+        self
+    }
+
+    /// Client extensions for the trade opened by the entry.
+    pub fn with_trade_client_extensions(mut self, x: ClientExtensions) -> Self {
+        self.trade_client_extensions = Some(x);
+        self
+    }
+
+    fn entry_price(&self) -> Option<f32> {
+        self.limit_price.or(self.reference_price)
+    }
+
+    fn distance(&self, leg: &'static str, x: f32, pips: bool) -> Result<f32, BracketError> {
+        if !(x > 0.0 && x.is_finite()) {
+            return Err(BracketError::InvalidLevel(leg));
+        }
+        if !pips {
+            return Ok(x);
+        }
+        match self.pip_location {
+            Some(location) => Ok(round_to(x * 10f32.powi(location), self.precision(leg)?)),
+            None => Err(BracketError::NeedsPipLocation(leg)),
+        }
+    }
+
+    fn precision(&self, leg: &'static str) -> Result<i32, BracketError> {
+        self.display_precision
+            .or(self.pip_location.map(|x| 1 - x))
+            .ok_or(BracketError::NeedsPrecision(leg))
+    }
+
+    // The price of an exit `sign` (1 = profit side) away from the entry.
+    fn price(&self, leg: &'static str, level: ExitLevel, sign: f32) -> Result<f32, BracketError> {
+        let offset = match level {
+            ExitLevel::Price(x) => {
+                if !(x > 0.0 && x.is_finite()) {
+                    return Err(BracketError::InvalidLevel(leg));
+                }
+                return Ok(x);
+            }
+            ExitLevel::Distance(x) => self.distance(leg, x, false)?,
+            ExitLevel::Pips(x) => self.distance(leg, x, true)?,
+        };
+        match self.entry_price() {
+            Some(entry) => Ok(round_to(entry + sign * self.units.signum() * offset, self.precision(leg)?)),
+            None => Err(BracketError::NeedsEntryPrice(leg)),
+        }
+    }
+
+    fn take_profit_details(&self) -> Result<Option<TakeProfitDetails>, BracketError> {
+        match self.take_profit {
+            None => Ok(None),
+            Some(level) => Ok(Some(TakeProfitDetails::new().with_price(self.price("take profit", level, 1.0)?))),
+        }
+    }
+
+    fn stop_loss_details(&self) -> Result<Option<StopLossDetails>, BracketError> {
+        let mut details = match self.stop_loss {
+            None => {
+                return Ok(None);
+            }
+            Some(level @ ExitLevel::Price(_)) => {
+                StopLossDetails::new().with_price(self.price("stop loss", level, -1.0)?)
+            }
+            Some(ExitLevel::Distance(x)) => StopLossDetails::new().with_distance(self.distance("stop loss", x, false)?),
+            Some(ExitLevel::Pips(x)) => StopLossDetails::new().with_distance(self.distance("stop loss", x, true)?),
+        };
+        if let Some(x) = self.guaranteed_stop {
+            details = details.with_guaranteed(x);
+        }
+        Ok(Some(details))
+    }
+
+    fn trailing_stop_details(&self) -> Result<Option<TrailingStopLossDetails>, BracketError> {
+        match self.trailing_stop {
+            None => Ok(None),
+            Some(ExitLevel::Price(_)) => Err(BracketError::TrailingStopPrice),
+            Some(ExitLevel::Distance(x)) => Ok(
+                Some(TrailingStopLossDetails::new().with_distance(self.distance("trailing stop", x, false)?))
+            ),
+            Some(ExitLevel::Pips(x)) => Ok(
+                Some(TrailingStopLossDetails::new().with_distance(self.distance("trailing stop", x, true)?))
+            ),
+        }
+    }
+
+    fn validate(
+        &self,
+        take_profit: &Option<TakeProfitDetails>,
+        stop_loss: &Option<StopLossDetails>
+    ) -> Result<(), BracketError> {
+        if !(self.units != 0.0 && self.units.is_finite()) {
+            return Err(BracketError::InvalidUnits(self.units));
+        }
+        let long = self.units > 0.0;
+        let tp = take_profit.as_ref().and_then(|x| x.price);
+        let sl = stop_loss.as_ref().and_then(|x| x.price);
+        match self.entry_price() {
+            Some(entry) => {
+                if tp.is_some_and(|tp| (tp > entry) != long || tp == entry) {
+                    return Err(BracketError::WrongSide("take profit"));
+                }
+                if sl.is_some_and(|sl| (sl < entry) != long || sl == entry) {
+                    return Err(BracketError::WrongSide("stop loss"));
+                }
+            }
+            None => {
+                if let (Some(tp), Some(sl)) = (tp, sl) {
+                    if (tp > sl) != long || tp == sl {
+                        return Err(BracketError::WrongSide("take profit"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the bracket and build the entry order request.
+    pub fn build(&self) -> Result<BracketRequest, BracketError> {
+        let take_profit = self.take_profit_details()?;
+        let stop_loss = self.stop_loss_details()?;
+        let trailing_stop = self.trailing_stop_details()?;
+        self.validate(&take_profit, &stop_loss)?;
+        match self.limit_price {
+            None => {
+                let mut request = MarketOrderRequest::new()
+                    .with_otype(OrderType::Market.to_string())
+                    .with_instrument(self.instrument.clone())
+                    .with_units(self.units);
+                request.take_profit_on_fill = take_profit;
+                request.stop_loss_on_fill = stop_loss;
+                request.trailing_stop_loss_on_fill = trailing_stop;
+                request.time_in_force = self.time_in_force.map(|x| x.to_string());
+                request.price_bound = self.price_bound;
+                request.position_fill = self.position_fill.map(|x| x.to_string());
+                request.client_extensions = self.client_extensions.clone();
+                request.trade_client_extensions = self.trade_client_extensions.clone();
+                Ok(BracketRequest::Market(request))
+            }
+            Some(price) => {
+                let mut request = LimitOrderRequest::new()
+                    .with_otype(OrderType::Limit.to_string())
+                    .with_instrument(self.instrument.clone())
+                    .with_units(self.units)
+                    .with_price(price);
+                request.take_profit_on_fill = take_profit;
+                request.stop_loss_on_fill = stop_loss;
+                request.trailing_stop_loss_on_fill = trailing_stop;
+                request.time_in_force = self.time_in_force.map(|x| x.to_string());
+                request.gtd_time = self.gtd_time;
+                request.position_fill = self.position_fill.map(|x| x.to_string());
+                request.client_extensions = self.client_extensions.clone();
+                request.trade_client_extensions = self.trade_client_extensions.clone();
+                Ok(BracketRequest::Limit(request))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_and_checks_sides() {
+        let short = BracketOrder::market("EUR_USD".to_string(), -1000.0)
+            .with_reference_price(1.1)
+            .with_pip_location(-4)
+            .with_take_profit(ExitLevel::Pips(50.0))
+            .with_stop_loss(ExitLevel::Pips(25.0));
+        match short.build().unwrap() {
+            BracketRequest::Market(request) => {
+                let tp = request.take_profit_on_fill.unwrap().price.unwrap();
+                assert!((tp - 1.095).abs() < 1e-6);
+                let sl = request.stop_loss_on_fill.unwrap();
+                assert!((sl.distance.unwrap() - 0.0025).abs() < 1e-6);
+                assert_eq!(sl.price, None);
+                assert_eq!(request.otype.as_deref(), Some("MARKET"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let wrong = BracketOrder::limit("EUR_USD".to_string(), 1000.0, 1.1).with_stop_loss(ExitLevel::Price(1.2));
+        assert_eq!(wrong.build(), Err(BracketError::WrongSide("stop loss")));
+        let no_entry = BracketOrder::market("EUR_USD".to_string(), 1000.0).with_take_profit(ExitLevel::Distance(0.01));
+        assert_eq!(no_entry.build(), Err(BracketError::NeedsEntryPrice("take profit")));
+        let reversed = BracketOrder::market("EUR_USD".to_string(), 1000.0)
+            .with_take_profit(ExitLevel::Price(1.0))
+            .with_stop_loss(ExitLevel::Price(1.2));
+        assert_eq!(reversed.build(), Err(BracketError::WrongSide("take profit")));
+    }
+
+    #[test]
+    fn rounds_to_display_precision() {
+        let long = BracketOrder::limit("EUR_USD".to_string(), 1000.0, 1.1)
+            .with_pip_location(-4)
+            .with_display_precision(5)
+            .with_take_profit(ExitLevel::Pips(25.0))
+            .with_stop_loss(ExitLevel::Pips(30.0))
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&long).unwrap();
+        assert_eq!(json["takeProfitOnFill"]["price"], "1.1025");
+        assert_eq!(json["stopLossOnFill"]["distance"], "0.003");
+
+        let short = BracketOrder::market("EUR_USD".to_string(), -1000.0)
+            .with_reference_price(1.2345)
+            .with_pip_location(-4)
+            .with_stop_loss(ExitLevel::Distance(0.003))
+            .with_take_profit(ExitLevel::Distance(0.003))
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&short).unwrap();
+        assert_eq!(json["takeProfitOnFill"]["price"], "1.2315");
+        assert_eq!(json["stopLossOnFill"]["distance"], "0.003");
+        let unknown = BracketOrder::limit("EUR_USD".to_string(), 1000.0, 1.1)
+            .with_take_profit(ExitLevel::Distance(0.01));
+        assert_eq!(unknown.build(), Err(BracketError::NeedsPrecision("take profit")));
+    }
+}
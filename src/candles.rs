@@ -0,0 +1,312 @@
+use chrono::prelude::*;
+use chrono::Months;
+
+use crate::*;
+
+/// The price component of a `Candlestick`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceComponent {
+    Mid,
+    Bid,
+    Ask,
+}
+
+impl PriceComponent {
+    /// The character used for this component in the `price` request parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceComponent::Mid => "M",
+            PriceComponent::Bid => "B",
+            PriceComponent::Ask => "A",
+        }
+    }
+
+    /// The data for this component of a candle, if it was requested.
+    pub fn data<'a>(&self, candle: &'a Candlestick) -> Option<&'a CandlestickData> {
+        match self {
+            PriceComponent::Mid => candle.mid.as_ref(),
+            PriceComponent::Bid => candle.bid.as_ref(),
+            PriceComponent::Ask => candle.ask.as_ref(),
+        }
+    }
+}
+
+impl std::fmt::Display for PriceComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a `Candlestick` could not be turned into an `Ohlc`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandleError {
+    /// The candle has no `time`.
+    MissingTime,
+    /// The candle does not carry the requested price component.
+    MissingComponent(PriceComponent),
+    /// One of `o`, `h`, `l` or `c` is missing.
+    MissingPrice(DateTime<Utc>, &'static str),
+    /// The prices are not finite or the high/low do not bound open and close.
+    Inconsistent(DateTime<Utc>),
+}
+
+impl std::fmt::Display for CandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CandleError::MissingTime => write!(f, "candle has no time"),
+            CandleError::MissingComponent(x) => write!(f, "candle has no {} prices", x),
+            CandleError::MissingPrice(t, x) => write!(f, "candle at {} has no {} price", t, x),
+            CandleError::Inconsistent(t) => write!(f, "candle at {} has inconsistent prices", t),
+        }
+    }
+}
+
+impl std::error::Error for CandleError {}
+
+/// A validated candle for one price component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ohlc {
+    pub time: DateTime<Utc>,
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+    pub volume: i32,
+    pub complete: bool,
+}
+
+impl Ohlc {
+    /// Build an `Ohlc` from one price component of a candle.
+    pub fn from_candle(candle: &Candlestick, component: PriceComponent) -> Result<Ohlc, CandleError> {
+        let time = candle.time.ok_or(CandleError::MissingTime)?;
+        let data = component.data(candle).ok_or(CandleError::MissingComponent(component))?;
+        let ohlc = Ohlc {
+            time,
+            open: data.o.ok_or(CandleError::MissingPrice(time, "open"))?,
+            high: data.h.ok_or(CandleError::MissingPrice(time, "high"))?,
+            low: data.l.ok_or(CandleError::MissingPrice(time, "low"))?,
+            close: data.c.ok_or(CandleError::MissingPrice(time, "close"))?,
+            volume: candle.volume.unwrap_or(0),
+            complete: candle.complete.unwrap_or(false),
+        };
+        let finite = [ohlc.open, ohlc.high, ohlc.low, ohlc.close].iter().all(|x| x.is_finite());
+        if
+            !finite ||
+            ohlc.high < ohlc.open.max(ohlc.close) ||
+            ohlc.low > ohlc.open.min(ohlc.close)
+        {
+            return Err(CandleError::Inconsistent(time));
+        }
+        Ok(ohlc)
+    }
+}
+
+impl TryFrom<(&Candlestick, PriceComponent)> for Ohlc {
+    type Error = CandleError;
+    fn try_from(x: (&Candlestick, PriceComponent)) -> Result<Ohlc, CandleError> {
+        Ohlc::from_candle(x.0, x.1)
+    }
+}
+
+/// Uses the midpoint prices.
+impl TryFrom<&Candlestick> for Ohlc {
+    type Error = CandleError;
+    fn try_from(candle: &Candlestick) -> Result<Ohlc, CandleError> {
+        Ohlc::from_candle(candle, PriceComponent::Mid)
+    }
+}
+
+/// A run of missing bars in a `CandleSeries`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandleGap {
+    /// Time of the last bar before the gap.
+    pub after: DateTime<Utc>,
+    /// Time of the first bar after the gap.
+    pub before: DateTime<Utc>,
+    /// How many bars of the series granularity are missing.
+    pub missing: i64,
+}
+
+/// The start of the bar that follows the one starting at `time`.
+pub(crate) fn next_bar_time(granularity: CandlestickGranularity, time: DateTime<Utc>) -> DateTime<Utc> {
+    match granularity {
+        CandlestickGranularity::M => time.checked_add_months(Months::new(1)).unwrap_or(time),
+        _ => time + granularity_duration(granularity),
+    }
+}
+
+/// Time-ordered `Ohlc` bars of a single instrument, granularity and price
+/// component, with at most one bar per time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleSeries {
+    granularity: CandlestickGranularity,
+    bars: Vec<Ohlc>,
+}
+
+impl CandleSeries {
+    pub fn new(granularity: CandlestickGranularity) -> CandleSeries {
+        CandleSeries {
+            granularity,
+            bars: Vec::new(),
+        }
+    }
+
+    /// Convert one price component of `candles`, failing on the first candle
+    /// that does not validate.
+    pub fn from_candles(
+        granularity: CandlestickGranularity,
+        candles: &[Candlestick],
+        component: PriceComponent
+    ) -> Result<CandleSeries, CandleError> {
+        let mut series = CandleSeries::new(granularity);
+        for candle in candles {
+            series.insert(Ohlc::from_candle(candle, component)?);
+        }
+        Ok(series)
+    }
+
+    pub fn granularity(&self) -> CandlestickGranularity {
+        self.granularity
+    }
+
+    /// Add a bar, replacing any bar with the same time.
+    pub fn insert(&mut self, bar: Ohlc) {
+        match self.position(bar.time) {
+            Ok(i) => {
+                self.bars[i] = bar;
+            }
+            Err(i) => self.bars.insert(i, bar),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+
+    pub fn bars(&self) -> &[Ohlc] {
+        &self.bars
+    }
+
+    pub fn first(&self) -> Option<&Ohlc> {
+        self.bars.first()
+    }
+
+    pub fn last(&self) -> Option<&Ohlc> {
+        self.bars.last()
+    }
+
+    /// All bars, oldest first.
+    pub fn iter(&self) -> std::slice::Iter<'_, Ohlc> {
+        self.bars.iter()
+    }
+
+    /// Only the bars that are complete, oldest first.
+    pub fn complete(&self) -> impl Iterator<Item = &Ohlc> {
+        self.bars.iter().filter(|bar| bar.complete)
+    }
+
+    /// The bar starting exactly at `time`.
+    pub fn get(&self, time: DateTime<Utc>) -> Option<&Ohlc> {
+        self.position(time).ok().map(|i| &self.bars[i])
+    }
+
+    /// The latest bar starting at or before `time`.
+    pub fn at_or_before(&self, time: DateTime<Utc>) -> Option<&Ohlc> {
+        match self.position(time) {
+            Ok(i) => Some(&self.bars[i]),
+            Err(0) => None,
+            Err(i) => Some(&self.bars[i - 1]),
+        }
+    }
+
+    /// The bars starting in `[from, to)`.
+    pub fn range(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> &[Ohlc] {
+        let start = self.position(from).unwrap_or_else(|i| i);
+        let end = self.position(to).unwrap_or_else(|i| i);
+        &self.bars[start..end.max(start)]
+    }
+
+    /// Places where consecutive bars are further apart than one bar of the
+    /// series granularity. Market closes (weekends, holidays) show up here
+    /// too; it is up to the caller to decide which gaps matter.
+    pub fn gaps(&self) -> Vec<CandleGap> {
+        let mut result = Vec::new();
+        for pair in self.bars.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            let mut expected = next_bar_time(self.granularity, a.time);
+            if b.time <= expected {
+                continue;
+            }
+            let mut missing = 0;
+            while expected < b.time {
+                missing += 1;
+                expected = next_bar_time(self.granularity, expected);
+            }
+            result.push(CandleGap {
+                after: a.time,
+                before: b.time,
+                missing,
+            });
+        }
+        result
+    }
+
+    fn position(&self, time: DateTime<Utc>) -> Result<usize, usize> {
+        self.bars.binary_search_by(|bar| bar.time.cmp(&time))
+    }
+}
+
+impl<'a> IntoIterator for &'a CandleSeries {
+    type Item = &'a Ohlc;
+    type IntoIter = std::slice::Iter<'a, Ohlc>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.bars.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(minute: u32, complete: bool) -> Candlestick {
+        Candlestick::new()
+            .with_time(Utc.with_ymd_and_hms(2020, 1, 6, 0, minute, 0).unwrap())
+            .with_complete(complete)
+            .with_volume(10)
+            .with_mid(CandlestickData::new().with_o(1.0).with_h(1.2).with_l(0.9).with_c(1.1))
+    }
+
+    #[test]
+    fn converts_and_validates() {
+        let bar = Ohlc::try_from(&candle(0, true)).unwrap();
+        assert_eq!((bar.open, bar.high, bar.low, bar.close), (1.0, 1.2, 0.9, 1.1));
+        assert_eq!(
+            Ohlc::from_candle(&candle(0, true), PriceComponent::Bid),
+            Err(CandleError::MissingComponent(PriceComponent::Bid))
+        );
+        let bad = candle(0, true).with_mid(CandlestickData::new().with_o(1.0).with_h(0.95).with_l(0.9).with_c(1.1));
+        assert!(matches!(Ohlc::try_from(&bad), Err(CandleError::Inconsistent(_))));
+    }
+
+    #[test]
+    fn series_lookup_and_gaps() {
+        let candles = vec![candle(3, true), candle(0, true), candle(1, true), candle(6, false)];
+        let series = CandleSeries::from_candles(CandlestickGranularity::M1, &candles, PriceComponent::Mid).unwrap();
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.complete().count(), 3);
+        let t = |m| Utc.with_ymd_and_hms(2020, 1, 6, 0, m, 0).unwrap();
+        assert_eq!(series.get(t(1)).map(|b| b.time), Some(t(1)));
+        assert_eq!(series.at_or_before(t(2)).map(|b| b.time), Some(t(1)));
+        assert_eq!(
+            series.gaps(),
+            vec![
+                CandleGap { after: t(1), before: t(3), missing: 1 },
+                CandleGap { after: t(3), before: t(6), missing: 2 }
+            ]
+        );
+    }
+}
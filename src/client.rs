@@ -1,17 +1,329 @@
+use serde::de::DeserializeOwned;
 use std::error::Error;
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::*;
 
-/// HTTP Client used to call the OANDA web services.
-#[derive(Debug)]
-pub struct Client {
-    /// The reqwest object to use. Note that this is a synchronous client
-    /// that cannot be used in async code.
-    pub reqwest: reqwest::Client,
-    /// OANDA host to use (do not include https://)
+/// Called with the response type and the path of every field in a response
+/// that does not map onto the definitions, e.g. `("GetAccountResponse200Body",
+/// "account.newField")`.
+pub type UnknownFieldHandler = Arc<dyn Fn(&str, &str) + Send + Sync>;
+
+/// A handler for `Client::with_unknown_fields` that reports unknown fields
+/// as warnings through the `log` crate.
+pub fn log_unknown_field(response: &str, path: &str) {
+    log::warn!("unrecognized field `{}` in {}", path, response);
+}
+
+/// Capability of a `Client` that may call every endpoint, including the ones
+/// that create, modify, cancel or close orders, trades and positions.
+#[derive(Debug, Clone, Copy)]
+pub struct Trading;
+
+/// Capability of a `Client` that may only call endpoints that do not change
+/// the state of an account. Passing a `Client<ReadOnly>` to a mutating
+/// request's `remote` is a compile error.
+///
+/// ```compile_fail
+/// # async fn cancel(client: fxoanda::Client<fxoanda::ReadOnly>) {
+/// let _ = fxoanda::CancelOrderRequest::new().remote(&client).await;
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnly;
+
+/// The OANDA environment a `Client` talks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// The fxTrade Practice (demo) environment.
+    Practice,
+    /// The fxTrade environment, with real money.
+    Live,
+}
+
+impl Environment {
+    /// The environment of an OANDA host. Any host that is not exactly a
+    /// practice host (`api-fxpractice.oanda.com`,
+    /// `stream-fxpractice.oanda.com`) is taken to be live.
+    pub fn from_host(host: &str) -> Environment {
+        if host == "api-fxpractice.oanda.com" || host == "stream-fxpractice.oanda.com" {
+            Environment::Practice
+        } else {
+            Environment::Live
+        }
+    }
+}
+
+impl std::fmt::Display for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Environment::Practice => write!(f, "practice"),
+            Environment::Live => write!(f, "live"),
+        }
+    }
+}
+
+/// A trading request refused because the client targets the live
+/// environment and the account was not confirmed with
+/// `Client::allow_live_trading`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveTradingError {
+    pub environment: Environment,
     pub host: String,
+    pub account_id: String,
+}
+
+impl std::fmt::Display for LiveTradingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "refusing to trade account {} in the {} environment ({}) without Client::allow_live_trading",
+            self.account_id,
+            self.environment,
+            self.host
+        )
+    }
+}
+
+impl std::error::Error for LiveTradingError {}
+
+/// A response with an HTTP error status.
+///
+/// `environment` is the environment of the client that sent the request.
+/// `message` and `code` are OANDA's `errorMessage` and `errorCode`, when the
+/// body has them. `body` keeps the whole response body, e.g. for the reject
+/// transaction of a refused order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub environment: Environment,
+    pub status: u16,
+    pub message: Option<String>,
+    pub code: Option<String>,
+    pub body: String,
+}
+
+impl ApiError {
+    pub(crate) fn new(environment: Environment, status: u16, body: &[u8]) -> ApiError {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            #[serde(rename = "errorMessage")]
+            error_message: Option<String>,
+            #[serde(rename = "errorCode")]
+            error_code: Option<String>,
+        }
+        let parsed = serde_json::from_slice::<ErrorBody>(body).ok();
+        ApiError {
+            environment,
+            status,
+            message: parsed.as_ref().and_then(|x| x.error_message.clone()),
+            code: parsed.and_then(|x| x.error_code),
+            body: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "HTTP {} ({})", self.status, self.environment)?;
+        if let Some(ref code) = self.code {
+            write!(f, " {}", code)?;
+        }
+        match self.message {
+            Some(ref message) => write!(f, ": {}", message),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// HTTP Client used to call the OANDA web services.
+///
+/// The capability parameter is either `Trading` or `ReadOnly`. Use
+/// `Client::new` for a trading client and `Client::new_read_only` or
+/// `to_read_only` for one that cannot place or modify orders.
+///
+/// A trading client whose host is in the live environment refuses every
+/// request that changes an account until that account is confirmed with
+/// `allow_live_trading`:
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// let client = Client::new("api-fxtrade.oanda.com".to_string(), "key".to_string())
+///     .allow_live_trading("001-001-1234567-001");
+/// assert_eq!(client.environment(), Environment::Live);
+/// ```
+pub struct Client<C = Trading> {
+    /// The reqwest client every request goes through, see `with_reqwest`.
+    reqwest: reqwest::Client,
+    host: String,
     /// OANDA API key
-    pub authentication: String,
+    authentication: String,
+    /// Strict mode: when set, every response is checked for fields that the
+    /// definitions do not know about and each one is passed to the handler.
+    /// With the `extra-fields` feature enabled those fields are kept in the
+    /// `extra` maps instead and are not reported.
+    pub unknown_fields: Option<UnknownFieldHandler>,
+    risk: Arc<RiskGuard>,
+    live_accounts: Vec<String>,
+    capability: PhantomData<C>,
+}
+
+impl<C> std::fmt::Debug for Client<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("host", &self.host)
+            .field("environment", &self.environment())
+            .field("unknown_fields", &self.unknown_fields.is_some())
+            .field("risk", &self.risk)
+            .field("live_accounts", &self.live_accounts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Client<Trading> {
+    /// Create a client that can call every endpoint.
+    pub fn new(host: String, authentication: String) -> Client<Trading> {
+        Client::with_capability(host, authentication)
+    }
+
+    /// Allow requests that change `account_id` when the host is in the live
+    /// environment. Call once for every live account this client may trade.
+    pub fn allow_live_trading<A: Into<AccountId>>(mut self, account_id: A) -> Self {
+        let account_id = account_id.into().to_string();
+        if self.environment() == Environment::Live {
+            log::warn!("live trading enabled for account {} on {}", account_id, self.host);
+        }
+        self.live_accounts.push(account_id);
+        self
+    }
+
+    /// Refuse to change `account_id` on live unless it was confirmed.
+    pub(crate) fn authorize(&self, account_id: &str) -> Result<(), LiveTradingError> {
+        let environment = self.environment();
+        if environment == Environment::Practice || self.live_accounts.iter().any(|x| x == account_id) {
+            Ok(())
+        } else {
+            Err(LiveTradingError {
+                environment,
+                host: self.host.clone(),
+                account_id: account_id.to_string(),
+            })
+        }
+    }
+}
+
+impl Client<ReadOnly> {
+    /// Create a client that can only call read-only endpoints.
+    pub fn new_read_only(host: String, authentication: String) -> Client<ReadOnly> {
+        Client::with_capability(host, authentication)
+    }
+}
+
+impl<C> Client<C> {
+    fn with_capability(host: String, authentication: String) -> Client<C> {
+        Client {
+            reqwest: reqwest::Client::new(),
+            host,
+            authentication,
+            unknown_fields: None,
+            risk: Arc::new(RiskGuard::default()),
+            live_accounts: Vec::new(),
+            capability: PhantomData,
+        }
+    }
+
+    /// OANDA host to use (without https://).
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// The environment of `host`.
+    pub fn environment(&self) -> Environment {
+        Environment::from_host(&self.host)
+    }
+
+    /// Use the given reqwest client for all requests.
+    pub fn with_reqwest(mut self, x: reqwest::Client) -> Self {
+        self.reqwest = x;
+        self
+    }
+
+    /// Enable strict mode, see `unknown_fields`.
+    pub fn with_unknown_fields<F: Fn(&str, &str) + Send + Sync + 'static>(mut self, x: F) -> Self {
+        self.unknown_fields = Some(Arc::new(x));
+        self
+    }
+
+    /// Check every request that changes an account against `x` before
+    /// sending it, see `RiskGuard`.
+    pub fn with_risk_guard(mut self, x: Arc<RiskGuard>) -> Self {
+        self.risk = x;
+        self
+    }
+
+    /// The risk guard every request that changes an account goes through.
+    pub fn risk_guard(&self) -> &Arc<RiskGuard> {
+        &self.risk
+    }
+
+    /// A read-only client sharing this client's connection pool and settings.
+    pub fn to_read_only(&self) -> Client<ReadOnly> {
+        Client {
+            reqwest: self.reqwest.clone(),
+            host: self.host.clone(),
+            authentication: self.authentication.clone(),
+            unknown_fields: self.unknown_fields.clone(),
+            risk: self.risk.clone(),
+            live_accounts: Vec::new(),
+            capability: PhantomData,
+        }
+    }
+
+    /// Start a request to `url` with the API key, logging the environment
+    /// it goes to.
+    pub(crate) fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        log::debug!("{} {} ({})", method, url, self.environment());
+        self.reqwest.request(method, url).bearer_auth(&self.authentication)
+    }
+
+    /// Decode a response body, reporting unknown fields in strict mode.
+    /// Responses with an error status are returned as an `ApiError`.
+    pub(crate) async fn decode<T: DeserializeOwned>(
+        &self,
+        response: reqwest::Response
+    ) -> Result<T, Box<dyn Error>> {
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            return Err(Box::new(ApiError::new(self.environment(), status.as_u16(), &body)));
+        }
+        self.decode_slice(&body)
+    }
+
+    pub(crate) fn decode_slice<T: DeserializeOwned>(&self, body: &[u8]) -> Result<T, Box<dyn Error>> {
+        match self.unknown_fields {
+            None => Ok(serde_json::from_slice::<T>(body)?),
+            Some(ref handler) => {
+                let name = std::any::type_name::<T>().rsplit("::").next().unwrap_or_default();
+                let mut de = serde_json::Deserializer::from_slice(body);
+                let value = serde_ignored::deserialize(&mut de, |path| {
+                    handler(name, &path.to_string())
+                })?;
+                de.end()?;
+                Ok(value)
+            }
+        }
+    }
+}
+
+/// The value of a response header, if present and valid text.
+pub(crate) fn header_value(response: &reqwest::Response, name: &str) -> Option<String> {
+    response.headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
 }
 
 macro_rules! client_requests {
@@ -19,7 +331,7 @@ macro_rules! client_requests {
       $(
          use $request;
          use $response;
-         impl Client {
+         impl<C> Client<C> {
            pub async fn $func( &self, x: $request ) -> Result<$response, Box<dyn Error>> {
              x.remote(&self).await
            }
@@ -33,4 +345,70 @@ client_requests!( candles(GetInstrumentCandlesRequest) -> GetInstrumentCandlesRe
                   positionbook(GetPositionBookRequest) -> GetPositionBookResponse,
                   pricing(GetPricesRequest) -> GetPricesResponse,
                   accounts(ListAccountsRequest) -> ListAccountsResponse,
-                  account_summary(GetAccountSummaryRequest) -> GetAccountSummaryResponse);
+                  account_summary(GetAccountSummaryRequest) -> GetAccountSummaryResponse,
+                  user_info(GetUserInfoRequest) -> GetUserInfoResponse,
+                  external_user_info(GetExternalUserInfoRequest) -> GetExternalUserInfoResponse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    const BODY: &[u8] = br#"{"instrument":"EUR_USD","price":"1.1","newField":1}"#;
+
+    // A strict client and the fields it reported.
+    fn recording_client() -> (Client<ReadOnly>, Arc<Mutex<Vec<String>>>) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let client = Client::new_read_only(String::new(), String::new())
+            .with_unknown_fields(move |response: &str, path: &str| {
+                sink.lock().unwrap().push(format!("{}:{}", response, path))
+            });
+        (client, seen)
+    }
+
+    #[test]
+    fn live_trading_needs_confirmed_account() {
+        let practice = Client::new("api-fxpractice.oanda.com".to_string(), String::new());
+        assert_eq!(practice.environment(), Environment::Practice);
+        assert!(practice.authorize("101-004-1234567-001").is_ok());
+
+        let live = Client::new("api-fxtrade.oanda.com".to_string(), String::new())
+            .allow_live_trading("001-001-1234567-001");
+        assert_eq!(live.environment(), Environment::Live);
+        assert!(live.authorize("001-001-1234567-001").is_ok());
+        let error = live.authorize("001-001-1234567-002").unwrap_err();
+        assert_eq!(error.environment, Environment::Live);
+        assert!(error.to_string().contains("live environment"));
+        assert_eq!(Environment::from_host("api-fxpractice.oanda.com.example.net"), Environment::Live);
+        assert_eq!(Environment::from_host("stream-fxpractice.oanda.com"), Environment::Practice);
+    }
+
+    #[test]
+    fn error_responses_keep_the_message() {
+        let body = br#"{"errorMessage":"Invalid value specified for 'units'","errorCode":"UNITS_INVALID"}"#;
+        let error = ApiError::new(Environment::Practice, 400, body);
+        assert_eq!(error.message.as_deref(), Some("Invalid value specified for 'units'"));
+        assert_eq!(error.to_string(), "HTTP 400 (practice) UNITS_INVALID: Invalid value specified for 'units'");
+        assert_eq!(ApiError::new(Environment::Live, 502, b"Bad Gateway").to_string(), "HTTP 502 (live)");
+    }
+
+    #[cfg(not(feature = "extra-fields"))]
+    #[test]
+    fn strict_mode_reports_unknown_fields() {
+        let (client, seen) = recording_client();
+        let book: OrderBook = client.decode_slice(BODY).unwrap();
+        assert_eq!(book.instrument.as_deref(), Some("EUR_USD"));
+        assert_eq!(*seen.lock().unwrap(), vec!["OrderBook:newField".to_string()]);
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn extra_fields_are_kept_instead_of_reported() {
+        let (client, seen) = recording_client();
+        let book: OrderBook = client.decode_slice(BODY).unwrap();
+        assert_eq!(book.instrument.as_deref(), Some("EUR_USD"));
+        assert_eq!(book.extra.get("newField"), Some(&serde_json::json!(1)));
+        assert!(seen.lock().unwrap().is_empty());
+    }
+}
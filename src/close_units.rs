@@ -0,0 +1,146 @@
+use fxoanda_definitions::*;
+pub use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+/// How many units of a Trade or Position side to close out.
+///
+/// The OANDA API encodes this as the string "ALL", the string "NONE" or a
+/// positive decimal number of units. `CloseUnits` serializes to exactly that
+/// wire format and is accepted by `CloseTradeRequest::with_units` and
+/// `ClosePositionRequest::with_long_units` / `with_short_units`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CloseUnits {
+    /// Close the entire Trade or Position side.
+    All,
+    /// Close nothing. Only meaningful for one side of a Position closeout.
+    None,
+    /// Close this many units. Must be positive and not exceed the open size.
+    Partial(Decimal),
+}
+
+/// Error returned when a string is not "ALL", "NONE" or a positive decimal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCloseUnitsError {
+    /// The string that failed to parse.
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseCloseUnitsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid close units: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseCloseUnitsError {}
+
+impl CloseUnits {
+    /// Work out how many units to close to reduce an open size by `fraction`.
+    ///
+    /// `units` is the current size of the Trade or Position side (the sign is
+    /// ignored) and `precision` the instrument's `tradeUnitsPrecision`. The
+    /// partial amount is truncated to that many decimals; a fraction of `1.0`
+    /// or more closes everything and anything that truncates to zero units
+    /// closes nothing.
+    pub fn close_fraction(units: f32, fraction: f32, precision: u32) -> CloseUnits {
+        if fraction >= 1.0 {
+            return CloseUnits::All;
+        }
+        let (units, fraction) = match (Decimal::try_from(units.abs()), Decimal::try_from(fraction)) {
+            (Ok(u), Ok(f)) => (u, f),
+            _ => {
+                return CloseUnits::None;
+            }
+        };
+        let partial = (units * fraction).trunc_with_scale(precision).normalize();
+        if partial <= Decimal::ZERO {
+            return CloseUnits::None;
+        }
+        CloseUnits::Partial(partial)
+    }
+
+    /// Close `fraction` of a Trade's current units.
+    pub fn trade_fraction(trade: &Trade, fraction: f32, precision: u32) -> CloseUnits {
+        CloseUnits::close_fraction(trade.current_units.unwrap_or(0.0), fraction, precision)
+    }
+
+    /// Close `fraction` of one side of a Position.
+    pub fn position_side_fraction(side: &PositionSide, fraction: f32, precision: u32) -> CloseUnits {
+        CloseUnits::close_fraction(side.units.unwrap_or(0.0), fraction, precision)
+    }
+}
+
+impl FromStr for CloseUnits {
+    type Err = ParseCloseUnitsError;
+    fn from_str(s: &str) -> Result<CloseUnits, ParseCloseUnitsError> {
+        match s {
+            "ALL" => Ok(CloseUnits::All),
+            "NONE" => Ok(CloseUnits::None),
+            _ => match Decimal::from_str_exact(s) {
+                Ok(units) if units > Decimal::ZERO => Ok(CloseUnits::Partial(units)),
+                _ => Err(ParseCloseUnitsError { input: s.to_string() }),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for CloseUnits {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CloseUnits::All => write!(f, "ALL"),
+            CloseUnits::None => write!(f, "NONE"),
+            CloseUnits::Partial(units) => write!(f, "{}", units),
+        }
+    }
+}
+
+impl Serialize for CloseUnits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CloseUnits {
+    fn deserialize<D>(deserializer: D) -> Result<CloseUnits, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse::<CloseUnits>().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(s: &str) -> CloseUnits {
+        CloseUnits::Partial(Decimal::from_str_exact(s).unwrap())
+    }
+
+    #[test]
+    fn wire_format() {
+        assert_eq!(CloseUnits::All.to_string(), "ALL");
+        assert_eq!(CloseUnits::None.to_string(), "NONE");
+        assert_eq!(units("1500").to_string(), "1500");
+        assert_eq!("ALL".parse::<CloseUnits>(), Ok(CloseUnits::All));
+        assert_eq!("250.5".parse::<CloseUnits>(), Ok(units("250.5")));
+        for bad in ["SOME", "-5", "0", "NaN", "inf", ""] {
+            assert!(bad.parse::<CloseUnits>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(CloseUnits::close_fraction(-1001.0, 0.5, 0), units("500"));
+        assert_eq!(CloseUnits::close_fraction(-1001.0, 0.5, 1), units("500.5"));
+        assert_eq!(CloseUnits::close_fraction(0.3, 0.5, 2), units("0.15"));
+        assert_eq!(CloseUnits::close_fraction(1000.0, 1.0, 0), CloseUnits::All);
+        assert_eq!(CloseUnits::close_fraction(1.0, 0.5, 0), CloseUnits::None);
+        assert_eq!(CloseUnits::close_fraction(1000.0, 0.0, 0), CloseUnits::None);
+        assert_eq!(CloseUnits::close_fraction(1000.0, f32::NAN, 0), CloseUnits::None);
+    }
+}
@@ -0,0 +1,552 @@
+use chrono::prelude::*;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::*;
+
+/// A condition between orders that OANDA cannot express itself.
+///
+/// Orders are named by order specifier: an order ID, or `@` followed by a
+/// client order ID. Orders placed by an `IfDone` are always given a client
+/// ID, so a link can refer to an order that does not exist yet, which is
+/// how if-done chains are built.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Link {
+    /// When either order fills, cancel the other.
+    Oco { first: String, second: String },
+    /// When `trigger` fills, create `then`. If `trigger` is cancelled, `then`
+    /// is dropped.
+    IfDone { trigger: String, then: Box<LimitOrder> },
+    /// Cancel `order` if it is still pending at `at`.
+    CancelAt { order: String, at: DateTime<Utc> },
+}
+
+/// Something the `ConditionalEngine` did.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalEvent {
+    /// `order` was cancelled because `reason` filled, or at its deadline.
+    Cancelled { order: String, reason: String },
+    /// An `IfDone` order was created after `trigger` filled.
+    Placed { trigger: String, client_id: String },
+    /// An action failed. It is kept and retried on every poll until it
+    /// succeeds.
+    Failed { action: ConditionalAction, error: String },
+}
+
+/// What a link does when it fires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionalAction {
+    /// Cancel `order` because of `reason`.
+    Cancel { order: String, reason: String },
+    /// Create `then` because `trigger` filled.
+    Place { trigger: String, then: Box<LimitOrder> },
+}
+
+/// Everything the engine needs to resume after a restart.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ConditionalState {
+    pub account_id: String,
+    /// The last transaction that was acted upon.
+    pub last_transaction_id: Option<String>,
+    pub links: Vec<Link>,
+    /// The actions that failed, to be retried.
+    #[serde(default)]
+    pub pending: Vec<ConditionalAction>,
+    next_client_id: u64,
+}
+
+/// Emulates one-cancels-other pairs, if-done chains and time-based
+/// cancellation on the client side.
+///
+/// The engine cancels the other side of an OCO pair when one side fills
+/// (`CancelOrderRequest`), creates the follow-up order of an if-done link
+/// when its trigger fills (`CreateLimitOrderRequest`) and cancels orders
+/// whose deadline has passed. `run` follows the account's
+/// `TransactionStream`, catching up with `GetTransactionsSinceIdRequest`
+/// whenever it (re)connects and polling that instead while the stream is
+/// down. `poll` does one round of catching up, for callers with their own
+/// loop.
+///
+/// A link ends when one of its orders fills or is cancelled. An action that
+/// fails is reported, kept in the state and retried on every poll, while the
+/// transactions after it are still acted upon. Actions are safe to repeat: a
+/// cancel of an order that is no longer pending, and an if-done order whose
+/// client ID already exists, count as done.
+///
+/// The links, the failed actions and the last seen transaction are saved to
+/// a JSON file after every change, so a restarted engine picks up where it
+/// stopped, including fills that happened while it was down.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn bracket(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut engine = ConditionalEngine::open("links.json", "101-004-1234567-001")?;
+/// engine.start(client).await?;
+/// // ... create limit orders 1001 (buy below) and 1002 (sell above) ...
+/// engine.add(Link::Oco { first: "1001".to_string(), second: "1002".to_string() })?;
+/// engine.run(client, std::time::Duration::from_secs(1), |event| println!("{:?}", event)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ConditionalEngine {
+    path: PathBuf,
+    prefix: String,
+    state: ConditionalState,
+}
+
+impl ConditionalEngine {
+    /// Load the state saved at `path`, or start empty if there is none.
+    pub fn open<P: Into<PathBuf>, A: Into<AccountId>>(
+        path: P,
+        account_id: A
+    ) -> Result<ConditionalEngine, Box<dyn Error>> {
+        let path = path.into();
+        let account_id = account_id.into().to_string();
+        let state = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice::<ConditionalState>(&bytes)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => ConditionalState {
+                account_id: account_id.clone(),
+                ..ConditionalState::default()
+            },
+            Err(e) => {
+                return Err(Box::new(e));
+            }
+        };
+        if state.account_id != account_id {
+            return Err(format!("{} holds links for account {}", path.display(), state.account_id).into());
+        }
+        Ok(ConditionalEngine {
+            path,
+            prefix: "fxocond".to_string(),
+            state,
+        })
+    }
+
+    /// The start of the client IDs given to if-done orders that have none.
+    /// Defaults to `fxocond`.
+    pub fn with_prefix(mut self, x: String) -> Self {
+        self.prefix = x;
+        self
+    }
+
+    pub fn state(&self) -> &ConditionalState {
+        &self.state
+    }
+
+    pub fn links(&self) -> &[Link] {
+        &self.state.links
+    }
+
+    /// Begin at the account's current last transaction, unless the saved
+    /// state already has a position. Call before creating the linked orders
+    /// so that none of their fills is missed.
+    pub async fn start<C>(&mut self, client: &Client<C>) -> Result<(), Box<dyn Error>> {
+        if self.state.last_transaction_id.is_none() {
+            let response = GetAccountSummaryRequest::new()
+                .with_account_id(self.state.account_id.clone())
+                .remote(client).await?;
+            self.state.last_transaction_id = response.last_transaction_id;
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Add a link. An `IfDone` order without a client ID is given one, and
+    /// the specifier of the order it will create (`@` and the client ID) is
+    /// returned, to be used as the trigger of the next link in a chain.
+    pub fn add(&mut self, mut link: Link) -> Result<Option<String>, Box<dyn Error>> {
+        let mut specifier = None;
+        if let Link::IfDone { ref mut then, .. } = link {
+            let extensions = then.client_extensions.get_or_insert_with(ClientExtensions::new);
+            if extensions.id.is_none() {
+                self.state.next_client_id += 1;
+                extensions.id = Some(format!("{}-{}", self.prefix, self.state.next_client_id));
+            }
+            specifier = extensions.id.as_ref().map(|id| format!("@{}", id));
+        }
+        self.state.links.push(link);
+        self.save()?;
+        Ok(specifier)
+    }
+
+    /// Remove every link that names `order`.
+    pub fn remove(&mut self, order: &str) -> Result<(), Box<dyn Error>> {
+        self.state.links.retain(|link| !link_names(link, order));
+        self.save()
+    }
+
+    /// Retry the failed actions, then act on the transactions since the last
+    /// one seen and on passed deadlines.
+    pub async fn poll(&mut self, client: &Client<Trading>) -> Result<Vec<ConditionalEvent>, Box<dyn Error>> {
+        let mut events = Vec::new();
+        self.retry(client, &mut events).await?;
+        if let Some(since) = self.state.last_transaction_id.clone() {
+            let response = GetTransactionsSinceIdRequest::new()
+                .with_account_id(self.state.account_id.clone())
+                .with_id(since)
+                .remote(client).await?;
+            for transaction in response.transactions.unwrap_or_default() {
+                self.observe(client, &transaction, &mut events).await?;
+            }
+            if response.last_transaction_id.is_some() {
+                self.state.last_transaction_id = response.last_transaction_id;
+            }
+        }
+        self.expire(client, &mut events).await?;
+        Ok(events)
+    }
+
+    /// Follow the transaction stream until an error occurs, passing each
+    /// event to `handler`. Failed actions and deadlines are seen to at least
+    /// every `interval`. While the stream cannot be opened the transactions
+    /// are polled every `interval`, and the stream is tried again each time.
+    pub async fn run<F: FnMut(ConditionalEvent)>(
+        &mut self,
+        client: &Client<Trading>,
+        interval: Duration,
+        mut handler: F
+    ) -> Result<(), Box<dyn Error>> {
+        self.start(client).await?;
+        loop {
+            // Connect before catching up so that no transaction falls between.
+            let stream = TransactionStream::connect(client, self.state.account_id.clone()).await;
+            for event in self.poll(client).await? {
+                handler(event);
+            }
+            let mut stream = match stream {
+                Ok(x) => x,
+                Err(_) => {
+                    tokio::time::sleep(interval).await;
+                    continue;
+                }
+            };
+            let mut last_round = Instant::now();
+            loop {
+                let mut events = Vec::new();
+                match tokio::time::timeout(interval, stream.next()).await {
+                    Ok(Ok(Some(transaction))) => self.observe(client, &transaction, &mut events).await?,
+                    Ok(Ok(None)) | Ok(Err(_)) => break,
+                    Err(_) => {}
+                }
+                if last_round.elapsed() >= interval {
+                    self.retry(client, &mut events).await?;
+                    self.expire(client, &mut events).await?;
+                    last_round = Instant::now();
+                }
+                for event in events {
+                    handler(event);
+                }
+            }
+        }
+    }
+
+    // Act on one transaction, unless it was seen already.
+    async fn observe(
+        &mut self,
+        client: &Client<Trading>,
+        transaction: &Transaction,
+        events: &mut Vec<ConditionalEvent>
+    ) -> Result<(), Box<dyn Error>> {
+        if !is_new(transaction.id.as_deref(), self.state.last_transaction_id.as_deref()) {
+            return Ok(());
+        }
+        for action in self.fire(transaction) {
+            self.act(client, action, events).await;
+        }
+        if transaction.id.is_some() {
+            self.state.last_transaction_id = transaction.id.clone();
+        }
+        self.save()
+    }
+
+    // Run the failed actions again.
+    async fn retry(
+        &mut self,
+        client: &Client<Trading>,
+        events: &mut Vec<ConditionalEvent>
+    ) -> Result<(), Box<dyn Error>> {
+        for action in std::mem::take(&mut self.state.pending) {
+            self.act(client, action, events).await;
+        }
+        self.save()
+    }
+
+    // Cancel the orders whose deadline has passed.
+    async fn expire(
+        &mut self,
+        client: &Client<Trading>,
+        events: &mut Vec<ConditionalEvent>
+    ) -> Result<(), Box<dyn Error>> {
+        let now = Utc::now();
+        let (due, kept): (Vec<Link>, Vec<Link>) = std::mem::take(&mut self.state.links)
+            .into_iter()
+            .partition(|link| matches!(link, Link::CancelAt { at, .. } if *at <= now));
+        self.state.links = kept;
+        for link in due {
+            if let Link::CancelAt { order, at } = link {
+                let reason = format!("deadline {}", at);
+                self.act(client, ConditionalAction::Cancel { order, reason }, events).await;
+            }
+        }
+        self.save()
+    }
+
+    // Remove the links `transaction` ends and return the actions they fire.
+    fn fire(&mut self, transaction: &Transaction) -> Vec<ConditionalAction> {
+        let filled = match transaction.otype.as_deref() {
+            Some("ORDER_FILL") => true,
+            Some("ORDER_CANCEL") => false,
+            _ => {
+                return Vec::new();
+            }
+        };
+        let mut actions = Vec::new();
+        let mut kept = Vec::new();
+        for link in std::mem::take(&mut self.state.links) {
+            match link {
+                // A cancelled side ends the pair and leaves the other alone.
+                Link::Oco { first, second } if names(transaction, &first) || names(transaction, &second) => {
+                    if filled {
+                        let (done, other) = if names(transaction, &first) { (first, second) } else { (second, first) };
+                        actions.push(ConditionalAction::Cancel { order: other, reason: format!("{} filled", done) });
+                    }
+                }
+                Link::IfDone { trigger, then } if names(transaction, &trigger) => {
+                    if filled {
+                        actions.push(ConditionalAction::Place { trigger, then });
+                    }
+                }
+                Link::CancelAt { ref order, .. } if names(transaction, order) => {}
+                _ => kept.push(link),
+            }
+        }
+        self.state.links = kept;
+        actions
+    }
+
+    // Carry out an action, keeping it for the next poll if it fails.
+    async fn act(
+        &mut self,
+        client: &Client<Trading>,
+        action: ConditionalAction,
+        events: &mut Vec<ConditionalEvent>
+    ) {
+        let account_id = &self.state.account_id;
+        let result = match action {
+            ConditionalAction::Cancel { ref order, ref reason } => {
+                cancel(client, account_id, order).await.map(|()| ConditionalEvent::Cancelled {
+                    order: order.clone(),
+                    reason: reason.clone(),
+                })
+            }
+            ConditionalAction::Place { ref trigger, ref then } => {
+                place(client, account_id, then).await.map(|client_id| ConditionalEvent::Placed {
+                    trigger: trigger.clone(),
+                    client_id,
+                })
+            }
+        };
+        match result {
+            Ok(event) => events.push(event),
+            Err(error) => {
+                events.push(ConditionalEvent::Failed { action: action.clone(), error });
+                self.state.pending.push(action);
+            }
+        }
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.state)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+fn link_names(link: &Link, order: &str) -> bool {
+    match link {
+        Link::Oco { first, second } => first == order || second == order,
+        Link::IfDone { trigger, .. } => trigger == order,
+        Link::CancelAt { order: x, .. } => x == order,
+    }
+}
+
+// Cancel a pending order; one that is no longer pending counts as cancelled.
+async fn cancel(client: &Client<Trading>, account_id: &str, order: &str) -> Result<(), String> {
+    let result = CancelOrderRequest::new()
+        .with_account_id(account_id.to_string())
+        .with_order_specifier(order.to_string())
+        .remote(client).await;
+    match cancel_reply(result) {
+        Reply::Done => Ok(()),
+        Reply::Failed(e) => Err(e),
+        Reply::Check => {
+            let result = GetOrderRequest::new()
+                .with_account_id(account_id.to_string())
+                .with_order_specifier(order.to_string())
+                .remote(client).await;
+            match lookup(result)?.and_then(|o| o.state).as_deref() {
+                Some("PENDING") => Err(format!("order {} could not be cancelled", order)),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+// Create an if-done order; one whose client ID already exists counts as
+// created.
+async fn place(client: &Client<Trading>, account_id: &str, order: &LimitOrder) -> Result<String, String> {
+    let client_id = order.client_extensions.as_ref().and_then(|x| x.id.clone()).unwrap_or_default();
+    let result = CreateLimitOrderRequest::new()
+        .with_account_id(account_id.to_string())
+        .with_order(order.clone())
+        .remote(client).await;
+    match place_reply(result) {
+        Reply::Done => Ok(client_id),
+        Reply::Failed(e) => Err(e),
+        Reply::Check => {
+            let result = GetOrderRequest::new()
+                .with_account_id(account_id.to_string())
+                .with_order_specifier(format!("@{}", client_id))
+                .remote(client).await;
+            match lookup(result)? {
+                Some(_) => Ok(client_id),
+                None => Err(format!("order @{} was not created", client_id)),
+            }
+        }
+    }
+}
+
+// What the response to a cancel or create says about the action.
+#[derive(Debug, PartialEq)]
+enum Reply {
+    Done,
+    // The action may have been done before; look the order up.
+    Check,
+    Failed(String),
+}
+
+fn cancel_reply(result: Result<CancelOrderResponse, Box<dyn Error>>) -> Reply {
+    match result {
+        Ok(x) if x.order_cancel_transaction.is_some() => Reply::Done,
+        Ok(_) => Reply::Check,
+        // The order is no longer pending, e.g. it filled.
+        Err(e) if api_error_is(&*e, 404, "ORDER_DOESNT_EXIST") => Reply::Check,
+        Err(e) => Reply::Failed(e.to_string()),
+    }
+}
+
+fn place_reply(result: Result<CreateLimitOrderResponse, Box<dyn Error>>) -> Reply {
+    match result {
+        Ok(x) if x.order_create_transaction.is_some() => Reply::Done,
+        Ok(_) => Reply::Check,
+        Err(e) if api_error_is(&*e, 400, "CLIENT_ORDER_ID_ALREADY_EXISTS") => Reply::Check,
+        Err(e) => Reply::Failed(e.to_string()),
+    }
+}
+
+// The order a `GetOrderRequest` found, `None` if there is no such order.
+fn lookup(result: Result<GetOrderResponse, Box<dyn Error>>) -> Result<Option<Order>, String> {
+    match result {
+        Ok(x) => Ok(x.order),
+        Err(e) if api_error_is(&*e, 404, "ORDER_DOESNT_EXIST") => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn api_error_is(error: &(dyn Error + 'static), status: u16, code: &str) -> bool {
+    error.downcast_ref::<ApiError>().is_some_and(|e| e.status == status && e.code.as_deref() == Some(code))
+}
+
+// Whether a transaction ID comes after the last one seen. Transaction IDs
+// are increasing integers.
+fn is_new(id: Option<&str>, last: Option<&str>) -> bool {
+    let number = |x: Option<&str>| x.and_then(|x| x.parse::<u64>().ok());
+    match (number(id), number(last)) {
+        (Some(id), Some(last)) => id > last,
+        _ => true,
+    }
+}
+
+// Whether the transaction is about the order with this specifier.
+fn names(transaction: &Transaction, specifier: &str) -> bool {
+    match specifier.strip_prefix('@') {
+        Some(client_id) => transaction.client_order_id.as_deref() == Some(client_id),
+        None => transaction.order_id.as_deref() == Some(specifier),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persists_links_and_matches_transactions() {
+        let path = std::env::temp_dir().join(format!("fxoanda-conditional-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut engine = ConditionalEngine::open(&path, "101-004-1234567-001").unwrap();
+        engine.add(Link::Oco { first: "10".to_string(), second: "11".to_string() }).unwrap();
+        let next = engine
+            .add(Link::IfDone { trigger: "10".to_string(), then: Box::new(LimitOrder::new().with_price(1.1)) })
+            .unwrap();
+        assert_eq!(next.as_deref(), Some("@fxocond-1"));
+
+        let reopened = ConditionalEngine::open(&path, "101-004-1234567-001").unwrap();
+        assert_eq!(reopened.links(), engine.links());
+        assert!(ConditionalEngine::open(&path, "101-004-1234567-002").is_err());
+        fs::remove_file(&path).unwrap();
+
+        let fill: Transaction = serde_json::from_str(
+            r#"{"id":"12","type":"ORDER_FILL","orderID":"10","clientOrderID":"fxocond-1"}"#
+        ).unwrap();
+        assert!(names(&fill, "10"));
+        assert!(names(&fill, "@fxocond-1"));
+        assert!(!names(&fill, "11"));
+        assert!(is_new(Some("12"), Some("9")));
+        assert!(!is_new(Some("9"), Some("12")));
+    }
+
+    fn api_error(status: u16, code: &str) -> Box<dyn Error> {
+        let body = format!(r#"{{"errorCode":"{}","errorMessage":"refused"}}"#, code);
+        Box::new(ApiError::new(Environment::Practice, status, body.as_bytes()))
+    }
+
+    #[test]
+    fn cancel_of_a_filled_order_counts_as_done() {
+        assert_eq!(cancel_reply(Err(api_error(404, "ORDER_DOESNT_EXIST"))), Reply::Check);
+        let filled = Order::new().with_id("10".to_string()).with_state("FILLED".to_string());
+        let found = lookup(Ok(GetOrderResponse { order: Some(filled), last_transaction_id: None })).unwrap();
+        assert_eq!(found.and_then(|o| o.state).as_deref(), Some("FILLED"));
+        assert_eq!(lookup(Err(api_error(404, "ORDER_DOESNT_EXIST"))), Ok(None));
+        assert!(matches!(cancel_reply(Err(api_error(401, "INSUFFICIENT_AUTHORIZATION"))), Reply::Failed(_)));
+    }
+
+    #[test]
+    fn duplicate_client_id_counts_as_created() {
+        assert_eq!(place_reply(Err(api_error(400, "CLIENT_ORDER_ID_ALREADY_EXISTS"))), Reply::Check);
+        assert!(matches!(place_reply(Err(api_error(400, "UNITS_INVALID"))), Reply::Failed(_)));
+    }
+
+    #[test]
+    fn fires_and_ends_links() {
+        let path = std::env::temp_dir().join(format!("fxoanda-conditional-fire-{}.json", std::process::id()));
+        let mut engine = ConditionalEngine::open(&path, "101-004-1234567-001").unwrap();
+        engine.state.links = vec![
+            Link::Oco { first: "10".to_string(), second: "11".to_string() },
+            Link::Oco { first: "20".to_string(), second: "21".to_string() },
+        ];
+        let transaction = |otype: &str, order: &str| {
+            Transaction::new().with_otype(otype.to_string()).with_order_id(order.to_string())
+        };
+        assert_eq!(
+            engine.fire(&transaction("ORDER_FILL", "11")),
+            vec![ConditionalAction::Cancel { order: "10".to_string(), reason: "11 filled".to_string() }]
+        );
+        assert_eq!(engine.fire(&transaction("ORDER_CANCEL", "20")), vec![]);
+        assert!(engine.links().is_empty());
+    }
+}
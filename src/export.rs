@@ -0,0 +1,450 @@
+//! Tabular export of candles, price ticks and transactions.
+//!
+//! Every export goes through a `Table` with a fixed column schema, so the
+//! same data always produces the same columns whichever format it is
+//! written in:
+//!
+//! - times are UTC; RFC 3339 strings in CSV and nanosecond timestamps
+//!   tagged "UTC" in Arrow and Parquet,
+//! - prices and balances are decimals; the shortest string that
+//!   round-trips the parsed value in CSV and `Decimal128(18, 6)` in Arrow
+//!   and Parquet. Balances are parsed as `f64` so that large ones keep
+//!   their cents,
+//! - candles get one column per price component and OHLC field
+//!   (`bid_o` .. `ask_c`), left empty when the component was not requested.
+//!
+//! CSV output needs the `export-csv` feature, Arrow record batches and IPC
+//! files need `export-arrow` and Parquet files need `export-parquet`.
+
+use chrono::prelude::*;
+use std::error::Error;
+
+use crate::*;
+
+/// Number of decimal places kept for prices and balances in Arrow and
+/// Parquet output.
+pub const PRICE_SCALE: i8 = 6;
+
+/// The values of one `Table` column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    Time(Vec<Option<DateTime<Utc>>>),
+    Price(Vec<Option<f32>>),
+    Amount(Vec<Option<f64>>),
+    Integer(Vec<Option<i64>>),
+    Boolean(Vec<Option<bool>>),
+    Text(Vec<Option<String>>),
+}
+
+impl Column {
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Time(x) => x.len(),
+            Column::Price(x) => x.len(),
+            Column::Amount(x) => x.len(),
+            Column::Integer(x) => x.len(),
+            Column::Boolean(x) => x.len(),
+            Column::Text(x) => x.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The CSV representation of row `i`; empty for missing values.
+    pub fn text(&self, i: usize) -> String {
+        match self {
+            Column::Time(x) => {
+                x[i].map(|t| t.to_rfc3339_opts(SecondsFormat::AutoSi, true)).unwrap_or_default()
+            }
+            Column::Price(x) => x[i].map(|v| v.to_string()).unwrap_or_default(),
+            Column::Amount(x) => x[i].map(|v| v.to_string()).unwrap_or_default(),
+            Column::Integer(x) => x[i].map(|v| v.to_string()).unwrap_or_default(),
+            Column::Boolean(x) => x[i].map(|v| v.to_string()).unwrap_or_default(),
+            Column::Text(x) => x[i].clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Named columns of equal length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    names: Vec<&'static str>,
+    columns: Vec<Column>,
+}
+
+impl Table {
+    fn new() -> Table {
+        Table {
+            names: Vec::new(),
+            columns: Vec::new(),
+        }
+    }
+
+    fn with(mut self, name: &'static str, column: Column) -> Self {
+        self.names.push(name);
+        self.columns.push(column);
+        self
+    }
+
+    /// Column names, in order.
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.columns.first().map_or(0, |c| c.len())
+    }
+
+    /// Columns `time, volume, complete`, then `bid_o, bid_h, bid_l, bid_c`,
+    /// `mid_o` .. `mid_c` and `ask_o` .. `ask_c`.
+    pub fn candles(candles: &[Candlestick]) -> Table {
+        let mut table = Table::new()
+            .with("time", Column::Time(candles.iter().map(|c| c.time).collect()))
+            .with("volume", Column::Integer(candles.iter().map(|c| c.volume.map(i64::from)).collect()))
+            .with("complete", Column::Boolean(candles.iter().map(|c| c.complete).collect()));
+        let components: [(PriceComponent, [&'static str; 4]); 3] = [
+            (PriceComponent::Bid, ["bid_o", "bid_h", "bid_l", "bid_c"]),
+            (PriceComponent::Mid, ["mid_o", "mid_h", "mid_l", "mid_c"]),
+            (PriceComponent::Ask, ["ask_o", "ask_h", "ask_l", "ask_c"]),
+        ];
+        for (component, names) in components.iter() {
+            let fields: [fn(&CandlestickData) -> Option<f32>; 4] = [
+                |d| d.o,
+                |d| d.h,
+                |d| d.l,
+                |d| d.c,
+            ];
+            for (name, field) in names.iter().zip(fields.iter()) {
+                let values = candles
+                    .iter()
+                    .map(|c| component.data(c).and_then(field))
+                    .collect();
+                table = table.with(name, Column::Price(values));
+            }
+        }
+        table
+    }
+
+    /// Price ticks from the pricing endpoints or the pricing stream. Columns
+    /// `time, instrument, bid, ask, bid_liquidity, ask_liquidity,
+    /// closeout_bid, closeout_ask, tradeable`; `bid` and `ask` are the best
+    /// (first) bucket of each side.
+    pub fn client_prices(prices: &[ClientPrice]) -> Table {
+        Table::ticks(
+            prices
+                .iter()
+                .map(|p| {
+                    Tick {
+                        time: p.time,
+                        instrument: p.instrument.clone(),
+                        bid: best(&p.bids),
+                        ask: best(&p.asks),
+                        closeout_bid: p.closeout_bid,
+                        closeout_ask: p.closeout_ask,
+                        tradeable: p.tradeable,
+                    }
+                })
+                .collect()
+        )
+    }
+
+    /// Price ticks from the price range endpoints, with the same columns as
+    /// `client_prices`.
+    pub fn prices(prices: &[Price]) -> Table {
+        Table::ticks(
+            prices
+                .iter()
+                .map(|p| {
+                    Tick {
+                        time: p.timestamp,
+                        instrument: p.instrument.clone(),
+                        bid: best(&p.bids),
+                        ask: best(&p.asks),
+                        closeout_bid: p.closeout_bid,
+                        closeout_ask: p.closeout_ask,
+                        tradeable: p.tradeable,
+                    }
+                })
+                .collect()
+        )
+    }
+
+    fn ticks(ticks: Vec<Tick>) -> Table {
+        Table::new()
+            .with("time", Column::Time(ticks.iter().map(|t| t.time).collect()))
+            .with("instrument", Column::Text(ticks.iter().map(|t| t.instrument.clone()).collect()))
+            .with("bid", Column::Price(ticks.iter().map(|t| t.bid.and_then(|b| b.price)).collect()))
+            .with("ask", Column::Price(ticks.iter().map(|t| t.ask.and_then(|b| b.price)).collect()))
+            .with(
+                "bid_liquidity",
+                Column::Integer(ticks.iter().map(|t| t.bid.and_then(|b| b.liquidity).map(i64::from)).collect())
+            )
+            .with(
+                "ask_liquidity",
+                Column::Integer(ticks.iter().map(|t| t.ask.and_then(|b| b.liquidity).map(i64::from)).collect())
+            )
+            .with("closeout_bid", Column::Price(ticks.iter().map(|t| t.closeout_bid).collect()))
+            .with("closeout_ask", Column::Price(ticks.iter().map(|t| t.closeout_ask).collect()))
+            .with("tradeable", Column::Boolean(ticks.iter().map(|t| t.tradeable).collect()))
+    }
+
+    /// Columns `id, time, account_id, user_id, batch_id, request_id, type,
+    /// order_id, instrument, units, price, account_balance, reason, details`.
+    /// `details` holds every other field of the transaction as a JSON object,
+    /// including the `extra` fields with the `extra-fields` feature, and is
+    /// empty when there are none.
+    pub fn transactions(transactions: &[Transaction]) -> Table {
+        Table::new()
+            .with("id", Column::Text(transactions.iter().map(|t| t.id.clone()).collect()))
+            .with("time", Column::Time(transactions.iter().map(|t| t.time).collect()))
+            .with("account_id", Column::Text(transactions.iter().map(|t| t.account_id.clone()).collect()))
+            .with("user_id", Column::Integer(transactions.iter().map(|t| t.user_id.map(i64::from)).collect()))
+            .with("batch_id", Column::Text(transactions.iter().map(|t| t.batch_id.clone()).collect()))
+            .with("request_id", Column::Text(transactions.iter().map(|t| t.request_id.clone()).collect()))
+            .with("type", Column::Text(transactions.iter().map(|t| t.otype.clone()).collect()))
+            .with("order_id", Column::Text(transactions.iter().map(|t| t.order_id.clone()).collect()))
+            .with("instrument", Column::Text(transactions.iter().map(|t| t.instrument.clone()).collect()))
+            .with("units", Column::Price(transactions.iter().map(|t| t.units).collect()))
+            .with("price", Column::Price(transactions.iter().map(|t| t.price).collect()))
+            .with("account_balance", Column::Amount(transactions.iter().map(|t| t.account_balance).collect()))
+            .with("reason", Column::Text(transactions.iter().map(|t| t.reason.clone()).collect()))
+            .with("details", Column::Text(transactions.iter().map(transaction_details).collect()))
+    }
+
+    /// Write the table as CSV with a header row.
+    #[cfg(feature = "export-csv")]
+    pub fn write_csv<W: std::io::Write>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        let mut out = csv::Writer::from_writer(writer);
+        out.write_record(&self.names)?;
+        for i in 0..self.rows() {
+            out.write_record(self.columns.iter().map(|c| c.text(i)))?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    /// The Arrow schema of the table.
+    #[cfg(feature = "export-arrow")]
+    pub fn schema(&self) -> arrow::datatypes::Schema {
+        use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+        let fields: Vec<Field> = self.names
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(name, column)| {
+                let data_type = match column {
+                    Column::Time(_) => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                    Column::Price(_) | Column::Amount(_) => DataType::Decimal128(18, PRICE_SCALE),
+                    Column::Integer(_) => DataType::Int64,
+                    Column::Boolean(_) => DataType::Boolean,
+                    Column::Text(_) => DataType::Utf8,
+                };
+                Field::new(*name, data_type, true)
+            })
+            .collect();
+        Schema::new(fields)
+    }
+
+    /// The table as a single Arrow record batch.
+    #[cfg(feature = "export-arrow")]
+    pub fn to_record_batch(&self) -> Result<arrow::record_batch::RecordBatch, Box<dyn Error>> {
+        use arrow::array::*;
+        use std::sync::Arc;
+        let arrays: Vec<ArrayRef> = self.columns
+            .iter()
+            .map(|column| -> Result<ArrayRef, Box<dyn Error>> {
+                Ok(match column {
+                    Column::Time(x) => Arc::new(
+                        TimestampNanosecondArray::from(
+                            x.iter().map(|t| t.and_then(|t| t.timestamp_nanos_opt())).collect::<Vec<_>>()
+                        ).with_timezone("UTC")
+                    ),
+                    Column::Price(x) => Arc::new(
+                        Decimal128Array::from(x.iter().map(|v| v.map(exact).map(scaled)).collect::<Vec<_>>())
+                            .with_precision_and_scale(18, PRICE_SCALE)?
+                    ),
+                    Column::Amount(x) => Arc::new(
+                        Decimal128Array::from(x.iter().map(|v| v.map(scaled)).collect::<Vec<_>>())
+                            .with_precision_and_scale(18, PRICE_SCALE)?
+                    ),
+                    Column::Integer(x) => Arc::new(Int64Array::from(x.clone())),
+                    Column::Boolean(x) => Arc::new(BooleanArray::from(x.clone())),
+                    Column::Text(x) => Arc::new(StringArray::from(x.clone())),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(arrow::record_batch::RecordBatch::try_new(Arc::new(self.schema()), arrays)?)
+    }
+
+    /// Write the table as an Arrow IPC file.
+    #[cfg(feature = "export-arrow")]
+    pub fn write_arrow<W: std::io::Write>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        let batch = self.to_record_batch()?;
+        let mut out = arrow::ipc::writer::FileWriter::try_new(writer, &batch.schema())?;
+        out.write(&batch)?;
+        out.finish()?;
+        Ok(())
+    }
+
+    /// Write the table as a Parquet file.
+    #[cfg(feature = "export-parquet")]
+    pub fn write_parquet<W: std::io::Write + Send>(&self, writer: W) -> Result<(), Box<dyn Error>> {
+        let batch = self.to_record_batch()?;
+        let mut out = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None)?;
+        out.write(&batch)?;
+        out.close()?;
+        Ok(())
+    }
+}
+
+struct Tick {
+    time: Option<DateTime<Utc>>,
+    instrument: Option<String>,
+    bid: Option<PriceBucketValue>,
+    ask: Option<PriceBucketValue>,
+    closeout_bid: Option<f32>,
+    closeout_ask: Option<f32>,
+    tradeable: Option<bool>,
+}
+
+#[derive(Clone, Copy)]
+struct PriceBucketValue {
+    price: Option<f32>,
+    liquidity: Option<i32>,
+}
+
+fn best(buckets: &Option<Vec<PriceBucket>>) -> Option<PriceBucketValue> {
+    buckets
+        .as_ref()
+        .and_then(|b| b.first())
+        .map(|b| PriceBucketValue {
+            price: b.price,
+            liquidity: b.liquidity,
+        })
+}
+
+// The decimal value of `x`, taken from the shortest string that round-trips
+// the f32 so that e.g. 1.10001 stays 1.10001 rather than 1.1000100374.
+#[cfg(feature = "export-arrow")]
+fn exact(x: f32) -> f64 {
+    x.to_string().parse().unwrap_or(x as f64)
+}
+
+// The decimal value of `x` with PRICE_SCALE places.
+#[cfg(feature = "export-arrow")]
+fn scaled(x: f64) -> i128 {
+    (x * 10f64.powi(PRICE_SCALE as i32)).round() as i128
+}
+
+// The wire names of the fields that have their own transaction column.
+const TRANSACTION_COLUMNS: [&str; 13] = [
+    "id",
+    "time",
+    "accountID",
+    "userID",
+    "batchID",
+    "requestID",
+    "type",
+    "orderID",
+    "instrument",
+    "units",
+    "price",
+    "accountBalance",
+    "reason",
+];
+
+fn transaction_details(t: &Transaction) -> Option<String> {
+    let mut fields = match serde_json::to_value(t) {
+        Ok(serde_json::Value::Object(x)) => x,
+        _ => return None,
+    };
+    for name in TRANSACTION_COLUMNS.iter() {
+        fields.remove(*name);
+    }
+    if fields.is_empty() {
+        return None;
+    }
+    serde_json::to_string(&fields).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "export-csv")]
+    #[test]
+    fn candles_csv() {
+        let candle = Candlestick::new()
+            .with_time(Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap())
+            .with_volume(12)
+            .with_complete(true)
+            .with_bid(CandlestickData::new().with_o(1.10001).with_h(1.1002).with_l(1.0999).with_c(1.1001));
+        let mut out = Vec::new();
+        Table::candles(&[candle]).write_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "time,volume,complete,bid_o,bid_h,bid_l,bid_c,mid_o,mid_h,mid_l,mid_c,ask_o,ask_h,ask_l,ask_c\n\
+             2020-01-06T00:00:00Z,12,true,1.10001,1.1002,1.0999,1.1001,,,,,,,,\n"
+        );
+    }
+
+    fn fill() -> Transaction {
+        Transaction::new()
+            .with_id("6".to_string())
+            .with_time(Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap())
+            .with_otype("ORDER_FILL".to_string())
+            .with_order_id("5".to_string())
+            .with_instrument("EUR_USD".to_string())
+            .with_units(-100.0)
+            .with_price(1.10001)
+            .with_account_balance(12345678.91)
+            .with_reason("MARKET_ORDER".to_string())
+            .with_trade_opened(TradeOpen::new().with_trade_id("6".to_string()))
+    }
+
+    #[test]
+    fn transaction_columns() {
+        let table = Table::transactions(&[fill(), Transaction::new()]);
+        let column = |name| &table.columns()[table.names().iter().position(|x| *x == name).unwrap()];
+        assert_eq!(column("type").text(0), "ORDER_FILL");
+        assert_eq!(column("account_balance").text(0), "12345678.91");
+        assert_eq!(column("details").text(0), r#"{"tradeOpened":{"tradeID":"6"}}"#);
+        assert_eq!(column("details").text(1), "");
+    }
+
+    #[cfg(feature = "export-arrow")]
+    #[test]
+    fn arrow_round_trip() {
+        let table = Table::transactions(&[fill(), Transaction::new()]);
+        let mut out = Vec::new();
+        table.write_arrow(&mut out).unwrap();
+        let mut reader = arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(out), None).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch, table.to_record_batch().unwrap());
+        assert!(reader.next().is_none());
+        let balance = batch.column_by_name("account_balance").unwrap();
+        let balance = balance.as_any().downcast_ref::<arrow::array::Decimal128Array>().unwrap();
+        assert_eq!(balance.value_as_string(0), "12345678.910000");
+    }
+
+    #[cfg(feature = "export-parquet")]
+    #[test]
+    fn parquet_round_trip() {
+        let table = Table::transactions(&[fill(), Transaction::new()]);
+        let path = std::env::temp_dir().join(format!("fxoanda-export-{}.parquet", std::process::id()));
+        table.write_parquet(std::fs::File::create(&path).unwrap()).unwrap();
+        let file = std::fs::File::open(&path).unwrap();
+        let mut reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), table.to_record_batch().unwrap());
+        assert!(reader.next().is_none());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::time::Duration;
+
+use crate::*;
+
+/// A position side that was closed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SideClosed {
+    /// The units of the closing fill, negative when a long side was sold.
+    pub units: f32,
+    /// The average fill price.
+    pub price: Option<f32>,
+    pub transaction_id: Option<String>,
+}
+
+/// What happened to one instrument's position.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InstrumentFlatten {
+    pub long: Option<SideClosed>,
+    pub short: Option<SideClosed>,
+    /// The errors of the last attempt to close the position, if it is still
+    /// open.
+    pub errors: Vec<String>,
+}
+
+/// The outcome of flattening one account.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FlattenReport {
+    pub account_id: String,
+    /// The IDs of the pending orders that were cancelled.
+    pub cancelled_orders: Vec<String>,
+    /// The pending orders still open after the last attempt, with the error.
+    pub failed_orders: BTreeMap<String, String>,
+    /// The stop loss, take profit and trailing stop orders left in place in
+    /// the last attempt because their position could not be closed.
+    pub kept_orders: Vec<String>,
+    pub instruments: BTreeMap<String, InstrumentFlatten>,
+    /// Errors that are not about one order or instrument, e.g. a failure to
+    /// list the positions.
+    pub errors: Vec<String>,
+}
+
+impl FlattenReport {
+    /// Whether every pending order was cancelled and every position closed.
+    pub fn is_flat(&self) -> bool {
+        self.failed_orders.is_empty()
+            && self.errors.is_empty()
+            && self.instruments.values().all(|x| x.errors.is_empty())
+    }
+}
+
+/// Cancels every pending order and closes every open position of one or all
+/// accounts, for use in an emergency.
+///
+/// Each attempt lists the open positions (`ListOpenPositionsRequest`) and
+/// closes all units of each non-empty side with `ClosePositionRequest`, then
+/// lists the pending orders (`ListPendingOrdersRequest`) and cancels the ones
+/// left one by one. Positions go first so they keep their stop loss and take
+/// profit orders until they are closed, which also cancels those orders. The
+/// orders of trades in instruments whose close failed are not cancelled, so
+/// a position that stays open keeps its protection; they are listed in
+/// `kept_orders`.
+/// Failures do not stop the run. If anything failed, the next attempt starts
+/// again from fresh lists, so only what is still open is retried. Errors,
+/// including the message of a request the server refused, are collected in
+/// the `FlattenReport` instead of being returned.
+///
+/// Closing positions is not blocked by the client's `RiskGuard` kill switch,
+/// but a live account must still be confirmed with
+/// `Client::allow_live_trading`.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn panic_button(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// for report in Flatten::new().all_accounts(client).await? {
+///     for (instrument, x) in &report.instruments {
+///         println!("{} {}: long {:?} short {:?} errors {:?}", report.account_id, instrument, x.long, x.short, x.errors);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Flatten {
+    attempts: u32,
+    retry_interval: Duration,
+}
+
+impl Default for Flatten {
+    fn default() -> Flatten {
+        Flatten::new()
+    }
+}
+
+impl Flatten {
+    pub fn new() -> Flatten {
+        Flatten {
+            attempts: 3,
+            retry_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// How many times to go through the orders and positions. Defaults to 3.
+    pub fn with_attempts(mut self, x: u32) -> Self {
+        self.attempts = x.max(1);
+        self
+    }
+
+    /// How long to wait before retrying. Defaults to one second.
+    pub fn with_retry_interval(mut self, x: Duration) -> Self {
+        self.retry_interval = x;
+        self
+    }
+
+    /// Flatten every account the token can access.
+    pub async fn all_accounts(&self, client: &Client<Trading>) -> Result<Vec<FlattenReport>, Box<dyn Error>> {
+        let accounts = ListAccountsRequest::new().remote(client).await?.accounts.unwrap_or_default();
+        let mut reports = Vec::new();
+        for id in accounts.into_iter().filter_map(|a| a.id) {
+            reports.push(self.account(client, id).await);
+        }
+        Ok(reports)
+    }
+
+    /// Flatten one account.
+    pub async fn account<A: Into<AccountId>>(&self, client: &Client<Trading>, account_id: A) -> FlattenReport {
+        let account_id = account_id.into().to_string();
+        let mut report = FlattenReport {
+            account_id: account_id.clone(),
+            ..FlattenReport::default()
+        };
+        for attempt in 0..self.attempts {
+            if attempt > 0 {
+                tokio::time::sleep(self.retry_interval).await;
+            }
+            report.errors.clear();
+            report.failed_orders.clear();
+            report.kept_orders.clear();
+            for x in report.instruments.values_mut() {
+                x.errors.clear();
+            }
+            close_positions(client, &account_id, &mut report).await;
+            cancel_orders(client, &account_id, &mut report).await;
+            if report.is_flat() {
+                break;
+            }
+        }
+        report
+    }
+}
+
+async fn cancel_orders(client: &Client<Trading>, account_id: &str, report: &mut FlattenReport) {
+    let orders = match ListPendingOrdersRequest::new()
+        .with_account_id(account_id.to_string())
+        .remote(client).await
+    {
+        Ok(x) => x.orders.unwrap_or_default(),
+        Err(e) => {
+            report.errors.push(format!("listing pending orders: {}", e));
+            return;
+        }
+    };
+    let kept = kept_trades(client, account_id, report).await;
+    for order in orders {
+        let id = match order.id {
+            Some(ref x) => x.clone(),
+            None => continue,
+        };
+        if keeps(&kept, &order) {
+            report.kept_orders.push(id);
+            continue;
+        }
+        let result = CancelOrderRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_order_specifier(id.clone())
+            .remote(client).await;
+        match result {
+            Ok(x) if x.order_cancel_transaction.is_some() => report.cancelled_orders.push(id),
+            Ok(_) => {
+                report.failed_orders.insert(id, "no cancel transaction in response".to_string());
+            }
+            Err(e) => {
+                report.failed_orders.insert(id, e.to_string());
+            }
+        }
+    }
+}
+
+// The open trades whose dependent orders must stay because their instrument
+// could not be closed. `None` when they could not be listed, which keeps the
+// dependent orders of every trade.
+async fn kept_trades(
+    client: &Client<Trading>,
+    account_id: &str,
+    report: &mut FlattenReport
+) -> Option<BTreeSet<String>> {
+    if report.instruments.values().all(|x| x.errors.is_empty()) {
+        return Some(BTreeSet::new());
+    }
+    match ListOpenTradesRequest::new()
+        .with_account_id(account_id.to_string())
+        .remote(client).await
+    {
+        Ok(x) => Some(trades_still_open(report, &x.trades.unwrap_or_default())),
+        Err(e) => {
+            report.errors.push(format!("listing open trades: {}", e));
+            None
+        }
+    }
+}
+
+fn trades_still_open(report: &FlattenReport, trades: &[Trade]) -> BTreeSet<String> {
+    let failed = |instrument: &String| report.instruments.get(instrument).is_some_and(|x| !x.errors.is_empty());
+    trades
+        .iter()
+        .filter(|t| t.instrument.as_ref().is_some_and(failed))
+        .filter_map(|t| t.id.clone())
+        .collect()
+}
+
+fn keeps(kept: &Option<BTreeSet<String>>, order: &Order) -> bool {
+    match (kept, order.trade_id.as_ref()) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(trades), Some(trade)) => trades.contains(trade),
+    }
+}
+
+async fn close_positions(client: &Client<Trading>, account_id: &str, report: &mut FlattenReport) {
+    let positions = match ListOpenPositionsRequest::new()
+        .with_account_id(account_id.to_string())
+        .remote(client).await
+    {
+        Ok(x) => x.positions.unwrap_or_default(),
+        Err(e) => {
+            report.errors.push(format!("listing open positions: {}", e));
+            return;
+        }
+    };
+    for position in positions {
+        let instrument = match position.instrument {
+            Some(ref x) => x.clone(),
+            None => continue,
+        };
+        let long = side_open(&position.long);
+        let short = side_open(&position.short);
+        if !long && !short {
+            continue;
+        }
+        let units = |open| if open { CloseUnits::All } else { CloseUnits::None };
+        let result = ClosePositionRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_instrument(instrument.clone())
+            .with_long_units(units(long))
+            .with_short_units(units(short))
+            .remote(client).await;
+        let entry = report.instruments.entry(instrument).or_default();
+        match result {
+            Ok(x) => record_close(entry, long, short, x),
+            Err(e) => entry.errors.push(e.to_string()),
+        }
+    }
+}
+
+fn side_open(side: &Option<PositionSide>) -> bool {
+    side.as_ref().and_then(|s| s.units).is_some_and(|u| u != 0.0)
+}
+
+fn record_close(entry: &mut InstrumentFlatten, long: bool, short: bool, response: ClosePositionResponse) {
+    let sides = [
+        ("long", long, response.long_order_fill_transaction, response.long_order_cancel_transaction, &mut entry.long),
+        ("short", short, response.short_order_fill_transaction, response.short_order_cancel_transaction, &mut entry.short),
+    ];
+    for (name, requested, fill, cancel, closed) in sides {
+        if !requested {
+            continue;
+        }
+        match (fill, cancel) {
+            (Some(fill), _) => {
+                *closed = Some(SideClosed {
+                    units: fill.units.unwrap_or(0.0),
+                    price: fill.price.or(fill.full_vwap),
+                    transaction_id: fill.id,
+                });
+            }
+            (None, Some(cancel)) => {
+                let reason = cancel.reason.unwrap_or_else(|| "UNKNOWN".to_string());
+                entry.errors.push(format!("{} close order cancelled: {}", name, reason));
+            }
+            (None, None) => entry.errors.push(format!("{} side was not closed", name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_closed_sides_and_failures() {
+        let mut entry = InstrumentFlatten::default();
+        let response = ClosePositionResponse {
+            long_order_fill_transaction: Some(
+                OrderFillTransaction::new().with_id("7".to_string()).with_units(-100.0).with_price(1.1)
+            ),
+            short_order_cancel_transaction: Some(
+                OrderCancelTransaction::new().with_reason("MARKET_HALTED".to_string())
+            ),
+            ..ClosePositionResponse::default()
+        };
+        record_close(&mut entry, true, true, response);
+        assert_eq!(
+            entry.long,
+            Some(SideClosed { units: -100.0, price: Some(1.1), transaction_id: Some("7".to_string()) })
+        );
+        assert_eq!(entry.short, None);
+        assert_eq!(entry.errors, vec!["short close order cancelled: MARKET_HALTED".to_string()]);
+
+        let mut report = FlattenReport::default();
+        report.instruments.insert("EUR_USD".to_string(), entry);
+        assert!(!report.is_flat());
+    }
+
+    #[test]
+    fn keeps_the_orders_of_positions_that_failed_to_close() {
+        let mut report = FlattenReport::default();
+        report.instruments.insert(
+            "EUR_USD".to_string(),
+            InstrumentFlatten { errors: vec!["HTTP 400 MARKET_HALTED".to_string()], ..InstrumentFlatten::default() }
+        );
+        report.instruments.insert("USD_JPY".to_string(), InstrumentFlatten::default());
+        let trade = |id: &str, instrument: &str| {
+            Trade::new().with_id(id.to_string()).with_instrument(instrument.to_string())
+        };
+        let open = trades_still_open(&report, &[trade("5", "EUR_USD"), trade("6", "USD_JPY")]);
+        assert_eq!(open, BTreeSet::from(["5".to_string()]));
+
+        let stop = |trade: &str| Order::new().with_id("9".to_string()).with_trade_id(trade.to_string());
+        assert!(keeps(&Some(open.clone()), &stop("5")));
+        assert!(!keeps(&Some(open), &stop("6")));
+        assert!(!keeps(&None, &Order::new().with_id("10".to_string())));
+        assert!(keeps(&None, &stop("6")));
+    }
+}
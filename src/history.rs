@@ -0,0 +1,482 @@
+use chrono::prelude::*;
+use chrono::Duration;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::error::Error;
+use std::time::Duration as StdDuration;
+
+use crate::*;
+
+/// The largest number of candles OANDA returns for a single request.
+pub const MAX_CANDLES_PER_REQUEST: i64 = 5000;
+
+/// The number of ticks at which a price range response is taken to have
+/// been truncated.
+pub const MAX_TICKS_PER_REQUEST: usize = 5000;
+
+/// The inclusive `(from, to)` bounds of one request.
+pub type Window = (DateTime<Utc>, DateTime<Utc>);
+
+/// The length of one candle of the given granularity.
+///
+/// Weekly and monthly candles do not have a fixed length; `W` is seven days
+/// and `M` is 28 days, the shortest month, so that a window sized from it
+/// never holds more candles than intended.
+pub fn granularity_duration(granularity: CandlestickGranularity) -> Duration {
+    let seconds = match granularity {
+        CandlestickGranularity::S5 => 5,
+        CandlestickGranularity::S10 => 10,
+        CandlestickGranularity::S15 => 15,
+        CandlestickGranularity::S30 => 30,
+        CandlestickGranularity::M1 => 60,
+        CandlestickGranularity::M2 => 2 * 60,
+        CandlestickGranularity::M4 => 4 * 60,
+        CandlestickGranularity::M5 => 5 * 60,
+        CandlestickGranularity::M10 => 10 * 60,
+        CandlestickGranularity::M15 => 15 * 60,
+        CandlestickGranularity::M30 => 30 * 60,
+        CandlestickGranularity::H1 => 3600,
+        CandlestickGranularity::H2 => 2 * 3600,
+        CandlestickGranularity::H3 => 3 * 3600,
+        CandlestickGranularity::H4 => 4 * 3600,
+        CandlestickGranularity::H6 => 6 * 3600,
+        CandlestickGranularity::H8 => 8 * 3600,
+        CandlestickGranularity::H12 => 12 * 3600,
+        CandlestickGranularity::D => 86400,
+        CandlestickGranularity::W => 7 * 86400,
+        CandlestickGranularity::M => 28 * 86400,
+    };
+    Duration::seconds(seconds)
+}
+
+/// Downloads candles over an arbitrarily long time range.
+///
+/// The range is split into windows that each hold at most 5000 candles,
+/// the windows are fetched with `GetInstrumentCandlesRequest` with a bounded
+/// number of requests in flight, and the candle on each window boundary is
+/// only returned once.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use chrono::prelude::*;
+/// # async fn backfill(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let candles = CandleHistory::new("EUR_USD".to_string(), CandlestickGranularity::M1)
+///     .with_price("BA".to_string())
+///     .with_from(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+///     .with_to(Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap())
+///     .fetch(client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleHistory {
+    instrument: String,
+    granularity: CandlestickGranularity,
+    price: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    concurrency: usize,
+    window_size: i64,
+    daily_alignment: Option<i32>,
+    alignment_timezone: Option<String>,
+    weekly_alignment: Option<WeeklyAlignment>,
+}
+
+impl CandleHistory {
+    pub fn new(instrument: String, granularity: CandlestickGranularity) -> CandleHistory {
+        CandleHistory {
+            instrument,
+            granularity,
+            price: None,
+            from: None,
+            to: None,
+            concurrency: 4,
+            window_size: MAX_CANDLES_PER_REQUEST,
+            daily_alignment: None,
+            alignment_timezone: None,
+            weekly_alignment: None,
+        }
+    }
+
+    /// The Price component(s) to get candlestick data for, any combination
+    /// of "M", "B" and "A". The server default is "M".
+    pub fn with_price(mut self, x: String) -> Self {
+        self.price = Some(x);
+        self
+    }
+
+    /// The start of the range. Required.
+    pub fn with_from(mut self, x: DateTime<Utc>) -> Self {
+        self.from = Some(x);
+        self
+    }
+
+    /// The end of the range. Defaults to now.
+    pub fn with_to(mut self, x: DateTime<Utc>) -> Self {
+        self.to = Some(x);
+        self
+    }
+
+    /// The maximum number of requests in flight at once. Defaults to 4.
+    pub fn with_concurrency(mut self, x: usize) -> Self {
+        self.concurrency = x.max(1);
+        self
+    }
+
+    /// The number of candles requested per window, capped at 5000.
+    pub fn with_window_size(mut self, x: i64) -> Self {
+        self.window_size = x.clamp(1, MAX_CANDLES_PER_REQUEST);
+        self
+    }
+
+    /// Passed through as `dailyAlignment` on every request.
+    pub fn with_daily_alignment(mut self, x: i32) -> Self {
+        self.daily_alignment = Some(x);
+        self
+    }
+
+    /// Passed through as `alignmentTimezone` on every request.
+    pub fn with_alignment_timezone(mut self, x: String) -> Self {
+        self.alignment_timezone = Some(x);
+        self
+    }
+
+    /// Passed through as `weeklyAlignment` on every request.
+    pub fn with_weekly_alignment(mut self, x: WeeklyAlignment) -> Self {
+        self.weekly_alignment = Some(x);
+        self
+    }
+
+    /// The `(from, to)` windows the range is split into, in order. Fails
+    /// if no start was set.
+    ///
+    /// Both ends of a window are inclusive (`includeFirst` is set and `to`
+    /// is inclusive), so each window spans one candle less than the window
+    /// size to stay within the request limit.
+    pub fn windows(&self) -> Result<Vec<Window>, Box<dyn Error>> {
+        let mut result = Vec::new();
+        let from = self.from.ok_or_else(|| format!("no start set for the {} candle history", self.instrument))?;
+        let to = self.to.unwrap_or_else(Utc::now);
+        let step = granularity_duration(self.granularity) * (self.window_size - 1).max(1) as i32;
+        let mut start = from;
+        while start < to {
+            let end = std::cmp::min(start + step, to);
+            result.push((start, end));
+            start = end;
+        }
+        Ok(result)
+    }
+
+    fn request(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> GetInstrumentCandlesRequest {
+        let mut request = GetInstrumentCandlesRequest::new()
+            .with_instrument(self.instrument.clone())
+            .with_granularity(self.granularity)
+            .with_from(from)
+            .with_to(to)
+            .with_include_first(true);
+        if let Some(ref x) = self.price {
+            request = request.with_price(x.clone());
+        }
+        if let Some(x) = self.daily_alignment {
+            request = request.with_daily_alignment(x);
+        }
+        if let Some(ref x) = self.alignment_timezone {
+            request = request.with_alignment_timezone(x.clone());
+        }
+        if let Some(x) = self.weekly_alignment {
+            request = request.with_weekly_alignment(x.to_string());
+        }
+        request
+    }
+
+    async fn fetch_window<C>(
+        &self,
+        client: &Client<C>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<Vec<Candlestick>, Box<dyn Error>> {
+        let response = self.request(from, to).remote(client).await?;
+        match response.candles {
+            Some(candles) => Ok(candles),
+            None => Err(format!("no candles returned for {} to {}", from, to).into()),
+        }
+    }
+
+    /// Stream the candles of the whole range in time order.
+    pub fn stream<'a, C>(
+        &'a self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<Candlestick, Box<dyn Error>>> + 'a {
+        let mut last: Option<DateTime<Utc>> = None;
+        let (windows, missing) = match self.windows() {
+            Ok(x) => (x, None),
+            Err(e) => (Vec::new(), Some(Err(e))),
+        };
+        let candles = stream::iter(windows)
+            .map(move |(from, to)| self.fetch_window(client, from, to))
+            .buffered(self.concurrency)
+            .map(move |window| {
+                let items: Vec<Result<Candlestick, Box<dyn Error>>> = match window {
+                    Err(e) => vec![Err(e)],
+                    Ok(candles) => fresh_candles(candles, &mut last).into_iter().map(Ok).collect(),
+                };
+                stream::iter(items)
+            })
+            .flatten();
+        stream::iter(missing).chain(candles)
+    }
+
+    /// Fetch the candles of the whole range in time order.
+    pub async fn fetch<C>(&self, client: &Client<C>) -> Result<Vec<Candlestick>, Box<dyn Error>> {
+        self.stream(client).try_collect().await
+    }
+}
+
+// Drop the candles at or before the last time already returned, which is
+// the candle shared by two adjacent windows.
+fn fresh_candles(candles: Vec<Candlestick>, last: &mut Option<DateTime<Utc>>) -> Vec<Candlestick> {
+    candles
+        .into_iter()
+        .filter(|candle| {
+            match (candle.time, *last) {
+                (Some(time), Some(previous)) if time <= previous => false,
+                (Some(time), _) => {
+                    *last = Some(time);
+                    true
+                }
+                (None, _) => true,
+            }
+        })
+        .collect()
+}
+
+/// Downloads every price tick of an instrument over a time range.
+///
+/// `GetInstrumentPriceRangeRequest` truncates long windows without saying
+/// so, so the range is walked one window at a time, sequentially and with a
+/// pause between requests. A window whose response holds a full page of
+/// ticks is re-requested from its last tick; a shorter response completes
+/// it. Ticks on a boundary are only returned once.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use chrono::prelude::*;
+/// # use futures::TryStreamExt;
+/// # async fn backtest(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let ticks: Vec<Price> = TickHistory::new(
+///     "EUR_USD".to_string(),
+///     Utc.with_ymd_and_hms(2020, 1, 6, 8, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2020, 1, 6, 12, 0, 0).unwrap()
+/// )
+///     .stream(client)
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TickHistory {
+    instrument: String,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    window: Duration,
+    request_interval: StdDuration,
+    page_size: usize,
+}
+
+struct TickState {
+    cursor: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    // The ticks already returned at the latest time seen.
+    boundary: Vec<Price>,
+    first: bool,
+}
+
+impl TickHistory {
+    pub fn new(instrument: String, from: DateTime<Utc>, to: DateTime<Utc>) -> TickHistory {
+        TickHistory {
+            instrument,
+            from,
+            to,
+            window: Duration::hours(1),
+            request_interval: DEFAULT_REQUEST_INTERVAL,
+            page_size: MAX_TICKS_PER_REQUEST,
+        }
+    }
+
+    /// The length of the windows the range is split into. Defaults to one
+    /// hour.
+    pub fn with_window(mut self, x: Duration) -> Self {
+        if x > Duration::zero() {
+            self.window = x;
+        }
+        self
+    }
+
+    /// The pause between requests.
+    pub fn with_request_interval(mut self, x: StdDuration) -> Self {
+        self.request_interval = x;
+        self
+    }
+
+    /// The number of ticks at which a response is taken to have been cut
+    /// short and the window is continued from its last tick. Defaults to
+    /// `MAX_TICKS_PER_REQUEST`.
+    pub fn with_page_size(mut self, x: usize) -> Self {
+        self.page_size = x.max(1);
+        self
+    }
+
+    fn start(&self) -> TickState {
+        TickState {
+            cursor: self.from,
+            window_end: std::cmp::min(self.from + self.window, self.to),
+            boundary: Vec::new(),
+            first: true,
+        }
+    }
+
+    // Take one response for the window at the cursor: return the ticks not
+    // yet seen and move the cursor on.
+    fn advance(&self, state: &mut TickState, prices: Vec<Price>) -> Result<Vec<Price>, Box<dyn Error>> {
+        let full = prices.len() >= self.page_size;
+        let mut fresh = Vec::new();
+        for price in prices {
+            let time = match price.timestamp {
+                Some(x) => x,
+                None => return Err(format!("price tick after {} has no time", state.cursor).into()),
+            };
+            let latest = state.boundary.first().and_then(|p| p.timestamp);
+            if latest.is_some_and(|t| time < t) || state.boundary.contains(&price) {
+                continue;
+            }
+            if latest != Some(time) {
+                state.boundary.clear();
+            }
+            state.boundary.push(price.clone());
+            fresh.push(price);
+        }
+        match state.boundary.first().and_then(|p| p.timestamp) {
+            // A full page may have been cut short: carry on from the last
+            // tick.
+            Some(latest) if full && !fresh.is_empty() && latest > state.cursor => {
+                state.cursor = latest;
+            }
+            _ => {
+                state.cursor = state.window_end;
+                state.window_end = std::cmp::min(state.cursor + self.window, self.to);
+            }
+        }
+        Ok(fresh)
+    }
+
+    async fn fetch_window<C>(
+        &self,
+        client: &Client<C>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<Vec<Price>, Box<dyn Error>> {
+        let response = GetInstrumentPriceRangeRequest::new()
+            .with_instrument(self.instrument.clone())
+            .with_from(from)
+            .with_to(to)
+            .remote(client).await?;
+        match response.prices {
+            Some(prices) => Ok(prices),
+            None => Err(format!("no prices returned for {} to {}", from, to).into()),
+        }
+    }
+
+    /// Stream the ticks of the whole range in time order.
+    pub fn stream<'a, C>(
+        &'a self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<Price, Box<dyn Error>>> + 'a {
+        stream::unfold(Some(self.start()), move |state| async move {
+            let mut state = state?;
+            if state.cursor >= self.to {
+                return None;
+            }
+            if !state.first {
+                tokio::time::sleep(self.request_interval).await;
+            }
+            state.first = false;
+            let prices = match self.fetch_window(client, state.cursor, state.window_end).await {
+                Ok(x) => x,
+                Err(e) => {
+                    return Some((vec![Err(e)], None));
+                }
+            };
+            match self.advance(&mut state, prices) {
+                Ok(fresh) => Some((fresh.into_iter().map(Ok).collect(), Some(state))),
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        })
+            .map(stream::iter)
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_hold_at_most_window_size_candles() {
+        let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let history = CandleHistory::new("EUR_USD".to_string(), CandlestickGranularity::M1)
+            .with_from(from)
+            .with_to(from + Duration::minutes(12000));
+        let windows = history.windows().unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0], (from, from + Duration::minutes(4999)));
+        assert_eq!(windows[2], (from + Duration::minutes(9998), from + Duration::minutes(12000)));
+
+        let unbounded = CandleHistory::new("EUR_USD".to_string(), CandlestickGranularity::M1);
+        assert!(unbounded.windows().is_err());
+    }
+
+    #[test]
+    fn candles_on_a_window_boundary_are_returned_once() {
+        let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let candle = |minutes| Candlestick::new().with_time(from + Duration::minutes(minutes));
+        let mut last = None;
+        let first = fresh_candles(vec![candle(0), candle(1), candle(2)], &mut last);
+        let second = fresh_candles(vec![candle(2), candle(3)], &mut last);
+        assert_eq!(first.len(), 3);
+        assert_eq!(second, vec![candle(3)]);
+    }
+
+    fn tick(from: DateTime<Utc>, seconds: i64, bid: f32) -> Price {
+        Price::new().with_timestamp(from + Duration::seconds(seconds)).with_closeout_bid(bid)
+    }
+
+    #[test]
+    fn ticks_on_a_boundary_are_returned_once() {
+        let from = Utc.with_ymd_and_hms(2020, 1, 6, 8, 0, 0).unwrap();
+        let history = TickHistory::new("EUR_USD".to_string(), from, from + Duration::hours(2)).with_page_size(3);
+        let mut state = history.start();
+        let first = history.advance(&mut state, vec![tick(from, 1, 1.1), tick(from, 2, 1.2), tick(from, 2, 1.3)]);
+        assert_eq!(first.unwrap().len(), 3);
+        // The next page starts at the last tick, so it repeats both ticks
+        // at that time; a new tick at that same time is still kept.
+        let second = history.advance(
+            &mut state,
+            vec![tick(from, 2, 1.2), tick(from, 2, 1.3), tick(from, 2, 1.4), tick(from, 3, 1.5)]
+        );
+        assert_eq!(second.unwrap(), vec![tick(from, 2, 1.4), tick(from, 3, 1.5)]);
+    }
+
+    #[test]
+    fn full_pages_continue_the_window_and_short_ones_end_it() {
+        let from = Utc.with_ymd_and_hms(2020, 1, 6, 8, 0, 0).unwrap();
+        let history = TickHistory::new("EUR_USD".to_string(), from, from + Duration::minutes(90)).with_page_size(2);
+        let mut state = history.start();
+        history.advance(&mut state, vec![tick(from, 1, 1.1), tick(from, 5, 1.2)]).unwrap();
+        assert_eq!((state.cursor, state.window_end), (from + Duration::seconds(5), from + Duration::hours(1)));
+        history.advance(&mut state, vec![tick(from, 9, 1.3)]).unwrap();
+        assert_eq!((state.cursor, state.window_end), (from + Duration::hours(1), from + Duration::minutes(90)));
+        history.advance(&mut state, Vec::new()).unwrap();
+        assert_eq!(state.cursor, from + Duration::minutes(90));
+    }
+}
@@ -262,24 +262,19 @@ pub mod get_instrument_candles {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetInstrumentCandlesResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetInstrumentCandlesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetInstrumentCandlesResponse>(response).await,
             }
         }
     }
@@ -287,7 +282,7 @@ pub mod get_instrument_candles {
     pub type GetInstrumentCandlesResponse = GetInstrumentCandlesResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentCandlesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -295,7 +290,7 @@ pub mod get_instrument_candles {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentCandlesResponse200Body {
         /// The instrument whose Prices are represented by the candlesticks.
         /// format: A string containing the base currency and quote currency delimited by
@@ -441,24 +436,19 @@ pub mod get_instrument_price {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetInstrumentPriceResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetInstrumentPriceResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetInstrumentPriceResponse>(response).await,
             }
         }
     }
@@ -466,7 +456,7 @@ pub mod get_instrument_price {
     pub type GetInstrumentPriceResponse = GetInstrumentPriceResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentPriceResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -474,7 +464,7 @@ pub mod get_instrument_price {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentPriceResponse200Body {
         /// The Price representation
         #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
@@ -636,24 +626,19 @@ pub mod get_instrument_price_range {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetInstrumentPriceRangeResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetInstrumentPriceRangeResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetInstrumentPriceRangeResponse>(response).await,
             }
         }
     }
@@ -661,7 +646,7 @@ pub mod get_instrument_price_range {
     pub type GetInstrumentPriceRangeResponse = GetInstrumentPriceRangeResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentPriceRangeResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -672,7 +657,7 @@ pub mod get_instrument_price_range {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetInstrumentPriceRangeResponse200Body {
         /// The list of prices that satisfy the request.
         #[serde(rename = "prices", skip_serializing_if = "Option::is_none")]
@@ -686,7 +671,7 @@ pub mod get_order_book {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{header_value, Client};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -809,29 +794,45 @@ pub mod get_order_book {
             self
         }
 
-        pub async fn remote(self, client: &Client) -> Result<GetOrderBookResponse, Box<dyn Error>> {
+        pub async fn remote<C>(self, client: &Client<C>) -> Result<GetOrderBookResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetOrderBookResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetOrderBookResponse>(response).await,
             }
         }
+
+        /// Like `remote`, but also returns the response headers. The `Link`
+        /// header points at the previous and next snapshots.
+        pub async fn remote_with_header<C>(
+            self,
+            client: &Client<C>
+        ) -> Result<(GetOrderBookResponse, GetOrderBookResponse200Header), Box<dyn Error>> {
+            let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let response = client
+                .request(reqwest::Method::GET, &url)
+                .query(&self.query)
+                .send().await?;
+            let header = GetOrderBookResponse200Header {
+                content_encoding: header_value(&response, "Content-Encoding"),
+                link: header_value(&response, "Link"),
+                request_id: header_value(&response, "RequestID"),
+            };
+            let body = client.decode::<GetOrderBookResponse>(response).await?;
+            Ok((body, header))
+        }
     }
 
     pub type GetOrderBookResponse = GetOrderBookResponse200Body;
 
     /// The order book has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetOrderBookResponse200Header {
         /// Value will be "gzip" regardless of provided Accept-Encoding header
         #[serde(rename = "ContentEncoding", skip_serializing_if = "Option::is_none")]
@@ -845,7 +846,7 @@ pub mod get_order_book {
     }
 
     /// The order book has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetOrderBookResponse200Body {
         /// The representation of an instrument's order book at a point in time
         #[serde(rename = "orderBook", skip_serializing_if = "Option::is_none")]
@@ -859,7 +860,7 @@ pub mod get_position_book {
     #[allow(unused_imports)]
     use fxoanda_definitions::*;
     use std::error::Error;
-    use crate::Client;
+    use crate::{header_value, Client};
 
     #[derive(Debug, Serialize, Deserialize)]
     struct RequestHead {
@@ -982,32 +983,48 @@ pub mod get_position_book {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetPositionBookResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetPositionBookResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetPositionBookResponse>(response).await,
             }
         }
+
+        /// Like `remote`, but also returns the response headers. The `Link`
+        /// header points at the previous and next snapshots.
+        pub async fn remote_with_header<C>(
+            self,
+            client: &Client<C>
+        ) -> Result<(GetPositionBookResponse, GetPositionBookResponse200Header), Box<dyn Error>> {
+            let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let response = client
+                .request(reqwest::Method::GET, &url)
+                .query(&self.query)
+                .send().await?;
+            let header = GetPositionBookResponse200Header {
+                content_encoding: header_value(&response, "Content-Encoding"),
+                link: header_value(&response, "Link"),
+                request_id: header_value(&response, "RequestID"),
+            };
+            let body = client.decode::<GetPositionBookResponse>(response).await?;
+            Ok((body, header))
+        }
     }
 
     pub type GetPositionBookResponse = GetPositionBookResponse200Body;
 
     /// The position book has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPositionBookResponse200Header {
         /// Value will be "gzip" regardless of provided Accept-Encoding header
         #[serde(rename = "ContentEncoding", skip_serializing_if = "Option::is_none")]
@@ -1021,7 +1038,7 @@ pub mod get_position_book {
     }
 
     /// The position book has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPositionBookResponse200Body {
         /// The representation of an instrument's position book at a point in time
         #[serde(rename = "positionBook", skip_serializing_if = "Option::is_none")]
@@ -22,11 +22,7 @@
 //!     let api_key = env::var("OANDA_KEY").expect("expected OANDA_KEY environment variable to be set");
 //!     let api_host = env::var("OANDA_HOST").expect("expected OANDA_HOST environment variable to be set");
 //!
-//!     let client = fxoanda::Client {
-//!         host: String::from(api_host),
-//!         reqwest: reqwest::Client::new(),
-//!         authentication: String::from(api_key),
-//!     };
+//!     let client = fxoanda::Client::new(api_host, api_key);
 //!     match fxoanda::GetInstrumentCandlesRequest::new()
 //!         .with_instrument("EUR_USD".to_string())
 //!         .with_granularity(CandlestickGranularity::H4)
@@ -65,12 +61,58 @@ extern crate serde_json;
 extern crate time;
 
 pub mod account;
+pub mod account_handle;
+pub mod book_snapshot;
+pub mod books;
+pub mod bracket;
+pub mod candles;
 pub mod client;
+pub mod conditional;
+pub mod close_units;
+#[cfg(any(feature = "export-csv", feature = "export-arrow"))]
+pub mod export;
+pub mod flatten;
+pub mod history;
 pub mod instrument;
+pub mod mirror;
+pub mod order_manager;
+pub mod pages;
+pub mod price_stream;
 pub mod pricing;
+pub mod resample;
+pub mod risk;
+pub mod stop_manager;
+#[cfg(feature = "store")]
+pub mod store;
+pub mod transaction_stream;
+pub mod user;
+pub mod validation;
 pub use self::account::*;
+pub use self::account_handle::*;
+pub use self::book_snapshot::*;
+pub use self::books::*;
+pub use self::bracket::*;
+pub use self::candles::*;
 pub use self::client::*;
+pub use self::conditional::*;
+pub use self::close_units::*;
+#[cfg(any(feature = "export-csv", feature = "export-arrow"))]
+pub use self::export::*;
+pub use self::flatten::*;
+pub use self::history::*;
 pub use self::instrument::*;
+pub use self::mirror::*;
+pub use self::order_manager::*;
+pub use self::pages::*;
+pub use self::price_stream::*;
 pub use self::pricing::*;
+pub use self::resample::*;
+pub use self::risk::*;
+pub use self::stop_manager::*;
+#[cfg(feature = "store")]
+pub use self::store::*;
+pub use self::transaction_stream::*;
+pub use self::user::*;
+pub use self::validation::*;
 pub use fxoanda_definitions::*;
 pub use fxoanda_serdes::*;
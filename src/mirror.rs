@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::sync::Arc;
+
+use crate::*;
+
+/// A consistent copy of an account's state at one transaction.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountSnapshot {
+    pub account: Account,
+    /// The price-dependent state of trailing stop loss orders, by order ID.
+    pub order_states: BTreeMap<String, DynamicOrderState>,
+    /// The last transaction reflected in `account`.
+    pub last_transaction_id: String,
+}
+
+/// A local copy of an account kept up to date by polling
+/// `GetAccountChangesRequest`, as OANDA recommends, instead of fetching the
+/// whole account every time.
+///
+/// The mirror starts from `GetAccountRequest`. Each `poll` applies the order,
+/// trade and position changes since the last seen transaction, then the
+/// price-dependent state (NAV, unrealized P/L, margin, trade and order
+/// state). Readers take a `snapshot`, which never changes underneath them.
+///
+/// `AccountChanges` does not carry the balance, so it is taken from the
+/// `accountBalance` of the newest transaction that has one. Positions whose
+/// long and short sides are both closed are dropped, as `GetAccountRequest`
+/// leaves them out too.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn dashboard(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut mirror = AccountMirror::new(client, "101-004-1234567-001").await?;
+/// loop {
+///     mirror.poll(client).await?;
+///     let snapshot = mirror.snapshot();
+///     println!("NAV {:?}", snapshot.account.nav);
+///     tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccountMirror {
+    account_id: AccountId,
+    snapshot: Arc<AccountSnapshot>,
+}
+
+impl AccountMirror {
+    /// Fetch the account and start mirroring it.
+    pub async fn new<C, A: Into<AccountId>>(
+        client: &Client<C>,
+        account_id: A
+    ) -> Result<AccountMirror, Box<dyn Error>> {
+        let account_id = account_id.into();
+        let snapshot = fetch_snapshot(client, &account_id).await?;
+        Ok(AccountMirror {
+            account_id,
+            snapshot: Arc::new(snapshot),
+        })
+    }
+
+    /// Start mirroring from an account fetched elsewhere.
+    pub fn from_account(account: Account, last_transaction_id: String) -> AccountMirror {
+        AccountMirror {
+            account_id: AccountId::new(account.id.clone().unwrap_or_default()),
+            snapshot: Arc::new(AccountSnapshot {
+                account,
+                order_states: BTreeMap::new(),
+                last_transaction_id,
+            }),
+        }
+    }
+
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    pub fn last_transaction_id(&self) -> &str {
+        &self.snapshot.last_transaction_id
+    }
+
+    /// The current state. Later polls do not modify it.
+    pub fn snapshot(&self) -> Arc<AccountSnapshot> {
+        self.snapshot.clone()
+    }
+
+    /// Fetch and apply the changes since the last seen transaction. Returns
+    /// whether there were new transactions.
+    pub async fn poll<C>(&mut self, client: &Client<C>) -> Result<bool, Box<dyn Error>> {
+        let response = GetAccountChangesRequest::new()
+            .with_account_id(self.account_id.to_string())
+            .with_since_transaction_id(self.snapshot.last_transaction_id.clone())
+            .remote(client).await?;
+        let before = self.snapshot.last_transaction_id.clone();
+        self.apply(response)?;
+        Ok(self.snapshot.last_transaction_id != before)
+    }
+
+    /// Replace the mirrored state with a freshly fetched account.
+    pub async fn resync<C>(&mut self, client: &Client<C>) -> Result<(), Box<dyn Error>> {
+        self.snapshot = Arc::new(fetch_snapshot(client, &self.account_id).await?);
+        Ok(())
+    }
+
+    /// Apply one `GetAccountChangesRequest` response.
+    pub fn apply(&mut self, response: GetAccountChangesResponse) -> Result<(), Box<dyn Error>> {
+        let last_transaction_id = match response.last_transaction_id {
+            Some(x) => x,
+            None => {
+                return Err("account changes have no lastTransactionID".into());
+            }
+        };
+        let snapshot = Arc::make_mut(&mut self.snapshot);
+        if let Some(ref changes) = response.changes {
+            apply_changes(&mut snapshot.account, changes);
+        }
+        if let Some(ref state) = response.state {
+            apply_state(snapshot, state);
+        }
+        let account = &mut snapshot.account;
+        let orders = account.orders.get_or_insert_with(Vec::new);
+        snapshot.order_states.retain(|id, _| orders.iter().any(|o| o.id.as_deref() == Some(id)));
+        account.pending_order_count = Some(orders.len() as i32);
+        account.open_trade_count = Some(account.trades.as_ref().map_or(0, |x| x.len()) as i32);
+        account.open_position_count = Some(account.positions.as_ref().map_or(0, |x| x.len()) as i32);
+        account.last_transaction_id = Some(last_transaction_id.clone());
+        snapshot.last_transaction_id = last_transaction_id;
+        Ok(())
+    }
+}
+
+async fn fetch_snapshot<C>(
+    client: &Client<C>,
+    account_id: &AccountId
+) -> Result<AccountSnapshot, Box<dyn Error>> {
+    let response = GetAccountRequest::new().with_account_id(account_id.to_string()).remote(client).await?;
+    match (response.account, response.last_transaction_id) {
+        (Some(account), Some(last_transaction_id)) => Ok(AccountSnapshot {
+            account,
+            order_states: BTreeMap::new(),
+            last_transaction_id,
+        }),
+        _ => Err(format!("no account returned for {}", account_id).into()),
+    }
+}
+
+fn side_units(side: &Option<PositionSide>) -> f32 {
+    side.as_ref().and_then(|s| s.units).unwrap_or(0.0)
+}
+
+// Insert or replace `updated`, then remove the items whose ID is in `gone`.
+// One response can both create and fill an order, or open and close a
+// trade, so removals go last.
+fn merge<T: Clone>(items: &mut Vec<T>, gone: &[&T], updated: &[&T], key: fn(&T) -> Option<&str>) {
+    for new in updated {
+        match items.iter_mut().find(|x| key(x).is_some() && key(x) == key(new)) {
+            Some(x) => {
+                *x = (*new).clone();
+            }
+            None => items.push((*new).clone()),
+        }
+    }
+    items.retain(|x| !gone.iter().any(|g| key(g).is_some() && key(g) == key(x)));
+}
+
+fn apply_changes(account: &mut Account, changes: &AccountChanges) {
+    let gone: Vec<&Order> = [&changes.orders_filled, &changes.orders_cancelled, &changes.orders_triggered]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect();
+    let created: Vec<&Order> = changes.orders_created.iter().flatten().collect();
+    merge(account.orders.get_or_insert_with(Vec::new), &gone, &created, |o| o.id.as_deref());
+
+    let closed: Vec<&TradeSummary> = changes.trades_closed.iter().flatten().collect();
+    let updated: Vec<&TradeSummary> = changes.trades_opened
+        .iter()
+        .flatten()
+        .chain(changes.trades_reduced.iter().flatten())
+        .collect();
+    merge(account.trades.get_or_insert_with(Vec::new), &closed, &updated, |t| t.id.as_deref());
+
+    let positions: Vec<&Position> = changes.positions.iter().flatten().collect();
+    let open_positions = account.positions.get_or_insert_with(Vec::new);
+    merge(open_positions, &[], &positions, |p| p.instrument.as_deref());
+    open_positions.retain(|p| side_units(&p.long) != 0.0 || side_units(&p.short) != 0.0);
+
+    let balance = changes.transactions.iter().flatten().rev().find_map(|t| t.account_balance.map(|x| x as f32));
+    if balance.is_some() {
+        account.balance = balance;
+    }
+}
+
+fn apply_state(snapshot: &mut AccountSnapshot, state: &AccountChangesState) {
+    let account = &mut snapshot.account;
+    macro_rules! copy {
+        ($($field:ident),*) => {
+            $(
+                if state.$field.is_some() {
+                    account.$field = state.$field;
+                }
+            )*
+        };
+    }
+    copy!(
+        unrealized_pl,
+        nav,
+        margin_used,
+        margin_available,
+        position_value,
+        margin_closeout_unrealized_pl,
+        margin_closeout_nav,
+        margin_closeout_margin_used,
+        margin_closeout_percent,
+        margin_closeout_position_value,
+        withdrawal_limit,
+        margin_call_margin_used,
+        margin_call_percent
+    );
+
+    for calculated in state.trades.iter().flatten() {
+        let trade = account.trades
+            .iter_mut()
+            .flatten()
+            .find(|t| t.id.is_some() && t.id == calculated.id);
+        if let Some(trade) = trade {
+            trade.unrealized_pl = calculated.unrealized_pl.or(trade.unrealized_pl);
+            trade.margin_used = calculated.margin_used.or(trade.margin_used);
+        }
+    }
+
+    for calculated in state.positions.iter().flatten() {
+        let position = account.positions
+            .iter_mut()
+            .flatten()
+            .find(|p| p.instrument.is_some() && p.instrument == calculated.instrument);
+        if let Some(position) = position {
+            position.unrealized_pl = calculated.net_unrealized_pl.or(position.unrealized_pl);
+            position.margin_used = calculated.margin_used.or(position.margin_used);
+            if let Some(ref mut long) = position.long {
+                long.unrealized_pl = calculated.long_unrealized_pl.or(long.unrealized_pl);
+            }
+            if let Some(ref mut short) = position.short {
+                short.unrealized_pl = calculated.short_unrealized_pl.or(short.unrealized_pl);
+            }
+        }
+    }
+
+    for order in state.orders.iter().flatten() {
+        if let Some(ref id) = order.id {
+            snapshot.order_states.insert(id.clone(), order.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(id: &str, units: f32) -> TradeSummary {
+        TradeSummary::new().with_id(id.to_string()).with_current_units(units)
+    }
+
+    fn position(instrument: &str, units: f32) -> Position {
+        let side = |x: f32| PositionSide::new().with_units(x);
+        Position::new()
+            .with_instrument(instrument.to_string())
+            .with_long(side(units.max(0.0)))
+            .with_short(side(units.min(0.0)))
+    }
+
+    #[test]
+    fn applies_changes_and_state() {
+        let account = Account::new()
+            .with_id("101-004-1234567-001".to_string())
+            .with_orders(vec![Order::new().with_id("10".to_string())])
+            .with_trades(vec![trade("5", 100.0), trade("7", 50.0)])
+            .with_positions(vec![position("EUR_USD", 150.0), position("USD_JPY", -10.0)]);
+        let mut mirror = AccountMirror::from_account(account, "10".to_string());
+        let before = mirror.snapshot();
+
+        let changes = AccountChanges::new()
+            .with_orders_filled(vec![Order::new().with_id("10".to_string())])
+            .with_trades_opened(vec![trade("11", 20.0)])
+            .with_trades_reduced(vec![trade("5", 60.0)])
+            .with_trades_closed(vec![trade("7", 0.0)])
+            .with_positions(vec![position("USD_JPY", 0.0)])
+            .with_transactions(vec![
+                Transaction::new().with_id("10".to_string()).with_account_balance(1000.0),
+                Transaction::new().with_id("11".to_string()).with_account_balance(999.5),
+            ]);
+        let state = AccountChangesState::new()
+            .with_nav(1010.5)
+            .with_trades(vec![CalculatedTradeState::new().with_id("11".to_string()).with_unrealized_pl(1.5)]);
+        let response = GetAccountChangesResponse {
+            changes: Some(changes),
+            state: Some(state),
+            last_transaction_id: Some("11".to_string()),
+        };
+        mirror.apply(response).unwrap();
+
+        let after = mirror.snapshot();
+        let trades = after.account.trades.as_ref().unwrap();
+        assert_eq!(
+            trades.iter().map(|t| (t.id.as_deref().unwrap(), t.current_units.unwrap())).collect::<Vec<_>>(),
+            vec![("5", 60.0), ("11", 20.0)]
+        );
+        assert_eq!(trades[1].unrealized_pl, Some(1.5));
+        assert_eq!(after.account.pending_order_count, Some(0));
+        assert_eq!(after.account.nav, Some(1010.5));
+        assert_eq!(after.account.balance, Some(999.5));
+        assert_eq!(after.account.open_position_count, Some(1));
+        assert_eq!(after.account.positions.as_ref().unwrap()[0].instrument.as_deref(), Some("EUR_USD"));
+        assert_eq!(mirror.last_transaction_id(), "11");
+        // Earlier snapshots are left alone.
+        assert_eq!(before.account.trades.as_ref().unwrap().len(), 2);
+    }
+
+    // Apply changes to an empty account and return the result.
+    fn apply_to_empty(changes: AccountChanges) -> Arc<AccountSnapshot> {
+        let account = Account::new().with_id("101-004-1234567-001".to_string());
+        let mut mirror = AccountMirror::from_account(account, "10".to_string());
+        let response = GetAccountChangesResponse {
+            changes: Some(changes),
+            state: None,
+            last_transaction_id: Some("13".to_string()),
+        };
+        mirror.apply(response).unwrap();
+        mirror.snapshot()
+    }
+
+    #[test]
+    fn drops_orders_created_and_filled_together() {
+        let market = Order::new().with_id("11".to_string());
+        let after = apply_to_empty(
+            AccountChanges::new().with_orders_created(vec![market.clone()]).with_orders_filled(vec![market])
+        );
+        assert_eq!(after.account.orders.as_deref(), Some(&[][..]));
+        assert_eq!(after.account.pending_order_count, Some(0));
+    }
+
+    #[test]
+    fn drops_trades_opened_and_closed_together() {
+        let after = apply_to_empty(
+            AccountChanges::new()
+                .with_trades_opened(vec![trade("12", 100.0)])
+                .with_trades_closed(vec![trade("12", 0.0)])
+        );
+        assert_eq!(after.account.trades.as_deref(), Some(&[][..]));
+        assert_eq!(after.account.open_trade_count, Some(0));
+    }
+}
@@ -0,0 +1,377 @@
+use std::error::Error;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::*;
+
+/// How a submitted order ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderOutcome {
+    /// The order was filled. `trade_id` is the trade it opened, or else the
+    /// first trade it reduced or closed.
+    Filled { trade_id: Option<String>, price: f32 },
+    /// The order was created and later cancelled, e.g. a FOK market order
+    /// that could not be filled or a limit order past its GTD time.
+    Cancelled { reason: String },
+    /// The order was never created.
+    Rejected { reason: String },
+}
+
+/// What the create request of a submitted order returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submitted {
+    /// The OANDA order ID, if the order was created.
+    pub order_id: Option<String>,
+    /// The outcome, if the order was filled, cancelled or rejected straight
+    /// away.
+    pub outcome: Option<OrderOutcome>,
+}
+
+/// An order the `OrderManager` can submit and tag with a client ID.
+pub trait ManagedOrder {
+    fn client_extensions_mut(&mut self) -> &mut Option<ClientExtensions>;
+    /// Send the order with its generated create request, which applies the
+    /// client's live trading interlock and `RiskGuard`.
+    fn create(
+        self,
+        client: &Client<Trading>,
+        account_id: &AccountId
+    ) -> impl Future<Output = Result<Submitted, Box<dyn Error>>>;
+}
+
+macro_rules! managed_order {
+    ($($order:ident => $request:ident),*) => {
+      $(
+        impl ManagedOrder for $order {
+          fn client_extensions_mut(&mut self) -> &mut Option<ClientExtensions> {
+            &mut self.client_extensions
+          }
+
+          async fn create(
+            self,
+            client: &Client<Trading>,
+            account_id: &AccountId
+          ) -> Result<Submitted, Box<dyn Error>> {
+            let response = match $request::new()
+              .with_account_id(account_id.to_string())
+              .with_order(self)
+              .remote(client).await
+            {
+              Ok(x) => x,
+              Err(e) => {
+                return Ok(Submitted { order_id: None, outcome: Some(rejected(e)?) });
+              }
+            };
+            let outcome = if let Some(fill) = response.order_fill_transaction {
+              let price = fill.price.or(fill.full_vwap);
+              Some(filled(&fill.trade_opened, &fill.trade_reduced, &fill.trades_closed, price)?)
+            } else if let Some(cancel) = response.order_cancel_transaction {
+              Some(cancelled(cancel.reason))
+            } else {
+              None
+            };
+            Ok(Submitted {
+              order_id: response.order_create_transaction.and_then(|t| t.id),
+              outcome,
+            })
+          }
+        }
+      )*
+    };
+}
+
+managed_order!(MarketOrder => CreateMarketOrderRequest,
+               LimitOrder => CreateLimitOrderRequest,
+               StopOrder => CreateStopOrderRequest);
+
+/// Submits orders and follows them until they are filled, cancelled or
+/// rejected.
+///
+/// Every order is tagged with a generated client ID (`<prefix>-<n>`) so it
+/// can be found again even when the submit response is lost. Orders are sent
+/// with the generated create requests, so the client's live trading
+/// interlock, `RiskGuard` and strict mode apply. The outcome of orders that
+/// fill or fail straight away is read from the submit response; pending
+/// orders are followed on the `TransactionStream` and, if the stream cannot
+/// be opened or ends, polled with `GetOrderRequest`.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn trade(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let manager = OrderManager::new("101-004-1234567-001");
+/// let order = LimitOrder::new()
+///     .with_otype("LIMIT".to_string())
+///     .with_instrument("EUR_USD".to_string())
+///     .with_units(1000.0)
+///     .with_price(1.08);
+/// let mut handle = manager.submit(client, order).await?;
+/// match handle.wait(client).await? {
+///     OrderOutcome::Filled { trade_id, price } => println!("{:?} filled at {}", trade_id, price),
+///     other => println!("{:?}", other),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OrderManager {
+    account_id: AccountId,
+    prefix: String,
+    next: AtomicU64,
+    poll_interval: Duration,
+}
+
+impl OrderManager {
+    pub fn new<A: Into<AccountId>>(account_id: A) -> OrderManager {
+        let started = chrono::Utc::now().timestamp_millis() as u64;
+        OrderManager {
+            account_id: account_id.into(),
+            prefix: format!("fxo{}", started),
+            next: AtomicU64::new(1),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// The start of every generated client ID. Defaults to `fxo` followed by
+    /// the creation time in milliseconds, which keeps IDs unique across
+    /// restarts.
+    pub fn with_prefix(mut self, x: String) -> Self {
+        self.prefix = x;
+        self
+    }
+
+    /// How often handles poll pending orders when the transaction stream is
+    /// not available. Defaults to one second.
+    pub fn with_poll_interval(mut self, x: Duration) -> Self {
+        self.poll_interval = x;
+        self
+    }
+
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    /// A new, unused client ID.
+    pub fn next_client_id(&self) -> String {
+        format!("{}-{}", self.prefix, self.next.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Tag `order` with a new client ID and submit it. Any client extensions
+    /// already on the order are kept, except for the ID. Orders the server
+    /// refuses resolve to `Rejected`; orders refused locally, by the
+    /// `RiskGuard` or the live trading interlock, are returned as errors.
+    pub async fn submit<O: ManagedOrder>(
+        &self,
+        client: &Client<Trading>,
+        mut order: O
+    ) -> Result<OrderHandle, Box<dyn Error>> {
+        let client_id = self.next_client_id();
+        order.client_extensions_mut().get_or_insert_with(ClientExtensions::new).id = Some(client_id.clone());
+        let submitted = order.create(client, &self.account_id).await?;
+        let outcome = match submitted.outcome {
+            None if submitted.order_id.is_none() => {
+                Some(OrderOutcome::Rejected { reason: "order was not created".to_string() })
+            }
+            x => x,
+        };
+        Ok(OrderHandle {
+            account_id: self.account_id.clone(),
+            client_id,
+            order_id: submitted.order_id,
+            outcome,
+            poll_interval: self.poll_interval,
+        })
+    }
+}
+
+/// A submitted order.
+#[derive(Debug, Clone)]
+pub struct OrderHandle {
+    account_id: AccountId,
+    client_id: String,
+    order_id: Option<String>,
+    outcome: Option<OrderOutcome>,
+    poll_interval: Duration,
+}
+
+impl OrderHandle {
+    /// The generated client ID the order was tagged with.
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// The OANDA order ID, if the order was created.
+    pub fn order_id(&self) -> Option<&str> {
+        self.order_id.as_deref()
+    }
+
+    /// The outcome, once known.
+    pub fn outcome(&self) -> Option<&OrderOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Check the order once with `GetOrderRequest` and return the outcome if
+    /// it is known.
+    pub async fn poll<C>(&mut self, client: &Client<C>) -> Result<Option<OrderOutcome>, Box<dyn Error>> {
+        if self.outcome.is_some() {
+            return Ok(self.outcome.clone());
+        }
+        let order = GetOrderRequest::new()
+            .with_account_id(self.account_id.to_string())
+            .with_order_specifier(format!("@{}", self.client_id))
+            .remote(client).await?
+            .order
+            .ok_or_else(|| format!("order {} not found", self.client_id))?;
+        if self.order_id.is_none() {
+            self.order_id = order.id.clone();
+        }
+        let id = match order.state.as_deref() {
+            Some("FILLED") => order.filling_transaction_id.ok_or("filled order has no fillingTransactionID")?,
+            Some("CANCELLED") => {
+                order.cancelling_transaction_id.ok_or("cancelled order has no cancellingTransactionID")?
+            }
+            _ => return Ok(None),
+        };
+        let transaction = GetTransactionRequest::new()
+            .with_account_id(self.account_id.to_string())
+            .with_transaction_id(id.clone())
+            .remote(client).await?
+            .transaction
+            .ok_or_else(|| format!("transaction {} not found", id))?;
+        self.outcome = self.outcome_of(&transaction)?;
+        Ok(self.outcome.clone())
+    }
+
+    /// Wait until the order is filled or cancelled, following the account's
+    /// transaction stream, or polling every `poll_interval` without it.
+    pub async fn wait<C>(&mut self, client: &Client<C>) -> Result<OrderOutcome, Box<dyn Error>> {
+        if let Some(ref outcome) = self.outcome {
+            return Ok(outcome.clone());
+        }
+        // Connect before the first poll so that nothing in between is missed.
+        let stream = TransactionStream::connect(client, self.account_id.clone()).await;
+        if let Some(outcome) = self.poll(client).await? {
+            return Ok(outcome);
+        }
+        if let Ok(mut stream) = stream {
+            while let Ok(Some(transaction)) = stream.next().await {
+                if let Some(outcome) = self.outcome_of(&transaction)? {
+                    self.outcome = Some(outcome.clone());
+                    return Ok(outcome);
+                }
+            }
+        }
+        loop {
+            if let Some(outcome) = self.poll(client).await? {
+                return Ok(outcome);
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    // The outcome a transaction gives this order, if it is its fill or
+    // cancellation.
+    fn outcome_of(&self, transaction: &Transaction) -> Result<Option<OrderOutcome>, Box<dyn Error>> {
+        let ours = transaction.client_order_id.as_deref() == Some(self.client_id.as_str())
+            || (self.order_id.is_some() && transaction.order_id == self.order_id);
+        if !ours {
+            return Ok(None);
+        }
+        Ok(match transaction.otype.as_deref() {
+            Some("ORDER_FILL") => Some(filled(
+                &transaction.trade_opened,
+                &transaction.trade_reduced,
+                &transaction.trades_closed,
+                transaction.price
+            )?),
+            Some("ORDER_CANCEL") => Some(cancelled(transaction.reason.clone())),
+            _ => None,
+        })
+    }
+}
+
+// A request error that means the server refused the order.
+fn rejected(error: Box<dyn Error>) -> Result<OrderOutcome, Box<dyn Error>> {
+    match error.downcast_ref::<ApiError>() {
+        Some(x) if (400..500).contains(&x.status) => Ok(OrderOutcome::Rejected {
+            reason: x.code.clone().or_else(|| x.message.clone()).unwrap_or_else(|| x.to_string()),
+        }),
+        _ => Err(error),
+    }
+}
+
+// The trade a fill opened, or else the first trade it reduced or closed.
+fn filled(
+    opened: &Option<TradeOpen>,
+    reduced: &Option<TradeReduce>,
+    closed: &Option<Vec<TradeReduce>>,
+    price: Option<f32>
+) -> Result<OrderOutcome, Box<dyn Error>> {
+    let trade_id = opened
+        .as_ref()
+        .and_then(|t| t.trade_id.clone())
+        .or_else(|| reduced.as_ref().and_then(|t| t.trade_id.clone()))
+        .or_else(|| closed.iter().flatten().find_map(|t| t.trade_id.clone()));
+    let price = price
+        .or_else(|| opened.as_ref().and_then(|t| t.price))
+        .or_else(|| reduced.as_ref().and_then(|t| t.price))
+        .ok_or("fill transaction has no price")?;
+    Ok(OrderOutcome::Filled { trade_id, price })
+}
+
+fn cancelled(reason: Option<String>) -> OrderOutcome {
+    OrderOutcome::Cancelled {
+        reason: reason.unwrap_or_else(|| "UNKNOWN".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_outcomes() {
+        let error = ApiError {
+            environment: Environment::Practice,
+            status: 400,
+            message: Some("Insufficient margin".to_string()),
+            code: Some("INSUFFICIENT_MARGIN".to_string()),
+            body: String::new(),
+        };
+        assert_eq!(
+            rejected(Box::new(error)).unwrap(),
+            OrderOutcome::Rejected { reason: "INSUFFICIENT_MARGIN".to_string() }
+        );
+        assert!(rejected(Box::new(RiskError::KillSwitch)).is_err());
+
+        let manager = OrderManager::new("101-004-1234567-001").with_prefix("bot".to_string());
+        assert_eq!(manager.next_client_id(), "bot-1");
+        assert_eq!(manager.next_client_id(), "bot-2");
+
+        let handle = OrderHandle {
+            account_id: manager.account_id().clone(),
+            client_id: "bot-1".to_string(),
+            order_id: Some("41".to_string()),
+            outcome: None,
+            poll_interval: Duration::from_secs(1),
+        };
+        let fill = Transaction::new()
+            .with_otype("ORDER_FILL".to_string())
+            .with_order_id("41".to_string())
+            .with_trade_opened(TradeOpen::new().with_trade_id("42".to_string()).with_price(1.1));
+        assert_eq!(
+            handle.outcome_of(&fill).unwrap(),
+            Some(OrderOutcome::Filled { trade_id: Some("42".to_string()), price: 1.1 })
+        );
+        let other = fill.clone().with_order_id("40".to_string());
+        assert_eq!(handle.outcome_of(&other).unwrap(), None);
+        let cancel = Transaction::new()
+            .with_otype("ORDER_CANCEL".to_string())
+            .with_client_order_id("bot-1".to_string())
+            .with_reason("TIME_IN_FORCE_EXPIRED".to_string());
+        assert_eq!(
+            handle.outcome_of(&cancel).unwrap(),
+            Some(OrderOutcome::Cancelled { reason: "TIME_IN_FORCE_EXPIRED".to_string() })
+        );
+    }
+}
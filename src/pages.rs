@@ -0,0 +1,438 @@
+use chrono::prelude::*;
+use futures::stream::{self, Stream, StreamExt};
+use std::error::Error;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::*;
+
+/// The pause between two requests of a paginated download, keeping a long
+/// walk well under OANDA's limit of 120 requests per second.
+pub const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The largest `count` `ListTradesRequest` and `ListOrdersRequest` accept.
+pub const MAX_ITEMS_PER_PAGE: i32 = 500;
+
+/// The `(from, to)` transaction ID ranges of the `pages` returned by
+/// `ListTransactionsRequest`.
+pub fn transaction_page_ranges(pages: &[String]) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let mut result = Vec::with_capacity(pages.len());
+    for page in pages {
+        let url = reqwest::Url::parse(page)?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.into_owned())
+        };
+        match (param("from"), param("to")) {
+            (Some(from), Some(to)) => result.push((from, to)),
+            _ => {
+                return Err(format!("transaction page {} has no ID range", page).into());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Downloads the transactions of an account over a time range.
+///
+/// `ListTransactionsRequest` only returns links to pages of transactions;
+/// this follows them with `GetTransactionRangeRequest`, one page at a time,
+/// and yields the transactions oldest first.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use chrono::prelude::*;
+/// # use futures::TryStreamExt;
+/// # async fn audit(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let transactions: Vec<Transaction> = TransactionHistory::new("101-004-1234567-001".to_string())
+///     .with_from(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+///     .stream(client)
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionHistory {
+    account_id: String,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    page_size: Option<i32>,
+    otype: Option<Vec<String>>,
+    request_interval: Duration,
+}
+
+impl TransactionHistory {
+    pub fn new(account_id: String) -> TransactionHistory {
+        TransactionHistory {
+            account_id,
+            from: None,
+            to: None,
+            page_size: None,
+            otype: None,
+            request_interval: DEFAULT_REQUEST_INTERVAL,
+        }
+    }
+
+    /// The start of the range. The server default is the account creation
+    /// time.
+    pub fn with_from(mut self, x: DateTime<Utc>) -> Self {
+        self.from = Some(x);
+        self
+    }
+
+    /// The end of the range. The server default is now.
+    pub fn with_to(mut self, x: DateTime<Utc>) -> Self {
+        self.to = Some(x);
+        self
+    }
+
+    /// The number of transactions per page, at most 1000.
+    pub fn with_page_size(mut self, x: i32) -> Self {
+        self.page_size = Some(x);
+        self
+    }
+
+    /// Only return transactions of these types.
+    pub fn with_otype(mut self, x: Vec<String>) -> Self {
+        self.otype = Some(x);
+        self
+    }
+
+    /// The pause between page requests.
+    pub fn with_request_interval(mut self, x: Duration) -> Self {
+        self.request_interval = x;
+        self
+    }
+
+    async fn page_ranges<C>(&self, client: &Client<C>) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        let mut request = ListTransactionsRequest::new().with_account_id(self.account_id.clone());
+        if let Some(x) = self.from {
+            request = request.with_from(x);
+        }
+        if let Some(x) = self.to {
+            request = request.with_to(x);
+        }
+        if let Some(x) = self.page_size {
+            request = request.with_page_size(x);
+        }
+        if let Some(ref x) = self.otype {
+            request = request.with_otype(x.clone());
+        }
+        let response = request.remote(client).await?;
+        match response.pages {
+            Some(pages) => transaction_page_ranges(&pages),
+            None => Err(format!("no transaction pages returned for {}", self.account_id).into()),
+        }
+    }
+
+    async fn fetch_page<C>(
+        &self,
+        client: &Client<C>,
+        from: String,
+        to: String
+    ) -> Result<Vec<Transaction>, Box<dyn Error>> {
+        tokio::time::sleep(self.request_interval).await;
+        let mut request = GetTransactionRangeRequest::new()
+            .with_account_id(self.account_id.clone())
+            .with_from(from.clone())
+            .with_to(to.clone());
+        if let Some(ref x) = self.otype {
+            request = request.with_otype(x.clone());
+        }
+        let response = request.remote(client).await?;
+        match response.transactions {
+            Some(transactions) => Ok(transactions),
+            None => Err(format!("no transactions returned for {} to {}", from, to).into()),
+        }
+    }
+
+    /// Stream the transactions of the range, oldest first.
+    pub fn stream<'a, C>(
+        &'a self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<Transaction, Box<dyn Error>>> + 'a {
+        stream::once(self.page_ranges(client))
+            .map(move |ranges| {
+                match ranges {
+                    Err(e) => stream::iter(vec![Err(e)]).left_stream(),
+                    Ok(ranges) => stream::iter(ranges)
+                        .then(move |(from, to)| self.fetch_page(client, from, to))
+                        .right_stream(),
+                }
+            })
+            .flatten()
+            .map(flatten_page)
+            .flatten()
+    }
+}
+
+fn flatten_page<T>(
+    page: Result<Vec<T>, Box<dyn Error>>
+) -> impl Stream<Item = Result<T, Box<dyn Error>>> {
+    let items: Vec<Result<T, Box<dyn Error>>> = match page {
+        Err(e) => vec![Err(e)],
+        Ok(items) => items.into_iter().map(Ok).collect(),
+    };
+    stream::iter(items)
+}
+
+/// Walks the trades of an account from the newest back, following
+/// `beforeID` one page at a time until there are no more trades, the
+/// oldest wanted ID is passed or the limit is reached.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use futures::TryStreamExt;
+/// # async fn report(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let trades: Vec<Trade> = TradeHistory::new("101-004-1234567-001".to_string())
+///     .with_state("CLOSED".to_string())
+///     .with_limit(2000)
+///     .stream(client)
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TradeHistory {
+    walk: IdWalk,
+}
+
+/// Walks the orders of an account from the newest back, following
+/// `beforeID` one page at a time until there are no more orders, the
+/// oldest wanted ID is passed or the limit is reached.
+#[derive(Debug, Clone)]
+pub struct OrderHistory {
+    walk: IdWalk,
+}
+
+#[derive(Debug, Clone)]
+struct IdWalk {
+    account_id: String,
+    state: Option<String>,
+    instrument: Option<String>,
+    before_id: Option<String>,
+    min_id: Option<u64>,
+    limit: Option<usize>,
+    count: i32,
+    request_interval: Duration,
+}
+
+macro_rules! id_walk_builders {
+    ($name:ident) => {
+        impl $name {
+            pub fn new(account_id: String) -> $name {
+                $name {
+                    walk: IdWalk {
+                        account_id,
+                        state: None,
+                        instrument: None,
+                        before_id: None,
+                        min_id: None,
+                        limit: None,
+                        count: MAX_ITEMS_PER_PAGE,
+                        request_interval: DEFAULT_REQUEST_INTERVAL,
+                    },
+                }
+            }
+
+            /// Passed through as `state`. The server default is "OPEN"
+            /// (trades) or "PENDING" (orders); use "ALL" for the full history.
+            pub fn with_state(mut self, x: String) -> Self {
+                self.walk.state = Some(x);
+                self
+            }
+
+            /// Passed through as `instrument`.
+            pub fn with_instrument(mut self, x: String) -> Self {
+                self.walk.instrument = Some(x);
+                self
+            }
+
+            /// Start below this ID instead of at the newest.
+            pub fn with_before_id(mut self, x: String) -> Self {
+                self.walk.before_id = Some(x);
+                self
+            }
+
+            /// Stop once IDs drop below this one. The ID itself is included.
+            pub fn with_min_id(mut self, x: u64) -> Self {
+                self.walk.min_id = Some(x);
+                self
+            }
+
+            /// Stop after this many items.
+            pub fn with_limit(mut self, x: usize) -> Self {
+                self.walk.limit = Some(x);
+                self
+            }
+
+            /// The number of items requested per page, capped at 500.
+            pub fn with_count(mut self, x: i32) -> Self {
+                self.walk.count = x.clamp(1, MAX_ITEMS_PER_PAGE);
+                self
+            }
+
+            /// The pause between page requests.
+            pub fn with_request_interval(mut self, x: Duration) -> Self {
+                self.walk.request_interval = x;
+                self
+            }
+        }
+    };
+}
+
+id_walk_builders!(TradeHistory);
+id_walk_builders!(OrderHistory);
+
+impl TradeHistory {
+    /// Stream the trades, newest first.
+    pub fn stream<'a, C>(
+        &'a self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<Trade, Box<dyn Error>>> + 'a {
+        let walk = &self.walk;
+        walk.stream(
+            move |before_id| {
+                let mut request = ListTradesRequest::new()
+                    .with_account_id(walk.account_id.clone())
+                    .with_count(walk.count);
+                if let Some(ref x) = walk.state {
+                    request = request.with_state(x.clone());
+                }
+                if let Some(ref x) = walk.instrument {
+                    request = request.with_instrument(x.clone());
+                }
+                if let Some(x) = before_id {
+                    request = request.with_before_id(x);
+                }
+                async move {
+                    let response = request.remote(client).await?;
+                    response.trades.ok_or_else(|| "no trades returned".into())
+                }
+            },
+            |trade: &Trade| trade.id.as_deref()
+        )
+    }
+}
+
+impl OrderHistory {
+    /// Stream the orders, newest first.
+    pub fn stream<'a, C>(
+        &'a self,
+        client: &'a Client<C>
+    ) -> impl Stream<Item = Result<Order, Box<dyn Error>>> + 'a {
+        let walk = &self.walk;
+        walk.stream(
+            move |before_id| {
+                let mut request = ListOrdersRequest::new()
+                    .with_account_id(walk.account_id.clone())
+                    .with_count(walk.count);
+                if let Some(ref x) = walk.state {
+                    request = request.with_state(x.clone());
+                }
+                if let Some(ref x) = walk.instrument {
+                    request = request.with_instrument(x.clone());
+                }
+                if let Some(x) = before_id {
+                    request = request.with_before_id(x);
+                }
+                async move {
+                    let response = request.remote(client).await?;
+                    response.orders.ok_or_else(|| "no orders returned".into())
+                }
+            },
+            |order: &Order| order.id.as_deref()
+        )
+    }
+}
+
+struct WalkState {
+    before_id: Option<String>,
+    remaining: Option<usize>,
+    first: bool,
+    done: bool,
+}
+
+impl IdWalk {
+    fn stream<'a, T, F, Fut>(
+        &'a self,
+        fetch: F,
+        id_of: fn(&T) -> Option<&str>
+    ) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a
+        where
+            T: 'a,
+            F: Fn(Option<String>) -> Fut + 'a,
+            Fut: Future<Output = Result<Vec<T>, Box<dyn Error>>> + 'a
+    {
+        let state = WalkState {
+            before_id: self.before_id.clone(),
+            remaining: self.limit,
+            first: true,
+            done: false,
+        };
+        stream::unfold((state, fetch), move |(mut state, fetch)| async move {
+            if state.done || state.remaining == Some(0) {
+                return None;
+            }
+            if !state.first {
+                tokio::time::sleep(self.request_interval).await;
+            }
+            state.first = false;
+            let mut items = match fetch(state.before_id.clone()).await {
+                Ok(x) => x,
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), (state, fetch)));
+                }
+            };
+            // A short page is the last one.
+            state.done = items.len() < (self.count as usize);
+            if let Some(min) = self.min_id {
+                let wanted = items
+                    .iter()
+                    .take_while(|x| id_of(x).and_then(|id| id.parse::<u64>().ok()).is_none_or(|id| id >= min))
+                    .count();
+                if wanted < items.len() {
+                    items.truncate(wanted);
+                    state.done = true;
+                }
+            }
+            if let Some(remaining) = state.remaining {
+                items.truncate(remaining);
+                state.remaining = Some(remaining - items.len());
+            }
+            match items.last().and_then(id_of) {
+                Some(id) => {
+                    state.before_id = Some(id.to_string());
+                }
+                None => {
+                    state.done = true;
+                }
+            }
+            Some((Ok(items), (state, fetch)))
+        })
+            .map(flatten_page)
+            .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_transaction_pages() {
+        let pages = vec![
+            "https://api-fxpractice.oanda.com/v3/accounts/101-004-1234567-001/transactions/idrange?from=1&to=1000".to_string(),
+            "https://api-fxpractice.oanda.com/v3/accounts/101-004-1234567-001/transactions/idrange?from=1001&to=1042".to_string()
+        ];
+        assert_eq!(
+            transaction_page_ranges(&pages).unwrap(),
+            vec![("1".to_string(), "1000".to_string()), ("1001".to_string(), "1042".to_string())]
+        );
+        assert!(transaction_page_ranges(&["https://example.com/".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use std::error::Error;
+
+use crate::transaction_stream::{next_line, open_stream};
+use crate::*;
+
+/// Reads an account's price stream as prices change.
+///
+/// Like `TransactionStream`, the stream is read one line at a time from the
+/// stream host instead of through `StreamPricingRequest`, which waits for
+/// the whole response body. Heartbeats are skipped.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn watch(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = PriceStream::connect(client, "101-004-1234567-001", &["EUR_USD", "USD_JPY"]).await?;
+/// while let Some(price) = stream.next().await? {
+///     println!("{:?} {:?} {:?}", price.instrument, price.closeout_bid, price.closeout_ask);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PriceStream {
+    client: Client<ReadOnly>,
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+}
+
+impl PriceStream {
+    /// Open the price stream of `instruments` for `account_id`.
+    pub async fn connect<C, A: Into<AccountId>>(
+        client: &Client<C>,
+        account_id: A,
+        instruments: &[&str]
+    ) -> Result<PriceStream, Box<dyn Error>> {
+        let uri = format!("/v3/accounts/{}/pricing/stream", account_id.into());
+        let response = open_stream(client, &uri, &[("instruments", instruments.join(","))]).await?;
+        Ok(PriceStream {
+            client: client.to_read_only(),
+            response,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// The next price, or `None` once the server closes the stream.
+    pub async fn next(&mut self) -> Result<Option<ClientPrice>, Box<dyn Error>> {
+        while let Some(line) = next_line(&mut self.response, &mut self.buffer).await? {
+            if let Some(x) = parse_line(&self.client, &line)? {
+                return Ok(Some(x));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// A price line, or `None` for a heartbeat or an empty line.
+fn parse_line(client: &Client<ReadOnly>, line: &[u8]) -> Result<Option<ClientPrice>, Box<dyn Error>> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let heartbeat: PricingHeartbeat = serde_json::from_slice(line)?;
+    if heartbeat.otype.as_deref() == Some("HEARTBEAT") {
+        return Ok(None);
+    }
+    client.decode_slice(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_heartbeats() {
+        let client = Client::new_read_only("api-fxpractice.oanda.com".to_string(), String::new());
+        let heartbeat = br#"{"type":"HEARTBEAT","time":"2020-01-06T00:00:00.000000000Z"}"#;
+        assert_eq!(parse_line(&client, heartbeat).unwrap(), None);
+        let line = br#"{"type":"PRICE","instrument":"EUR_USD","closeoutBid":"1.1","closeoutAsk":"1.1002"}"#;
+        let price = parse_line(&client, line).unwrap().unwrap();
+        assert_eq!((price.instrument.as_deref(), price.closeout_bid), (Some("EUR_USD"), Some(1.1)));
+    }
+}
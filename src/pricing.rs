@@ -116,24 +116,19 @@ pub mod get_base_prices {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetBasePricesResponse, Box<dyn Error>> {
             let uri = self.uri.clone();
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetBasePricesResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetBasePricesResponse>(response).await,
             }
         }
     }
@@ -141,7 +136,7 @@ pub mod get_base_prices {
     pub type GetBasePricesResponse = GetBasePricesResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetBasePricesResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -149,7 +144,7 @@ pub mod get_base_prices {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetBasePricesResponse200Body {
         /// The list of prices that satisfy the request.
         #[serde(rename = "prices", skip_serializing_if = "Option::is_none")]
@@ -311,24 +306,19 @@ pub mod get_price_range {
             self
         }
 
-        pub async fn remote(
+        pub async fn remote<C>(
             self,
-            client: &Client
+            client: &Client<C>
         ) -> Result<GetPriceRangeResponse, Box<dyn Error>> {
             let uri = self.uri.clone().replace("{instrument}", &self.path.instrument.unwrap());
-            let url = format!("https://{host}{uri}", host = client.host, uri = uri);
-            let res = client.reqwest
-                .get(&url)
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
                 .query(&self.query)
-                .bearer_auth(&client.authentication)
                 .send().await;
             match res {
                 Err(e) => Err(Box::new(e)),
-                Ok(response) =>
-                    match response.json::<GetPriceRangeResponse>().await {
-                        Err(e) => Err(Box::new(e)),
-                        Ok(j) => Ok(j),
-                    }
+                Ok(response) => client.decode::<GetPriceRangeResponse>(response).await,
             }
         }
     }
@@ -336,7 +326,7 @@ pub mod get_price_range {
     pub type GetPriceRangeResponse = GetPriceRangeResponse200Body;
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPriceRangeResponse200Header {
         /// The unique identifier generated for the request
         #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
@@ -347,7 +337,7 @@ pub mod get_price_range {
     }
 
     /// Pricing information has been successfully provided.
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
     pub struct GetPriceRangeResponse200Body {
         /// The list of prices that satisfy the request.
         #[serde(rename = "prices", skip_serializing_if = "Option::is_none")]
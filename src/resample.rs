@@ -0,0 +1,344 @@
+use chrono::prelude::*;
+use chrono::{Duration, Months};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+
+use crate::*;
+
+/// Why a `CandleSeries` could not be resampled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResampleError {
+    /// A target bar is not made of a whole number of series bars: the
+    /// target granularity is not a multiple of the series granularity.
+    NotNested(CandlestickGranularity, CandlestickGranularity),
+    /// The series bar starting at this time straddles a target bar boundary,
+    /// e.g. `H1` bars into days that start on a half hour.
+    Misaligned(DateTime<Utc>),
+    /// `alignmentTimezone` is not an IANA timezone name.
+    UnknownTimezone(String),
+    /// `dailyAlignment` is not an hour between 0 and 23.
+    InvalidDailyAlignment(i32),
+}
+
+impl std::fmt::Display for ResampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ResampleError::NotNested(from, to) => {
+                write!(f, "cannot resample {} candles into {} candles", from, to)
+            }
+            ResampleError::Misaligned(x) => {
+                write!(f, "the candle at {} straddles a resampled bar boundary", x.to_rfc3339())
+            }
+            ResampleError::UnknownTimezone(x) => write!(f, "unknown alignment timezone {:?}", x),
+            ResampleError::InvalidDailyAlignment(x) => write!(f, "invalid daily alignment {}", x),
+        }
+    }
+}
+
+impl std::error::Error for ResampleError {}
+
+/// Builds coarser candles from a finer `CandleSeries` locally.
+///
+/// Bars are aligned the way `GetInstrumentCandlesRequest` aligns them on the
+/// server, using the same `dailyAlignment`, `alignmentTimezone` and
+/// `weeklyAlignment` parameters and the same defaults (17:00,
+/// America/New_York, Friday):
+///
+/// - `S5` to `H1` start on multiples of their length since the Unix epoch.
+/// - `H2` to `H12` and `D` start at the daily alignment hour in the alignment
+///   timezone and every multiple of their length after it.
+/// - `W` starts at the daily alignment hour on the weekly alignment day.
+/// - `M` starts at the last daily boundary at or before midnight on the first
+///   day of the month in the alignment timezone.
+///
+/// Every target bar must be made of whole source bars: the target length has
+/// to be a multiple of the source length (`M4` to `M10` or `H2` to `H3` are
+/// refused), weekly and monthly bars only take sources that divide a day, and
+/// a source bar that straddles a target boundary, as `H1` bars do with days
+/// starting on a half-hour timezone offset, fails the resample.
+///
+/// A resampled bar is complete when all of its source bars are complete and
+/// the source series reaches the end of the bar.
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    granularity: CandlestickGranularity,
+    daily_alignment: i32,
+    alignment_timezone: String,
+    weekly_alignment: WeeklyAlignment,
+}
+
+impl Resampler {
+    pub fn new(granularity: CandlestickGranularity) -> Resampler {
+        Resampler {
+            granularity,
+            daily_alignment: 17,
+            alignment_timezone: String::from("America/New_York"),
+            weekly_alignment: WeeklyAlignment::Friday,
+        }
+    }
+
+    /// The hour of the day (in the alignment timezone) daily bars start at.
+    pub fn with_daily_alignment(mut self, x: i32) -> Self {
+        self.daily_alignment = x;
+        self
+    }
+
+    /// The IANA timezone the daily alignment hour is expressed in.
+    pub fn with_alignment_timezone(mut self, x: String) -> Self {
+        self.alignment_timezone = x;
+        self
+    }
+
+    /// The day of the week weekly bars start on.
+    pub fn with_weekly_alignment(mut self, x: WeeklyAlignment) -> Self {
+        self.weekly_alignment = x;
+        self
+    }
+
+    /// The start of the target bar that contains `time`.
+    pub fn bar_start(&self, time: DateTime<Utc>) -> Result<DateTime<Utc>, ResampleError> {
+        let tz = self.timezone()?;
+        let hour = self.hour()?;
+        Ok(self.bar_start_in(time, tz, hour))
+    }
+
+    /// Resample `series` into the target granularity.
+    pub fn resample(&self, series: &CandleSeries) -> Result<CandleSeries, ResampleError> {
+        let source = series.granularity();
+        if !nests(self.granularity, source) {
+            return Err(ResampleError::NotNested(source, self.granularity));
+        }
+        let tz = self.timezone()?;
+        let hour = self.hour()?;
+
+        let mut buckets: BTreeMap<DateTime<Utc>, (Ohlc, bool)> = BTreeMap::new();
+        for bar in series.iter() {
+            let start = self.bar_start_in(bar.time, tz, hour);
+            // Sources of an hour or less are aligned on the epoch rather than
+            // on the day boundary, so they may not line up with the target.
+            let length = granularity_duration(source);
+            let last = bar.time + length - Duration::seconds(1);
+            if length <= Duration::hours(1) && self.bar_start_in(last, tz, hour) != start {
+                return Err(ResampleError::Misaligned(bar.time));
+            }
+            buckets
+                .entry(start)
+                .and_modify(|(agg, complete)| {
+                    agg.high = agg.high.max(bar.high);
+                    agg.low = agg.low.min(bar.low);
+                    agg.close = bar.close;
+                    agg.volume += bar.volume;
+                    *complete = *complete && bar.complete;
+                })
+                .or_insert((Ohlc { time: start, ..*bar }, bar.complete));
+        }
+
+        let covered = series.last().map(|bar| next_bar_time(source, bar.time));
+        let mut result = CandleSeries::new(self.granularity);
+        for (start, (mut bar, complete)) in buckets {
+            let end = self.bar_start_in(self.bar_end_estimate(start), tz, hour);
+            bar.complete = complete && covered.is_some_and(|c| c >= end);
+            result.insert(bar);
+        }
+        Ok(result)
+    }
+
+    fn timezone(&self) -> Result<Tz, ResampleError> {
+        self.alignment_timezone
+            .parse::<Tz>()
+            .map_err(|_| ResampleError::UnknownTimezone(self.alignment_timezone.clone()))
+    }
+
+    fn hour(&self) -> Result<u32, ResampleError> {
+        match self.daily_alignment {
+            0..=23 => Ok(self.daily_alignment as u32),
+            x => Err(ResampleError::InvalidDailyAlignment(x)),
+        }
+    }
+
+    // A time inside the bar after the one starting at `start`, used to find
+    // where the bar starting at `start` ends.
+    fn bar_end_estimate(&self, start: DateTime<Utc>) -> DateTime<Utc> {
+        match self.granularity {
+            CandlestickGranularity::M => start + Duration::days(35),
+            CandlestickGranularity::W => start + Duration::days(8),
+            CandlestickGranularity::D => start + Duration::hours(26),
+            g => start + granularity_duration(g) + Duration::minutes(30),
+        }
+    }
+
+    fn bar_start_in(&self, time: DateTime<Utc>, tz: Tz, hour: u32) -> DateTime<Utc> {
+        match self.granularity {
+            CandlestickGranularity::W => {
+                let mut start = day_start(time, tz, hour);
+                while start.with_timezone(&tz).weekday() != weekday(self.weekly_alignment) {
+                    start = day_start(start - Duration::seconds(1), tz, hour);
+                }
+                start
+            }
+            CandlestickGranularity::M => {
+                let local = time.with_timezone(&tz).date_naive();
+                let first = NaiveDate::from_ymd_opt(local.year(), local.month(), 1).unwrap();
+                let next = first.checked_add_months(Months::new(1)).unwrap();
+                let next_start = day_start(local_midnight(next, tz), tz, hour);
+                if time >= next_start {
+                    next_start
+                } else {
+                    day_start(local_midnight(first, tz), tz, hour)
+                }
+            }
+            CandlestickGranularity::H2 |
+            CandlestickGranularity::H3 |
+            CandlestickGranularity::H4 |
+            CandlestickGranularity::H6 |
+            CandlestickGranularity::H8 |
+            CandlestickGranularity::H12 |
+            CandlestickGranularity::D => {
+                let day = day_start(time, tz, hour);
+                let length = granularity_duration(self.granularity).num_seconds();
+                let offset = (time - day).num_seconds();
+                day + Duration::seconds((offset / length) * length)
+            }
+            g => {
+                let length = granularity_duration(g).num_seconds();
+                let seconds = time.timestamp().div_euclid(length) * length;
+                Utc.timestamp_opt(seconds, 0).unwrap()
+            }
+        }
+    }
+}
+
+// Whether every `target` bar is made of whole `source` bars, as far as the
+// lengths go. Alignment is checked bar by bar in `resample`.
+fn nests(target: CandlestickGranularity, source: CandlestickGranularity) -> bool {
+    let day = Duration::days(1).num_seconds();
+    let t = granularity_duration(target).num_seconds();
+    let s = granularity_duration(source).num_seconds();
+    match (target, source) {
+        (_, CandlestickGranularity::M) | (CandlestickGranularity::M, CandlestickGranularity::W) => false,
+        (CandlestickGranularity::W, _) | (CandlestickGranularity::M, _) => day % s == 0,
+        _ => t > s && t % s == 0,
+    }
+}
+
+fn weekday(x: WeeklyAlignment) -> Weekday {
+    match x {
+        WeeklyAlignment::Monday => Weekday::Mon,
+        WeeklyAlignment::Tuesday => Weekday::Tue,
+        WeeklyAlignment::Wednesday => Weekday::Wed,
+        WeeklyAlignment::Thursday => Weekday::Thu,
+        WeeklyAlignment::Friday => Weekday::Fri,
+        WeeklyAlignment::Saturday => Weekday::Sat,
+        WeeklyAlignment::Sunday => Weekday::Sun,
+    }
+}
+
+fn local_midnight(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    local_time(date, 0, tz)
+}
+
+// The UTC instant of `hour`:00 on `date` in `tz`. Times skipped by a DST
+// change resolve to the first valid instant after them.
+fn local_time(date: NaiveDate, hour: u32, tz: Tz) -> DateTime<Utc> {
+    let mut naive = date.and_hms_opt(hour, 0, 0).unwrap();
+    loop {
+        if let Some(x) = tz.from_local_datetime(&naive).earliest() {
+            return x.with_timezone(&Utc);
+        }
+        naive += Duration::minutes(30);
+    }
+}
+
+// The most recent daily boundary (`hour`:00 in `tz`) at or before `time`.
+fn day_start(time: DateTime<Utc>, tz: Tz, hour: u32) -> DateTime<Utc> {
+    let date = time.with_timezone(&tz).date_naive();
+    let today = local_time(date, hour, tz);
+    if today <= time {
+        today
+    } else {
+        local_time(date.pred_opt().unwrap(), hour, tz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2020, 7, d, h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn aligns_like_the_server() {
+        // New York is on EDT (UTC-4) in July, so 17:00 local is 21:00 UTC.
+        let t = utc(8, 23, 10);
+        assert_eq!(Resampler::new(CandlestickGranularity::H1).bar_start(t), Ok(utc(8, 23, 0)));
+        assert_eq!(Resampler::new(CandlestickGranularity::H4).bar_start(t), Ok(utc(8, 21, 0)));
+        assert_eq!(Resampler::new(CandlestickGranularity::D).bar_start(t), Ok(utc(8, 21, 0)));
+        assert_eq!(Resampler::new(CandlestickGranularity::D).bar_start(utc(8, 20, 0)), Ok(utc(7, 21, 0)));
+        // Friday 3 July 2020 17:00 New York.
+        assert_eq!(Resampler::new(CandlestickGranularity::W).bar_start(t), Ok(utc(3, 21, 0)));
+        assert_eq!(
+            Resampler::new(CandlestickGranularity::D)
+                .with_daily_alignment(0)
+                .with_alignment_timezone("UTC".to_string())
+                .bar_start(t),
+            Ok(utc(8, 0, 0))
+        );
+    }
+
+    #[test]
+    fn aggregates_bars() {
+        let mut series = CandleSeries::new(CandlestickGranularity::H1);
+        for (i, h) in [20, 21, 22, 23].iter().enumerate() {
+            let x = i as f32;
+            series.insert(Ohlc {
+                time: utc(8, *h, 0),
+                open: 1.0 + x,
+                high: 2.0 + x,
+                low: 0.5 + x,
+                close: 1.5 + x,
+                volume: 10,
+                complete: true,
+            });
+        }
+        let h4 = Resampler::new(CandlestickGranularity::H4).resample(&series).unwrap();
+        assert_eq!(h4.len(), 2);
+        let last = h4.get(utc(8, 21, 0)).unwrap();
+        assert_eq!((last.open, last.high, last.low, last.close, last.volume), (2.0, 5.0, 1.5, 4.5, 30));
+        assert!(!last.complete);
+        assert!(Resampler::new(CandlestickGranularity::M1).resample(&series).is_err());
+    }
+
+    #[test]
+    fn refuses_bars_that_do_not_nest() {
+        let series = |g: CandlestickGranularity, time: DateTime<Utc>| {
+            let mut x = CandleSeries::new(g);
+            x.insert(Ohlc { time, open: 1.0, high: 1.0, low: 1.0, close: 1.0, volume: 1, complete: true });
+            x
+        };
+        for (from, to) in [
+            (CandlestickGranularity::M4, CandlestickGranularity::M10),
+            (CandlestickGranularity::H2, CandlestickGranularity::H3),
+            (CandlestickGranularity::H4, CandlestickGranularity::H6),
+            (CandlestickGranularity::W, CandlestickGranularity::M),
+        ] {
+            assert_eq!(
+                Resampler::new(to).resample(&series(from, utc(8, 21, 0))),
+                Err(ResampleError::NotNested(from, to))
+            );
+        }
+        let h2 = series(CandlestickGranularity::H2, utc(8, 21, 0));
+        assert!(Resampler::new(CandlestickGranularity::H6).resample(&h2).is_ok());
+
+        // 17:00 in Kolkata (UTC+5:30) is 11:30 UTC: half-hour bars fit in its
+        // days, hourly bars do not.
+        let kolkata = Resampler::new(CandlestickGranularity::D)
+            .with_alignment_timezone("Asia/Kolkata".to_string());
+        assert!(kolkata.resample(&series(CandlestickGranularity::M30, utc(8, 11, 0))).is_ok());
+        assert_eq!(
+            kolkata.resample(&series(CandlestickGranularity::H1, utc(8, 11, 0))),
+            Err(ResampleError::Misaligned(utc(8, 11, 0)))
+        );
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::*;
+
+/// What an order would do, as far as the risk checks are concerned.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OrderIntent {
+    pub instrument: Option<String>,
+    /// Positive to buy, negative to sell.
+    pub units: f32,
+    /// The limit, stop or bound price, used for the notional check.
+    pub price: Option<f32>,
+    /// The order can only reduce an existing position (`positionFill` is
+    /// `REDUCE_ONLY`).
+    pub reduce_only: bool,
+}
+
+macro_rules! order_intent {
+    ($($order:ident [$price:ident]),*) => {
+      $(
+        impl From<&$order> for OrderIntent {
+          fn from(x: &$order) -> OrderIntent {
+            OrderIntent {
+              instrument: x.instrument.clone(),
+              units: x.units.unwrap_or(0.0),
+              price: x.$price,
+              reduce_only: x.position_fill.as_deref() == Some("REDUCE_ONLY"),
+            }
+          }
+        }
+      )*
+    };
+}
+
+order_intent!(MarketOrder [price_bound],
+              LimitOrder [price],
+              StopOrder [price],
+              MarketOrderRequest [price_bound],
+              LimitOrderRequest [price]);
+
+impl OrderIntent {
+    /// The intent of a replacement order. `OrderRequest` is untyped, so the
+    /// fields are read from its JSON; `None` when they cannot be found.
+    /// Dependent orders (take profit, stop loss) only ever reduce.
+    pub(crate) fn from_replacement(x: &OrderRequest) -> Option<OrderIntent> {
+        let value = serde_json::to_value(x).ok()?;
+        let text = |key: &str| value.get(key).and_then(|v| v.as_str());
+        let number = |key: &str| {
+            let v = value.get(key)?;
+            v.as_str().and_then(|s| s.parse::<f32>().ok()).or_else(|| v.as_f64().map(|f| f as f32))
+        };
+        let dependent = ["TAKE_PROFIT", "STOP_LOSS", "GUARANTEED_STOP_LOSS", "TRAILING_STOP_LOSS"];
+        if text("type").is_some_and(|x| dependent.contains(&x)) {
+            return Some(OrderIntent {
+                reduce_only: true,
+                ..OrderIntent::default()
+            });
+        }
+        Some(OrderIntent {
+            instrument: Some(text("instrument")?.to_string()),
+            units: number("units")?,
+            price: number("price").or_else(|| number("priceBound")),
+            reduce_only: text("positionFill") == Some("REDUCE_ONLY"),
+        })
+    }
+}
+
+/// What a request that changes an account does, as far as the `RiskGuard`
+/// is concerned. Every such endpoint passes one to `Client::guard`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RiskAction {
+    /// Creates or replaces an order. `None` stands for an order that cannot
+    /// be inspected.
+    Order(Option<OrderIntent>),
+    /// Changes the account configuration; only the kill switch applies.
+    Configure,
+    /// Closes positions or trades, cancels orders or edits dependent orders
+    /// and client extensions; always allowed.
+    Reduce,
+}
+
+/// A limit breached by an order, or the kill switch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskError {
+    /// The kill switch is engaged; only closing orders may be sent.
+    KillSwitch,
+    InstrumentNotAllowed(String),
+    /// The order has no instrument.
+    UnknownInstrument,
+    /// The order cannot be inspected, as when a `ReplaceOrderRequest` body
+    /// lacks an instrument or units, and limits are set.
+    UncheckedOrder,
+    /// The net position the order would leave, in units.
+    Units { instrument: String, units: f32, limit: f32 },
+    Notional { instrument: String, notional: f32, limit: f32 },
+    /// A notional limit applies but neither the order nor the pricing
+    /// endpoint gave a price to compute it from.
+    UnknownPrice(String),
+    OpenTrades { open: i32, limit: i32 },
+    /// Margin used as a fraction of NAV.
+    Margin { fraction: f32, limit: f32 },
+    /// The account summary lacks a field a limit needs.
+    MissingAccountState(&'static str),
+}
+
+impl std::fmt::Display for RiskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RiskError::KillSwitch => write!(f, "kill switch engaged"),
+            RiskError::InstrumentNotAllowed(x) => write!(f, "{} is not an allowed instrument", x),
+            RiskError::UnknownInstrument => write!(f, "order has no instrument"),
+            RiskError::UncheckedOrder => write!(f, "order cannot be checked against the limits"),
+            RiskError::Units { instrument, units, limit } => {
+                write!(f, "a position of {} units of {} exceeds the limit of {}", units, instrument, limit)
+            }
+            RiskError::Notional { instrument, notional, limit } => {
+                write!(f, "notional {} of {} exceeds the limit of {}", notional, instrument, limit)
+            }
+            RiskError::UnknownPrice(x) => write!(f, "no price to check the {} notional limit", x),
+            RiskError::OpenTrades { open, limit } => {
+                write!(f, "{} open trades reach the limit of {}", open, limit)
+            }
+            RiskError::Margin { fraction, limit } => {
+                write!(f, "margin used is {} of NAV, above the limit of {}", fraction, limit)
+            }
+            RiskError::MissingAccountState(x) => write!(f, "account summary has no {}", x),
+        }
+    }
+}
+
+impl std::error::Error for RiskError {}
+
+/// The limits a `RiskGuard` enforces. Every limit is off until set.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RiskLimits {
+    max_units: BTreeMap<String, f32>,
+    max_notional: BTreeMap<String, f32>,
+    max_open_trades: Option<i32>,
+    max_margin_fraction: Option<f32>,
+    allowed_instruments: Option<BTreeSet<String>>,
+}
+
+impl RiskLimits {
+    pub fn new() -> RiskLimits {
+        RiskLimits::default()
+    }
+
+    /// The largest absolute net position in `instrument` an order may leave,
+    /// assuming it fills in full. Orders that shrink the position are always
+    /// allowed; other pending orders are not counted.
+    pub fn with_max_units(mut self, instrument: String, x: f32) -> Self {
+        self.max_units.insert(instrument, x);
+        self
+    }
+
+    /// The largest `|units| * price` of a single order for `instrument`, in
+    /// the instrument's quote currency. Orders without a price or price
+    /// bound are valued at the current closeout price.
+    pub fn with_max_notional(mut self, instrument: String, x: f32) -> Self {
+        self.max_notional.insert(instrument, x);
+        self
+    }
+
+    /// No new orders once this many trades are open.
+    pub fn with_max_open_trades(mut self, x: i32) -> Self {
+        self.max_open_trades = Some(x);
+        self
+    }
+
+    /// No new orders while margin used is above this fraction of NAV.
+    pub fn with_max_margin_fraction(mut self, x: f32) -> Self {
+        self.max_margin_fraction = Some(x);
+        self
+    }
+
+    /// Only allow orders for these instruments.
+    pub fn with_allowed_instruments(mut self, x: Vec<String>) -> Self {
+        self.allowed_instruments = Some(x.into_iter().collect());
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == RiskLimits::default()
+    }
+
+    fn needs_summary(&self) -> bool {
+        self.max_open_trades.is_some() || self.max_margin_fraction.is_some()
+    }
+}
+
+/// Pre-trade risk checks and a kill switch for a `Client<Trading>`.
+///
+/// Every `Client<Trading>` has a guard, with no limits until one is
+/// installed with `Client::with_risk_guard`. Every request that changes an
+/// account goes through it: the order creation requests
+/// (`CreateMarketOrderRequest`, `CreateLimitOrderRequest`,
+/// `CreateStopOrderRequest`), `OrderManager` and `ReplaceOrderRequest` check
+/// the order before sending it. Orders that can only reduce a position are
+/// always let through, as are the close, cancel, dependent order and client
+/// extension endpoints, so the kill switch stops new risk without trapping
+/// existing positions. `ConfigureAccountRequest` is blocked by the kill
+/// switch only.
+///
+/// `ReplaceOrderRequest` carries an untyped order; its instrument, units and
+/// price are read from the JSON, and a replacement they cannot be read from
+/// is refused while any limit is set. Account-level limits are evaluated
+/// against a fresh `AccountSummary`, unit limits against the current open
+/// position.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use std::sync::Arc;
+/// let guard = Arc::new(RiskGuard::new(
+///     RiskLimits::new()
+///         .with_allowed_instruments(vec!["EUR_USD".to_string()])
+///         .with_max_units("EUR_USD".to_string(), 100000.0)
+///         .with_max_open_trades(10)
+///         .with_max_margin_fraction(0.5)
+/// ));
+/// let client = Client::new("api-fxpractice.oanda.com".to_string(), "key".to_string())
+///     .with_risk_guard(guard.clone());
+/// // Later, from anywhere holding the guard:
+/// guard.engage_kill_switch();
+/// ```
+#[derive(Debug, Default)]
+pub struct RiskGuard {
+    limits: RiskLimits,
+    killed: AtomicBool,
+}
+
+impl RiskGuard {
+    pub fn new(limits: RiskLimits) -> RiskGuard {
+        RiskGuard {
+            limits,
+            killed: AtomicBool::new(false),
+        }
+    }
+
+    pub fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    /// Block every order that could add risk.
+    pub fn engage_kill_switch(&self) {
+        self.killed.store(true, Ordering::SeqCst);
+    }
+
+    pub fn release_kill_switch(&self) {
+        self.killed.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.killed.load(Ordering::SeqCst)
+    }
+
+    /// Check an order against the limits. `position` is the current net
+    /// units of the order's instrument; `summary` is only needed for the open
+    /// trade and margin limits.
+    pub fn check(
+        &self,
+        intent: &OrderIntent,
+        position: f32,
+        summary: Option<&AccountSummary>
+    ) -> Result<(), RiskError> {
+        if intent.reduce_only {
+            return Ok(());
+        }
+        if self.is_killed() {
+            return Err(RiskError::KillSwitch);
+        }
+        if self.limits.is_empty() {
+            return Ok(());
+        }
+        let limits = &self.limits;
+        let instrument = intent.instrument.clone().ok_or(RiskError::UnknownInstrument)?;
+        if let Some(ref allowed) = limits.allowed_instruments {
+            if !allowed.contains(&instrument) {
+                return Err(RiskError::InstrumentNotAllowed(instrument));
+            }
+        }
+        let units = intent.units.abs();
+        if let Some(&limit) = limits.max_units.get(&instrument) {
+            let resulting = position + intent.units;
+            if resulting.abs() > limit && resulting.abs() > position.abs() {
+                return Err(RiskError::Units { instrument, units: resulting, limit });
+            }
+        }
+        if let Some(&limit) = limits.max_notional.get(&instrument) {
+            let notional = units * intent.price.ok_or_else(|| RiskError::UnknownPrice(instrument.clone()))?;
+            if notional > limit {
+                return Err(RiskError::Notional { instrument, notional, limit });
+            }
+        }
+        if !limits.needs_summary() {
+            return Ok(());
+        }
+        let summary = summary.ok_or(RiskError::MissingAccountState("summary"))?;
+        if let Some(limit) = limits.max_open_trades {
+            let open = summary.open_trade_count.ok_or(RiskError::MissingAccountState("openTradeCount"))?;
+            if open >= limit {
+                return Err(RiskError::OpenTrades { open, limit });
+            }
+        }
+        if let Some(limit) = limits.max_margin_fraction {
+            let used = summary.margin_used.ok_or(RiskError::MissingAccountState("marginUsed"))?;
+            let nav = summary.nav.ok_or(RiskError::MissingAccountState("NAV"))?;
+            let fraction = if nav > 0.0 { used / nav } else { f32::INFINITY };
+            if fraction > limit {
+                return Err(RiskError::Margin { fraction, limit });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Client<Trading> {
+    /// Run the risk guard over a request about to be sent.
+    pub(crate) async fn guard(&self, account_id: &str, action: RiskAction) -> Result<(), Box<dyn Error>> {
+        let guard = self.risk_guard();
+        let mut intent = match action {
+            RiskAction::Reduce => {
+                return Ok(());
+            }
+            RiskAction::Configure | RiskAction::Order(None) if guard.is_killed() => {
+                return Err(Box::new(RiskError::KillSwitch));
+            }
+            RiskAction::Configure => {
+                return Ok(());
+            }
+            RiskAction::Order(None) if guard.limits.is_empty() => {
+                return Ok(());
+            }
+            RiskAction::Order(None) => {
+                return Err(Box::new(RiskError::UncheckedOrder));
+            }
+            RiskAction::Order(Some(x)) => x,
+        };
+        if intent.reduce_only || guard.is_killed() || guard.limits.is_empty() {
+            return Ok(guard.check(&intent, 0.0, None)?);
+        }
+        let instrument = intent.instrument.clone().unwrap_or_default();
+        if intent.price.is_none() && guard.limits.max_notional.contains_key(&instrument) {
+            intent.price = self.closeout_price(account_id, &instrument, intent.units).await?;
+        }
+        let position = if guard.limits.max_units.contains_key(&instrument) {
+            self.position_units(account_id, &instrument).await?
+        } else {
+            0.0
+        };
+        let summary = if guard.limits.needs_summary() {
+            GetAccountSummaryRequest::new()
+                .with_account_id(account_id.to_string())
+                .remote(self).await?
+                .account
+        } else {
+            None
+        };
+        Ok(guard.check(&intent, position, summary.as_ref())?)
+    }
+
+    /// The price an order for `units` would close out at now.
+    async fn closeout_price(
+        &self,
+        account_id: &str,
+        instrument: &str,
+        units: f32
+    ) -> Result<Option<f32>, Box<dyn Error>> {
+        let prices = GetPricesRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_instruments(instrument.to_string())
+            .remote(self).await?
+            .prices
+            .unwrap_or_default();
+        Ok(prices.into_iter().next().and_then(|p| if units < 0.0 { p.closeout_bid } else { p.closeout_ask }))
+    }
+
+    /// The net units of the open position in `instrument`, zero if none.
+    async fn position_units(&self, account_id: &str, instrument: &str) -> Result<f32, Box<dyn Error>> {
+        let positions = ListOpenPositionsRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(self).await?
+            .positions
+            .unwrap_or_default();
+        let side = |x: &Option<PositionSide>| x.as_ref().and_then(|s| s.units).unwrap_or(0.0);
+        Ok(positions
+            .iter()
+            .filter(|p| p.instrument.as_deref() == Some(instrument))
+            .map(|p| side(&p.long) + side(&p.short))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enforces_limits_and_kill_switch() {
+        let guard = RiskGuard::new(
+            RiskLimits::new()
+                .with_allowed_instruments(vec!["EUR_USD".to_string()])
+                .with_max_units("EUR_USD".to_string(), 1000.0)
+                .with_max_margin_fraction(0.5)
+        );
+        let summary = AccountSummary::new().with_nav(1000.0).with_margin_used(100.0);
+        let order = |instrument: &str, units: f32| OrderIntent::from(
+            &MarketOrderRequest::new().with_instrument(instrument.to_string()).with_units(units)
+        );
+        assert_eq!(guard.check(&order("EUR_USD", -500.0), 0.0, Some(&summary)), Ok(()));
+        assert_eq!(
+            guard.check(&order("GBP_USD", 1.0), 0.0, Some(&summary)),
+            Err(RiskError::InstrumentNotAllowed("GBP_USD".to_string()))
+        );
+        assert!(matches!(guard.check(&order("EUR_USD", 2000.0), 0.0, Some(&summary)), Err(RiskError::Units { .. })));
+        assert!(matches!(guard.check(&order("EUR_USD", 600.0), 600.0, Some(&summary)), Err(RiskError::Units { .. })));
+        assert_eq!(guard.check(&order("EUR_USD", -600.0), 1500.0, Some(&summary)), Ok(()));
+        let stretched = summary.clone().with_margin_used(600.0);
+        assert!(matches!(guard.check(&order("EUR_USD", 1.0), 0.0, Some(&stretched)), Err(RiskError::Margin { .. })));
+
+        guard.engage_kill_switch();
+        assert_eq!(guard.check(&order("EUR_USD", 1.0), 0.0, Some(&summary)), Err(RiskError::KillSwitch));
+        let close = MarketOrderRequest::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(-1.0)
+            .with_position_fill("REDUCE_ONLY".to_string());
+        assert_eq!(guard.check(&OrderIntent::from(&close), 0.0, None), Ok(()));
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn reads_replacement_orders() {
+        let order: OrderRequest = serde_json::from_str(
+            r#"{"type": "LIMIT", "instrument": "EUR_USD", "units": "-100", "price": "1.1"}"#
+        ).unwrap();
+        assert_eq!(
+            OrderIntent::from_replacement(&order),
+            Some(OrderIntent {
+                instrument: Some("EUR_USD".to_string()),
+                units: -100.0,
+                price: Some(1.1),
+                reduce_only: false,
+            })
+        );
+        let stop: OrderRequest = serde_json::from_str(r#"{"type": "STOP_LOSS", "tradeID": "1"}"#).unwrap();
+        assert!(OrderIntent::from_replacement(&stop).is_some_and(|x| x.reduce_only));
+        assert_eq!(OrderIntent::from_replacement(&OrderRequest::new()), None);
+    }
+}
@@ -0,0 +1,332 @@
+use chrono::prelude::*;
+use std::collections::HashMap;
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use crate::*;
+
+/// A rule for moving the stop loss of an open trade. Distances are in pips
+/// and always measured in the trade's favour.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopRule {
+    /// Once the trade is `trigger_pips` in profit, move the stop to the entry
+    /// price plus `offset_pips`.
+    BreakEven { trigger_pips: f32, offset_pips: f32 },
+    /// For every full `step_pips` of profit, move the stop up a step, keeping
+    /// it `distance_pips` behind the last step reached.
+    Step { step_pips: f32, distance_pips: f32 },
+    /// Once the trade has been open for `after`, keep the stop
+    /// `distance_pips` from the current price.
+    TimeLimit { after: Duration, distance_pips: f32 },
+}
+
+/// A stop loss change made, or attempted, by the `StopManager`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopAdjustment {
+    pub time: DateTime<Utc>,
+    pub trade_id: String,
+    pub instrument: String,
+    /// The rule that asked for the tightest stop.
+    pub rule: StopRule,
+    /// The price the trade would close at when the rule was evaluated.
+    pub price: f32,
+    pub previous_stop: Option<f32>,
+    pub stop: f32,
+    /// Why the change was refused, if it was.
+    pub error: Option<String>,
+}
+
+impl std::fmt::Display for StopAdjustment {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} trade {} {}: stop {:?} -> {} at price {} ({:?})",
+            self.time.to_rfc3339(),
+            self.trade_id,
+            self.instrument,
+            self.previous_stop,
+            self.stop,
+            self.price,
+            self.rule
+        )?;
+        match self.error {
+            Some(ref e) => write!(f, " failed: {}", e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Called with every `StopAdjustment`, whether it succeeded or not.
+pub type StopAuditHandler = fn(adjustment: &StopAdjustment);
+
+/// A `StopAuditHandler` that logs adjustments with the `log` crate: at info
+/// level, or at warn level when they failed.
+pub fn log_stop_adjustment(adjustment: &StopAdjustment) {
+    match adjustment.error {
+        Some(_) => log::warn!("{}", adjustment),
+        None => log::info!("{}", adjustment),
+    }
+}
+
+/// Moves the stop losses of an account's open trades according to a set of
+/// `StopRule`s.
+///
+/// Each `poll` fetches the open trades (`ListOpenTradesRequest`) and their
+/// closeout prices (`GetPricesRequest`), works out the tightest stop any rule
+/// asks for and, if it is tighter than the current stop, replaces the stop
+/// loss with `SetTradeDependentOrdersRequest`. Stops are only ever
+/// tightened, are rounded to the instrument's display precision and must be
+/// on the losing side of the current price. A trade is adjusted at most once
+/// per `min_interval`, and every adjustment is passed to the audit handler,
+/// which logs them with the `log` crate by default.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use std::time::Duration;
+/// # async fn manage(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut manager = StopManager::load(client, "101-004-1234567-001").await?
+///     .with_rule(StopRule::BreakEven { trigger_pips: 10.0, offset_pips: 1.0 })
+///     .with_rule(StopRule::Step { step_pips: 20.0, distance_pips: 15.0 })
+///     .with_rule(StopRule::TimeLimit { after: Duration::from_secs(4 * 3600), distance_pips: 5.0 });
+/// manager.run(client, Duration::from_secs(5)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StopManager {
+    account_id: AccountId,
+    instruments: OrderValidator,
+    rules: Vec<StopRule>,
+    min_interval: Duration,
+    audit: Option<StopAuditHandler>,
+    last_adjusted: HashMap<String, Instant>,
+}
+
+impl StopManager {
+    /// A manager with no rules, using instrument rules fetched elsewhere for
+    /// pip sizes and price precision.
+    pub fn new<A: Into<AccountId>>(account_id: A, instruments: OrderValidator) -> StopManager {
+        StopManager {
+            account_id: account_id.into(),
+            instruments,
+            rules: Vec::new(),
+            min_interval: Duration::from_secs(10),
+            audit: Some(log_stop_adjustment),
+            last_adjusted: HashMap::new(),
+        }
+    }
+
+    /// Fetch the account's instrument rules and create a manager with no rules.
+    pub async fn load<C, A: Into<AccountId>>(
+        client: &Client<C>,
+        account_id: A
+    ) -> Result<StopManager, Box<dyn Error>> {
+        let account_id = account_id.into();
+        let instruments = OrderValidator::load(client, account_id.clone()).await?;
+        Ok(StopManager::new(account_id, instruments))
+    }
+
+    pub fn with_rule(mut self, x: StopRule) -> Self {
+        self.rules.push(x);
+        self
+    }
+
+    /// The shortest time between two adjustments of the same trade. Defaults
+    /// to ten seconds.
+    pub fn with_min_interval(mut self, x: Duration) -> Self {
+        self.min_interval = x;
+        self
+    }
+
+    /// Where adjustments are reported, `None` to turn auditing off. Defaults
+    /// to `log_stop_adjustment`.
+    pub fn with_audit(mut self, x: Option<StopAuditHandler>) -> Self {
+        self.audit = x;
+        self
+    }
+
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    /// The stop the rules ask for, if it is tighter than the trade's current
+    /// stop. `price` is the price the trade would close at: the bid for a
+    /// long trade, the ask for a short one.
+    pub fn target(&self, trade: &Trade, price: f32, now: DateTime<Utc>) -> Option<(StopRule, f32)> {
+        let instrument = trade.instrument.as_deref()?;
+        let pip = 10f32.powi(self.instruments.instrument(instrument)?.pip_location?);
+        let entry = trade.price?;
+        let units = trade.current_units?;
+        if units == 0.0 {
+            return None;
+        }
+        let direction = units.signum();
+        let profit_pips = (price - entry) * direction / pip;
+        let age = trade.open_time.and_then(|t| (now - t).to_std().ok());
+
+        let mut best: Option<(StopRule, f32)> = None;
+        for rule in &self.rules {
+            let stop = match *rule {
+                StopRule::BreakEven { trigger_pips, offset_pips } if profit_pips >= trigger_pips => {
+                    entry + direction * offset_pips * pip
+                }
+                StopRule::Step { step_pips, distance_pips } if step_pips > 0.0 && profit_pips >= step_pips => {
+                    let steps = (profit_pips / step_pips).floor();
+                    entry + direction * (steps * step_pips - distance_pips) * pip
+                }
+                StopRule::TimeLimit { after, distance_pips } if age.is_some_and(|x| x >= after) => {
+                    price - direction * distance_pips * pip
+                }
+                _ => continue,
+            };
+            let stop = self.instruments.round_price(instrument, stop);
+            if best.as_ref().is_none_or(|(_, b)| (stop - b) * direction > 0.0) {
+                best = Some((rule.clone(), stop));
+            }
+        }
+
+        let (rule, stop) = best?;
+        let current = trade.stop_loss_order.as_ref().and_then(|o| o.price);
+        let tighter = current.is_none_or(|c| (stop - c) * direction > 0.0);
+        let below_price = (price - stop) * direction > 0.0;
+        if tighter && below_price {
+            Some((rule, stop))
+        } else {
+            None
+        }
+    }
+
+    // Drop the adjustment times of trades that are no longer open.
+    fn forget_closed(&mut self, trades: &[Trade]) {
+        self.last_adjusted.retain(|id, _| trades.iter().any(|t| t.id.as_ref() == Some(id)));
+    }
+
+    /// Check every open trade once and apply the stops the rules ask for.
+    pub async fn poll(&mut self, client: &Client<Trading>) -> Result<Vec<StopAdjustment>, Box<dyn Error>> {
+        let trades = ListOpenTradesRequest::new()
+            .with_account_id(self.account_id.to_string())
+            .remote(client).await?
+            .trades
+            .unwrap_or_default();
+        self.forget_closed(&trades);
+        let mut names: Vec<&str> = trades.iter().filter_map(|t| t.instrument.as_deref()).collect();
+        names.sort_unstable();
+        names.dedup();
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+        let prices = GetPricesRequest::new()
+            .with_account_id(self.account_id.to_string())
+            .with_instruments(names.join(","))
+            .remote(client).await?
+            .prices
+            .unwrap_or_default();
+
+        let now = Utc::now();
+        let mut adjustments = Vec::new();
+        for trade in &trades {
+            let (id, instrument) = match (trade.id.as_ref(), trade.instrument.as_ref()) {
+                (Some(id), Some(instrument)) => (id, instrument),
+                _ => continue,
+            };
+            if self.last_adjusted.get(id).is_some_and(|t| t.elapsed() < self.min_interval) {
+                continue;
+            }
+            let quote = prices.iter().find(|p| p.instrument.as_ref() == Some(instrument));
+            let price = match quote {
+                Some(p) if trade.current_units.unwrap_or(0.0) > 0.0 => p.closeout_bid,
+                Some(p) => p.closeout_ask,
+                None => None,
+            };
+            let (rule, stop) = match price.and_then(|p| self.target(trade, p, now)) {
+                Some(x) => x,
+                None => continue,
+            };
+
+            let mut details = StopLossDetails::new().with_price(stop);
+            if let Some(ref current) = trade.stop_loss_order {
+                details.time_in_force = current.time_in_force.clone();
+                details.gtd_time = current.gtd_time;
+                details.client_extensions = current.client_extensions.clone();
+                details.guaranteed = current.guaranteed;
+            }
+            let result = SetTradeDependentOrdersRequest::new()
+                .with_account_id(self.account_id.to_string())
+                .with_trade_specifier(id.clone())
+                .with_stop_loss(details)
+                .remote(client).await;
+            let error = match result {
+                Ok(ref x) if x.stop_loss_order_transaction.is_some() => None,
+                Ok(_) => Some("stop loss was not replaced".to_string()),
+                Err(e) => Some(e.to_string()),
+            };
+            self.last_adjusted.insert(id.clone(), Instant::now());
+            let adjustment = StopAdjustment {
+                time: now,
+                trade_id: id.clone(),
+                instrument: instrument.clone(),
+                rule,
+                price: price.unwrap_or_default(),
+                previous_stop: trade.stop_loss_order.as_ref().and_then(|o| o.price),
+                stop,
+                error,
+            };
+            if let Some(audit) = self.audit {
+                audit(&adjustment);
+            }
+            adjustments.push(adjustment);
+        }
+        Ok(adjustments)
+    }
+
+    /// Poll every `interval` until an error occurs.
+    pub async fn run(&mut self, client: &Client<Trading>, interval: Duration) -> Result<(), Box<dyn Error>> {
+        loop {
+            self.poll(client).await?;
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tightens_stops_by_rule() {
+        let instruments = OrderValidator::from_instruments(
+            vec![Instrument::new().with_name("EUR_USD".to_string()).with_pip_location(-4).with_display_precision(5)]
+        );
+        let manager = StopManager::new("101-004-1234567-001", instruments)
+            .with_rule(StopRule::BreakEven { trigger_pips: 10.0, offset_pips: 1.0 })
+            .with_rule(StopRule::Step { step_pips: 20.0, distance_pips: 15.0 });
+        let now = Utc::now();
+        let long = Trade::new()
+            .with_id("1".to_string())
+            .with_instrument("EUR_USD".to_string())
+            .with_price(1.1)
+            .with_current_units(1000.0)
+            .with_stop_loss_order(StopLossOrder::new().with_price(1.095));
+
+        assert_eq!(manager.target(&long, 1.1005, now), None);
+        let (rule, stop) = manager.target(&long, 1.1012, now).unwrap();
+        assert!(matches!(rule, StopRule::BreakEven { .. }));
+        assert_eq!(stop, 1.1001);
+        // Two steps of profit beat break-even.
+        assert_eq!(manager.target(&long, 1.1041, now).unwrap().1, 1.1025);
+
+        let short = long.clone().with_current_units(-1000.0).with_stop_loss_order(StopLossOrder::new().with_price(1.0999));
+        assert_eq!(manager.target(&short, 1.0988, now), None);
+        assert_eq!(manager.target(&short, 1.0975, now).unwrap().1, 1.0995);
+    }
+    #[test]
+    fn forgets_closed_trades() {
+        let mut manager = StopManager::new("101-004-1234567-001", OrderValidator::from_instruments(Vec::new()));
+        manager.last_adjusted.insert("1".to_string(), Instant::now());
+        manager.last_adjusted.insert("2".to_string(), Instant::now());
+        manager.forget_closed(&[Trade::new().with_id("2".to_string())]);
+        assert_eq!(manager.last_adjusted.keys().collect::<Vec<_>>(), vec!["2"]);
+        manager.forget_closed(&[]);
+        assert!(manager.last_adjusted.is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::prelude::*;
+use fs2::FileExt;
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+use crate::*;
+
+/// Identifies one stored candle series.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CandleKey {
+    pub instrument: String,
+    pub granularity: CandlestickGranularity,
+    pub component: PriceComponent,
+}
+
+impl CandleKey {
+    pub fn new(
+        instrument: String,
+        granularity: CandlestickGranularity,
+        component: PriceComponent
+    ) -> CandleKey {
+        CandleKey {
+            instrument,
+            granularity,
+            component,
+        }
+    }
+}
+
+/// An on-disk cache of complete candles.
+///
+/// Every series is kept in its own append-only file,
+/// `<root>/<instrument>/<granularity>/<component>.csv`, holding one
+/// `time,open,high,low,close,volume` line per complete bar. Only complete
+/// bars are stored so nothing ever needs rewriting; a line cut short by a
+/// crash is dropped the next time the file is loaded or appended to.
+///
+/// Instrument names must be made of `A-Z`, `0-9` and `_` so they cannot
+/// point outside the root. Appends take an exclusive lock on the series file
+/// and loads a shared one, so several processes can sync the same store.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # use chrono::prelude::*;
+/// # async fn research(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let store = CandleStore::open("candles")?;
+/// let key = CandleKey::new("EUR_USD".to_string(), CandlestickGranularity::M1, PriceComponent::Mid);
+/// store.sync(client, &key, Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()).await?;
+/// let series = store.load(&key)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleStore {
+    root: PathBuf,
+}
+
+impl CandleStore {
+    /// Open (creating if needed) a store rooted at `root`.
+    pub fn open<P: Into<PathBuf>>(root: P) -> io::Result<CandleStore> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(CandleStore { root })
+    }
+
+    fn path(&self, key: &CandleKey) -> io::Result<PathBuf> {
+        let valid = |c: char| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_';
+        if key.instrument.is_empty() || !key.instrument.chars().all(valid) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid instrument name {:?}", key.instrument)
+            ));
+        }
+        Ok(self.root
+            .join(&key.instrument)
+            .join(key.granularity.as_str())
+            .join(format!("{}.csv", key.component)))
+    }
+
+    // The complete lines of a series file and the length they occupy.
+    fn read(&self, key: &CandleKey) -> io::Result<(String, usize)> {
+        let mut file = match File::open(self.path(key)?) {
+            Ok(x) => x,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok((String::new(), 0)),
+            Err(e) => return Err(e),
+        };
+        FileExt::lock_shared(&file)?;
+        read_complete(&mut file)
+    }
+
+    /// Every stored bar of a series.
+    pub fn load(&self, key: &CandleKey) -> io::Result<CandleSeries> {
+        let (contents, end) = self.read(key)?;
+        let mut series = CandleSeries::new(key.granularity);
+        for line in contents[..end].lines().filter(|line| !line.is_empty()) {
+            series.insert(parse_line(line)?);
+        }
+        Ok(series)
+    }
+
+    /// The time of the newest stored bar of a series.
+    pub fn last_time(&self, key: &CandleKey) -> io::Result<Option<DateTime<Utc>>> {
+        let (contents, end) = self.read(key)?;
+        match contents[..end].lines().rfind(|line| !line.is_empty()) {
+            Some(line) => Ok(Some(parse_line(line)?.time)),
+            None => Ok(None),
+        }
+    }
+
+    /// Append the complete bars newer than the newest stored bar and return
+    /// how many were written.
+    pub fn append(&self, key: &CandleKey, bars: &[Ohlc]) -> io::Result<usize> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).read(true).append(true).open(&path)?;
+        FileExt::lock_exclusive(&file)?;
+        let (contents, end) = read_complete(&mut file)?;
+        let mut last = match contents[..end].lines().rfind(|line| !line.is_empty()) {
+            Some(line) => Some(parse_line(line)?.time),
+            None => None,
+        };
+        if end < contents.len() {
+            file.set_len(end as u64)?;
+        }
+        let mut out = String::new();
+        let mut written = 0;
+        for bar in bars.iter().filter(|bar| bar.complete) {
+            if last.is_some_and(|t| bar.time <= t) {
+                continue;
+            }
+            out.push_str(&format_line(bar));
+            last = Some(bar.time);
+            written += 1;
+        }
+        file.write_all(out.as_bytes())?;
+        file.sync_data()?;
+        Ok(written)
+    }
+
+    /// Fetch and store the complete candles newer than the newest stored bar,
+    /// or newer than `since` if the series is empty. Returns how many bars
+    /// were added.
+    pub async fn sync<C>(
+        &self,
+        client: &Client<C>,
+        key: &CandleKey,
+        since: DateTime<Utc>
+    ) -> Result<usize, Box<dyn Error>> {
+        let from = self.last_time(key)?.unwrap_or(since);
+        let candles = CandleHistory::new(key.instrument.clone(), key.granularity)
+            .with_price(key.component.to_string())
+            .with_from(from)
+            .fetch(client).await?;
+        let mut bars = Vec::with_capacity(candles.len());
+        for candle in candles.iter().filter(|c| c.complete == Some(true)) {
+            bars.push(Ohlc::from_candle(candle, key.component)?);
+        }
+        Ok(self.append(key, &bars)?)
+    }
+}
+
+fn read_complete(file: &mut File) -> io::Result<(String, usize)> {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    let end = contents.rfind('\n').map_or(0, |i| i + 1);
+    Ok((contents, end))
+}
+
+fn format_line(bar: &Ohlc) -> String {
+    format!(
+        "{},{},{},{},{},{}\n",
+        bar.time.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        bar.open,
+        bar.high,
+        bar.low,
+        bar.close,
+        bar.volume
+    )
+}
+
+fn parse_line(line: &str) -> io::Result<Ohlc> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("bad candle line {:?}", line));
+    let fields: Vec<&str> = line.split(',').collect();
+    if fields.len() != 6 {
+        return Err(invalid());
+    }
+    let price = |i: usize| fields[i].parse::<f32>().map_err(|_| invalid());
+    Ok(Ohlc {
+        time: DateTime::parse_from_rfc3339(fields[0]).map_err(|_| invalid())?.with_timezone(&Utc),
+        open: price(1)?,
+        high: price(2)?,
+        low: price(3)?,
+        close: price(4)?,
+        volume: fields[5].parse::<i32>().map_err(|_| invalid())?,
+        complete: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_only_new_complete_bars() {
+        let root = std::env::temp_dir().join(format!("fxoanda-store-{}", std::process::id()));
+        let store = CandleStore::open(&root).unwrap();
+        let key = CandleKey::new("EUR_USD".to_string(), CandlestickGranularity::M1, PriceComponent::Bid);
+        let bar = |m: u32, complete: bool| Ohlc {
+            time: Utc.with_ymd_and_hms(2020, 1, 6, 0, m, 0).unwrap(),
+            open: 1.10001,
+            high: 1.1002,
+            low: 1.0999,
+            close: 1.1001,
+            volume: 7,
+            complete,
+        };
+        assert_eq!(store.append(&key, &[bar(0, true), bar(1, true), bar(2, false)]).unwrap(), 2);
+        assert_eq!(store.append(&key, &[bar(1, true), bar(2, true)]).unwrap(), 1);
+        let series = store.load(&key).unwrap();
+        assert_eq!(series.bars(), &[bar(0, true), bar(1, true), bar(2, true)]);
+        assert_eq!(store.last_time(&key).unwrap(), Some(bar(2, true).time));
+
+        let outside = CandleKey::new("../EUR_USD".to_string(), CandlestickGranularity::M1, PriceComponent::Bid);
+        assert_eq!(store.append(&outside, &[bar(0, true)]).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
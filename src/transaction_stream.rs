@@ -0,0 +1,120 @@
+use std::error::Error;
+
+use crate::*;
+
+/// Reads an account's transaction stream as transactions happen.
+///
+/// `StreamTransactionsRequest` waits for the whole response body, which a
+/// stream never finishes, so the stream is read here one line at a time.
+/// Heartbeats are skipped. The stream host is the client's host with `api-`
+/// replaced by `stream-`, e.g. `stream-fxpractice.oanda.com`.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn watch(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = TransactionStream::connect(client, "101-004-1234567-001").await?;
+/// while let Some(transaction) = stream.next().await? {
+///     println!("{:?} {:?}", transaction.id, transaction.otype);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TransactionStream {
+    client: Client<ReadOnly>,
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+}
+
+impl TransactionStream {
+    /// Open the transaction stream of `account_id`.
+    pub async fn connect<C, A: Into<AccountId>>(
+        client: &Client<C>,
+        account_id: A
+    ) -> Result<TransactionStream, Box<dyn Error>> {
+        let uri = format!("/v3/accounts/{}/transactions/stream", account_id.into());
+        let response = open_stream(client, &uri, &[]).await?;
+        Ok(TransactionStream {
+            client: client.to_read_only(),
+            response,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// The next transaction, or `None` once the server closes the stream.
+    pub async fn next(&mut self) -> Result<Option<Transaction>, Box<dyn Error>> {
+        while let Some(line) = next_line(&mut self.response, &mut self.buffer).await? {
+            if let Some(x) = parse_line(&self.client, &line)? {
+                return Ok(Some(x));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// Open a streaming endpoint on the stream host.
+pub(crate) async fn open_stream<C>(
+    client: &Client<C>,
+    uri: &str,
+    query: &[(&str, String)]
+) -> Result<reqwest::Response, Box<dyn Error>> {
+    let url = format!("https://{}{}", stream_host(client.host()), uri);
+    let response = client.request(reqwest::Method::GET, &url).query(query).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(Box::new(ApiError::new(client.environment(), status.as_u16(), &response.bytes().await?)));
+    }
+    Ok(response)
+}
+
+// The next line of a stream, or `None` once the server closes it.
+pub(crate) async fn next_line(
+    response: &mut reqwest::Response,
+    buffer: &mut Vec<u8>
+) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    loop {
+        if let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+            return Ok(Some(buffer.drain(..=end).collect()));
+        }
+        match response.chunk().await? {
+            Some(chunk) => buffer.extend_from_slice(&chunk),
+            None => return Ok(None),
+        }
+    }
+}
+
+// A transaction line, or `None` for a heartbeat or an empty line.
+fn parse_line(client: &Client<ReadOnly>, line: &[u8]) -> Result<Option<Transaction>, Box<dyn Error>> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let heartbeat: TransactionHeartbeat = serde_json::from_slice(line)?;
+    if heartbeat.otype.as_deref() == Some("HEARTBEAT") {
+        return Ok(None);
+    }
+    client.decode_slice(line)
+}
+
+fn stream_host(host: &str) -> String {
+    match host.strip_prefix("api-") {
+        Some(rest) => format!("stream-{}", rest),
+        None => host.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_heartbeats() {
+        let client = Client::new_read_only("api-fxpractice.oanda.com".to_string(), String::new());
+        let heartbeat = br#"{"type":"HEARTBEAT","lastTransactionID":"6","time":"2020-01-06T00:00:00.000000000Z"}"#;
+        assert_eq!(parse_line(&client, heartbeat).unwrap(), None);
+        assert_eq!(parse_line(&client, b"\r\n").unwrap(), None);
+        let fill = parse_line(&client, br#"{"id":"7","type":"ORDER_FILL","orderID":"6"}"#).unwrap().unwrap();
+        assert_eq!((fill.otype.as_deref(), fill.order_id.as_deref()), (Some("ORDER_FILL"), Some("6")));
+        assert_eq!(stream_host(client.host()), "stream-fxpractice.oanda.com");
+    }
+}
@@ -0,0 +1,330 @@
+pub mod get_user_info {
+    #[allow(unused_imports)]
+    use chrono::prelude::*;
+    #[allow(unused_imports)]
+    use fxoanda_definitions::*;
+    use std::error::Error;
+    use crate::Client;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestHead {
+        #[serde(rename = "Authorization", skip_serializing_if = "Option::is_none")]
+        pub authorization: Option<String>,
+    }
+    impl RequestHead {
+        fn new() -> RequestHead {
+            RequestHead {
+                authorization: None,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestPath {
+        #[serde(rename = "userSpecifier", skip_serializing_if = "Option::is_none")]
+        pub user_specifier: Option<String>,
+    }
+    impl RequestPath {
+        fn new() -> RequestPath {
+            RequestPath { user_specifier: None }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestBody {}
+    impl RequestBody {
+        fn new() -> RequestBody {
+            RequestBody {}
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestQuery {}
+    impl RequestQuery {
+        fn new() -> RequestQuery {
+            RequestQuery {}
+        }
+    }
+
+    /// User Info
+    /// Fetch the user information for the specified user. This endpoint is
+    /// intended to be used by the user themself to obtain their own
+    /// information.
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct GetUserInfoRequest {
+        #[serde(skip_serializing)]
+        uri: String,
+        header: RequestHead,
+        body: RequestBody,
+        path: RequestPath,
+        query: RequestQuery,
+    }
+
+    impl GetUserInfoRequest {
+        pub fn new() -> GetUserInfoRequest {
+            GetUserInfoRequest {
+                uri: String::from("/v3/users/{userSpecifier}"),
+                header: RequestHead::new(),
+                body: RequestBody::new(),
+                path: RequestPath::new(),
+                query: RequestQuery::new(),
+            }
+        }
+        pub fn with_uri(mut self, x: String) -> Self {
+            self.uri = x;
+            self
+        }
+
+        /// The User Specifier
+        /// format: A user specifier may have one of 3 formats: the OANDA-assigned User ID
+        /// (numerical), the client-provided username prefixed by the "@" symbol
+        /// (e.g. "@myusername"), or the "@" symbol. The "@" symbol on its own
+        /// acts as an alias for the username of the user accessing the endpoint
+        /// (as inferred from the token provided).
+        /// - param String
+        /// - return GetUserInfoRequest
+        pub fn with_user_specifier(mut self, x: String) -> Self {
+            self.path.user_specifier = Some(x);
+            self
+        }
+
+        /// The authorization bearer token previously obtained by the client
+        /// format: The string 'Bearer ' followed by the token.
+        /// - param String
+        /// - return GetUserInfoRequest
+        pub fn with_authorization(mut self, x: String) -> Self {
+            self.header.authorization = Some(x);
+            self
+        }
+
+        pub async fn remote<C>(
+            self,
+            client: &Client<C>
+        ) -> Result<GetUserInfoResponse, Box<dyn Error>> {
+            let uri = self.uri
+                .clone()
+                .replace("{userSpecifier}", &self.path.user_specifier.unwrap());
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
+                .query(&self.query)
+                .send().await;
+            match res {
+                Err(e) => Err(Box::new(e)),
+                Ok(response) => client.decode::<GetUserInfoResponse>(response).await,
+            }
+        }
+    }
+
+    impl Default for GetUserInfoRequest {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub type GetUserInfoResponse = GetUserInfoResponse200Body;
+
+    /// The user information has been provided
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct GetUserInfoResponse200Header {
+        /// The unique identifier generated for the request
+        #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
+        pub request_id: Option<String>,
+    }
+
+    /// The user information has been provided
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct GetUserInfoResponse200Body {
+        /// A representation of user information, as provided to the user
+        /// themself.
+        #[serde(rename = "userInfo", skip_serializing_if = "Option::is_none")]
+        pub user_info: Option<UserInfo>,
+    }
+}
+
+pub mod get_external_user_info {
+    #[allow(unused_imports)]
+    use chrono::prelude::*;
+    #[allow(unused_imports)]
+    use fxoanda_definitions::*;
+    use std::error::Error;
+    use crate::Client;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestHead {
+        #[serde(rename = "Authorization", skip_serializing_if = "Option::is_none")]
+        pub authorization: Option<String>,
+    }
+    impl RequestHead {
+        fn new() -> RequestHead {
+            RequestHead {
+                authorization: None,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestPath {
+        #[serde(rename = "userSpecifier", skip_serializing_if = "Option::is_none")]
+        pub user_specifier: Option<String>,
+    }
+    impl RequestPath {
+        fn new() -> RequestPath {
+            RequestPath { user_specifier: None }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestBody {}
+    impl RequestBody {
+        fn new() -> RequestBody {
+            RequestBody {}
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct RequestQuery {}
+    impl RequestQuery {
+        fn new() -> RequestQuery {
+            RequestQuery {}
+        }
+    }
+
+    /// External User Info
+    /// Fetch the externally-available user information for the specified
+    /// user. This endpoint is intended to be used by 3rd parties that have
+    /// been authorized by a user to view their personal information.
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct GetExternalUserInfoRequest {
+        #[serde(skip_serializing)]
+        uri: String,
+        header: RequestHead,
+        body: RequestBody,
+        path: RequestPath,
+        query: RequestQuery,
+    }
+
+    impl GetExternalUserInfoRequest {
+        pub fn new() -> GetExternalUserInfoRequest {
+            GetExternalUserInfoRequest {
+                uri: String::from("/v3/users/{userSpecifier}/externalInfo"),
+                header: RequestHead::new(),
+                body: RequestBody::new(),
+                path: RequestPath::new(),
+                query: RequestQuery::new(),
+            }
+        }
+        pub fn with_uri(mut self, x: String) -> Self {
+            self.uri = x;
+            self
+        }
+
+        /// The User Specifier
+        /// format: A user specifier may have one of 3 formats: the OANDA-assigned User ID
+        /// (numerical), the client-provided username prefixed by the "@" symbol
+        /// (e.g. "@myusername"), or the "@" symbol. The "@" symbol on its own
+        /// acts as an alias for the username of the user accessing the endpoint
+        /// (as inferred from the token provided).
+        /// - param String
+        /// - return GetExternalUserInfoRequest
+        pub fn with_user_specifier(mut self, x: String) -> Self {
+            self.path.user_specifier = Some(x);
+            self
+        }
+
+        /// The authorization bearer token previously obtained by the client
+        /// format: The string 'Bearer ' followed by the token.
+        /// - param String
+        /// - return GetExternalUserInfoRequest
+        pub fn with_authorization(mut self, x: String) -> Self {
+            self.header.authorization = Some(x);
+            self
+        }
+
+        pub async fn remote<C>(
+            self,
+            client: &Client<C>
+        ) -> Result<GetExternalUserInfoResponse, Box<dyn Error>> {
+            let uri = self.uri
+                .clone()
+                .replace("{userSpecifier}", &self.path.user_specifier.unwrap());
+            let url = format!("https://{host}{uri}", host = client.host(), uri = uri);
+            let res = client
+                .request(reqwest::Method::GET, &url)
+                .query(&self.query)
+                .send().await;
+            match res {
+                Err(e) => Err(Box::new(e)),
+                Ok(response) => client.decode::<GetExternalUserInfoResponse>(response).await,
+            }
+        }
+    }
+
+    impl Default for GetExternalUserInfoRequest {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub type GetExternalUserInfoResponse = GetExternalUserInfoResponse200Body;
+
+    /// The external user information has been provided
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct GetExternalUserInfoResponse200Header {
+        /// The unique identifier generated for the request
+        #[serde(rename = "RequestID", skip_serializing_if = "Option::is_none")]
+        pub request_id: Option<String>,
+    }
+
+    /// The external user information has been provided
+    #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+    pub struct GetExternalUserInfoResponse200Body {
+        /// A representation of user information, as available to external (3rd
+        /// party) clients.
+        #[serde(rename = "userInfo", skip_serializing_if = "Option::is_none")]
+        pub user_info: Option<UserInfoExternal>,
+    }
+}
+
+pub use get_external_user_info::*;
+pub use get_user_info::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fxoanda_definitions::*;
+
+    // The body parses into `T` and serializes back to the same JSON.
+    fn round_trip<T>(body: &str) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let parsed: T = serde_json::from_str(body).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), expected);
+        parsed
+    }
+
+    #[test]
+    fn user_info_round_trips() {
+        let response: GetUserInfoResponse = round_trip(
+            r#"{"userInfo":{"username":"trader","userID":1234567,"country":"CA","emailAddress":"trader@example.com"}}"#
+        );
+        let expected = UserInfo::new()
+            .with_username("trader".to_string())
+            .with_user_id(1234567)
+            .with_country("CA".to_string())
+            .with_email_address("trader@example.com".to_string());
+        assert_eq!(response.user_info, Some(expected));
+    }
+
+    #[test]
+    fn external_user_info_round_trips() {
+        let response: GetExternalUserInfoResponse =
+            round_trip(r#"{"userInfo":{"userID":1234567,"country":"CA","FIFO":true}}"#);
+        let expected = UserInfoExternal::new().with_user_id(1234567).with_country("CA".to_string()).with_fifo(true);
+        assert_eq!(response.user_info, Some(expected));
+    }
+}
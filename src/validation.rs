@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::*;
+
+/// One way an order breaks the rules of its instrument.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// The order field at fault, e.g. "units" or "stopLossOnFill.distance".
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Every `Violation` found in an order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let messages: Vec<String> = self.violations.iter().map(|v| v.to_string()).collect();
+        write!(f, "invalid order: {}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// The fields of an order that the `OrderValidator` checks and rounds.
+pub struct OrderFields<'a> {
+    pub instrument: Option<&'a str>,
+    pub units: &'a mut Option<f32>,
+    /// The order's own prices, e.g. the limit price or price bound.
+    pub prices: Vec<(&'static str, &'a mut Option<f32>)>,
+    pub take_profit: &'a mut Option<TakeProfitDetails>,
+    pub stop_loss: &'a mut Option<StopLossDetails>,
+    pub trailing_stop: &'a mut Option<TrailingStopLossDetails>,
+}
+
+/// An order the `OrderValidator` can check.
+pub trait ValidatedOrder {
+    fn fields(&mut self) -> OrderFields<'_>;
+}
+
+macro_rules! validated_order {
+    ($($order:ident [$($price:ident: $name:expr),*]),*) => {
+      $(
+        impl ValidatedOrder for $order {
+          fn fields(&mut self) -> OrderFields<'_> {
+            OrderFields {
+              instrument: self.instrument.as_deref(),
+              units: &mut self.units,
+              prices: vec![$(($name, &mut self.$price)),*],
+              take_profit: &mut self.take_profit_on_fill,
+              stop_loss: &mut self.stop_loss_on_fill,
+              trailing_stop: &mut self.trailing_stop_loss_on_fill,
+            }
+          }
+        }
+      )*
+    };
+}
+
+validated_order!(MarketOrder [price_bound: "priceBound"],
+                 LimitOrder [price: "price"],
+                 StopOrder [price: "price", price_bound: "priceBound"],
+                 MarketOrderRequest [price_bound: "priceBound"],
+                 LimitOrderRequest [price: "price"]);
+
+impl ValidatedOrder for BracketRequest {
+    fn fields(&mut self) -> OrderFields<'_> {
+        match self {
+            BracketRequest::Market(x) => x.fields(),
+            BracketRequest::Limit(x) => x.fields(),
+        }
+    }
+}
+
+/// Checks orders against the instrument rules of an account before they are
+/// sent.
+///
+/// The rules come from `GetAccountInstrumentsRequest` and are kept until
+/// `refresh`. `validate` rounds prices and distances to the instrument's
+/// `displayPrecision` and truncates units towards zero to its
+/// `tradeUnitsPrecision`, then reports everything that would still be
+/// rejected: unknown instruments, units outside the minimum trade size,
+/// maximum order units or maximum position size, non-positive prices and
+/// trailing stop distances outside the allowed range. The position size
+/// check looks at the order alone, not at the units already held.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// # async fn send(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+/// let validator = OrderValidator::load(client, "101-004-1234567-001").await?;
+/// let mut order = BracketOrder::limit("EUR_USD".to_string(), 1000.0, 1.123456)
+///     .with_stop_loss(ExitLevel::Distance(0.00251))
+///     .build()?;
+/// validator.validate(&mut order)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderValidator {
+    instruments: HashMap<String, Instrument>,
+}
+
+impl OrderValidator {
+    /// Fetch the instrument rules of an account.
+    pub async fn load<C, A: Into<AccountId>>(
+        client: &Client<C>,
+        account_id: A
+    ) -> Result<OrderValidator, Box<dyn Error>> {
+        let account_id = account_id.into();
+        let response = GetAccountInstrumentsRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client).await?;
+        match response.instruments {
+            Some(instruments) => Ok(OrderValidator::from_instruments(instruments)),
+            None => Err(format!("no instruments returned for {}", account_id).into()),
+        }
+    }
+
+    /// Use instrument rules fetched elsewhere.
+    pub fn from_instruments(instruments: Vec<Instrument>) -> OrderValidator {
+        OrderValidator {
+            instruments: instruments
+                .into_iter()
+                .filter_map(|i| i.name.clone().map(|name| (name, i)))
+                .collect(),
+        }
+    }
+
+    /// Fetch the instrument rules again.
+    pub async fn refresh<C, A: Into<AccountId>>(
+        &mut self,
+        client: &Client<C>,
+        account_id: A
+    ) -> Result<(), Box<dyn Error>> {
+        *self = OrderValidator::load(client, account_id).await?;
+        Ok(())
+    }
+
+    pub fn instrument(&self, name: &str) -> Option<&Instrument> {
+        self.instruments.get(name)
+    }
+
+    /// `price` rounded to the instrument's display precision.
+    pub fn round_price(&self, instrument: &str, price: f32) -> f32 {
+        match self.instrument(instrument).and_then(|i| i.display_precision) {
+            Some(digits) => round_to(price, digits),
+            None => price,
+        }
+    }
+
+    /// `units` truncated towards zero to the instrument's trade units
+    /// precision.
+    pub fn round_units(&self, instrument: &str, units: f32) -> f32 {
+        match self.instrument(instrument).and_then(|i| i.trade_units_precision) {
+            Some(digits) => {
+                let scale = 10f64.powi(digits);
+                ((units as f64 * scale).trunc() / scale) as f32
+            }
+            None => units,
+        }
+    }
+
+    fn check_price(
+        &self,
+        instrument: &str,
+        violations: &mut Vec<Violation>,
+        field: &'static str,
+        value: &mut Option<f32>
+    ) {
+        if let Some(x) = *value {
+            let rounded = self.round_price(instrument, x);
+            *value = Some(rounded);
+            if !(rounded > 0.0 && rounded.is_finite()) {
+                fail(violations, field, format!("{} is not a positive price", x));
+            }
+        }
+    }
+
+    /// Round the order in place and check it.
+    pub fn validate<O: ValidatedOrder>(&self, order: &mut O) -> Result<(), ValidationError> {
+        let fields = order.fields();
+        let mut violations = Vec::new();
+
+        let name = match fields.instrument {
+            Some(x) => x,
+            None => {
+                fail(&mut violations, "instrument", "missing".to_string());
+                return Err(ValidationError { violations });
+            }
+        };
+        let rules = match self.instrument(name) {
+            Some(x) => x,
+            None => {
+                fail(&mut violations, "instrument", format!("{} is not tradeable in this account", name));
+                return Err(ValidationError { violations });
+            }
+        };
+
+        match *fields.units {
+            None => fail(&mut violations, "units", "missing".to_string()),
+            Some(units) => {
+                let rounded = self.round_units(name, units);
+                *fields.units = Some(rounded);
+                let size = rounded.abs();
+                if size == 0.0 {
+                    let digits = rules.trade_units_precision.unwrap_or(0);
+                    fail(&mut violations, "units", format!("{} rounds to zero at {} decimals", units, digits));
+                }
+                if let Some(min) = rules.minimum_trade_size {
+                    if size > 0.0 && size < min {
+                        fail(&mut violations, "units", format!("{} is below the minimum trade size {}", size, min));
+                    }
+                }
+                // A maximum of zero means no limit.
+                if let Some(max) = rules.maximum_order_units.filter(|x| *x > 0.0) {
+                    if size > max {
+                        fail(&mut violations, "units", format!("{} is above the maximum order units {}", size, max));
+                    }
+                }
+                if let Some(max) = rules.maximum_position_size.filter(|x| *x > 0.0) {
+                    if size > max {
+                        fail(&mut violations, "units", format!("{} is above the maximum position size {}", size, max));
+                    }
+                }
+            }
+        }
+
+        for (field, value) in fields.prices {
+            self.check_price(name, &mut violations, field, value);
+        }
+        if let Some(ref mut tp) = fields.take_profit {
+            self.check_price(name, &mut violations, "takeProfitOnFill.price", &mut tp.price);
+        }
+        if let Some(ref mut sl) = fields.stop_loss {
+            self.check_price(name, &mut violations, "stopLossOnFill.price", &mut sl.price);
+            self.check_price(name, &mut violations, "stopLossOnFill.distance", &mut sl.distance);
+            if sl.price.is_some() == sl.distance.is_some() {
+                fail(&mut violations, "stopLossOnFill", "exactly one of price and distance must be set".to_string());
+            }
+        }
+        if let Some(ref mut tsl) = fields.trailing_stop {
+            self.check_price(name, &mut violations, "trailingStopLossOnFill.distance", &mut tsl.distance);
+            match tsl.distance {
+                None => fail(&mut violations, "trailingStopLossOnFill.distance", "missing".to_string()),
+                Some(distance) => {
+                    if let Some(min) = rules.minimum_trailing_stop_distance {
+                        if distance < min {
+                            fail(
+                                &mut violations,
+                                "trailingStopLossOnFill.distance",
+                                format!("{} is below the minimum trailing stop distance {}", distance, min)
+                            );
+                        }
+                    }
+                    if let Some(max) = rules.maximum_trailing_stop_distance {
+                        if distance > max {
+                            fail(
+                                &mut violations,
+                                "trailingStopLossOnFill.distance",
+                                format!("{} is above the maximum trailing stop distance {}", distance, max)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { violations })
+        }
+    }
+}
+
+fn fail(violations: &mut Vec<Violation>, field: &'static str, message: String) {
+    violations.push(Violation { field, message });
+}
+
+pub(crate) fn round_to(x: f32, digits: i32) -> f32 {
+    let scale = 10f64.powi(digits);
+    ((x as f64 * scale).round() / scale) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator() -> OrderValidator {
+        OrderValidator::from_instruments(
+            vec![
+                Instrument::new()
+                    .with_name("EUR_USD".to_string())
+                    .with_display_precision(5)
+                    .with_trade_units_precision(0)
+                    .with_minimum_trade_size(1.0)
+                    .with_maximum_order_units(100000000.0)
+                    .with_maximum_position_size(0.0)
+                    .with_minimum_trailing_stop_distance(0.0005)
+                    .with_maximum_trailing_stop_distance(1.0)
+            ]
+        )
+    }
+
+    #[test]
+    fn rounds_and_reports_every_violation() {
+        let mut order = LimitOrderRequest::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(1000.7)
+            .with_price(1.123456)
+            .with_stop_loss_on_fill(StopLossDetails::new().with_distance(0.00251));
+        validator().validate(&mut order).unwrap();
+        assert_eq!(order.units, Some(1000.0));
+        assert_eq!(order.price, Some(1.12346));
+        assert_eq!(order.stop_loss_on_fill.unwrap().distance, Some(0.00251));
+
+        let mut bad = MarketOrderRequest::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(0.4)
+            .with_trailing_stop_loss_on_fill(TrailingStopLossDetails::new().with_distance(0.0001));
+        let error = validator().validate(&mut bad).unwrap_err();
+        let fields: Vec<&str> = error.violations.iter().map(|v| v.field).collect();
+        assert_eq!(fields, vec!["units", "trailingStopLossOnFill.distance"]);
+
+        let mut unknown = MarketOrderRequest::new().with_instrument("XAU_XAG".to_string()).with_units(1.0);
+        assert_eq!(validator().validate(&mut unknown).unwrap_err().violations[0].field, "instrument");
+    }
+}
@@ -1,3 +1,4 @@
+import copy
 import json
 import textwrap
 import re
@@ -28,8 +29,45 @@ def get_schema():
   schema['paths']['/instruments/{instrument}/orderBook']['get']['operationId'] = 'getOrderBook'
   schema['paths']['/accounts/{accountID}/instruments/{instrument}/candles']['get']['operationId'] = 'getAccountInstrumentCandles'
   schema['paths']['/accounts/{accountID}/pricing']['get']['parameters'][3]['type'] = 'string'
+  # The account candles endpoint does not list its accountID path parameter.
+  schema['paths']['/accounts/{accountID}/instruments/{instrument}/candles']['get']['parameters'].insert(2, {'$ref': '#/parameters/accountIDPathParam'})
+  add_common_fields(schema)
   return schema
 
+# The base Transaction and Order only carry the fields every type has, so the
+# generic lists (transaction pages and streams, order lookups) lose the type
+# and outcome. Give them the fields shared by the order and fill types.
+TRANSACTION_FIELDS = {
+  'orderID': 'The ID of the Order the Transaction is about.',
+  'clientOrderID': 'The client Order ID of that Order (only provided if the client has assigned one).',
+  'instrument': 'The instrument of the Order or Trade.',
+  'units': 'The units of the Order, or the number of units filled.',
+  'price': 'The price of the Order, or the price it was filled at.',
+  'accountBalance': 'The Account\'s balance after the Transaction, for the types that change it.',
+  'tradeOpened': None,
+  'tradeReduced': None,
+  'tradesClosed': None,
+}
+ORDER_FIELDS = ['fillingTransactionID', 'cancellingTransactionID']
+
+def add_common_fields(schema):
+  definitions = schema['definitions']
+  transaction = definitions['Transaction']['properties']
+  transaction['type'] = {'type': 'string', 'description': 'The Type of the Transaction.'}
+  for name, description in TRANSACTION_FIELDS.items():
+    transaction[name] = copy.deepcopy(definitions['OrderFillTransaction']['properties'][name])
+    if description:
+      transaction[name]['description'] = description
+  # Balances need more digits than an f32 holds.
+  transaction['accountBalance']['x-rust-type'] = 'f64'
+  transaction['reason'] = {'type': 'string', 'description': 'The reason the Transaction was created, for the types that have one.'}
+  transaction['rejectReason'] = {'type': 'string', 'description': 'The reason a Reject Transaction was created.'}
+  order = definitions['Order']['properties']
+  for name in ORDER_FIELDS:
+    order[name] = copy.deepcopy(definitions['MarketOrder']['properties'][name])
+  order['tradeID'] = copy.deepcopy(definitions['TakeProfitOrder']['properties']['tradeID'])
+  order['tradeID']['description'] = 'The ID of the Trade a take profit, stop loss or trailing stop loss Order belongs to.'
+
 def parse_ref(s):
   ref_section, ref_name = s.split("/")[-2:]
   return (ref_section, ref_name)
@@ -78,7 +116,9 @@ def get_param_type(schema, param):
   else:
       t = param['type']
   comment = get_comment(schema, param)
-  if 'rfc3339' in comment:
+  if 'x-rust-type' in param:
+    result = param['x-rust-type']
+  elif 'rfc3339' in comment:
     result = 'DateTime<Utc>'
   elif 'A decimal number' in comment:
     result = 'f32'
@@ -211,6 +251,19 @@ def get_request(schema, url, method_name):
   result['responses'] = responses
   return result 
 
+# Body parameters that are "ALL", "NONE" or a decimal number of units, by
+# operation. They are typed as CloseUnits instead of String.
+CLOSE_UNITS = {
+  'closePosition': ['longUnits', 'shortUnits'],
+  'closeTrade': ['units'],
+}
+
+def set_close_units(r):
+  for p in r['params']['body']:
+    if p['name'] in CLOSE_UNITS.get(r['operationId'], []):
+      p['type'] = 'CloseUnits'
+  return r
+
 def get_definition_enum(schema,name):
   result = dict() 
   enum = schema['definitions'][name]
@@ -219,8 +272,7 @@ def get_definition_enum(schema,name):
   result['camel'] = camel(name.replace('-',''))
   variants = list()
   for v in enum['enum']:
-    v = v.replace('-','');
-    variant = { 'name': v, 'camel': camel(v)}
+    variant = { 'name': v, 'camel': camel(v.replace('-',''))}
     variants.append(variant) 
   result['variants'] = variants 
   return result 
@@ -268,7 +320,7 @@ def run():
               modules.append(snake(r['name']))
               print(request_tmpl.render(url=r['url'], method=r['method'], comment=r['comment'], operationId=r['operationId'], name=r['name'], snake=snake(r['name']), params=r['params'], responses=r['responses'])) 
           else:
-            r = get_request(schema, url, method)
+            r = set_close_units(get_request(schema, url, method))
             modules.append(snake(r['name']))
             print(request_tmpl.render(url=r['url'], method=r['method'], comment=r['comment'], operationId=r['operationId'], name=r['name'], snake=snake(r['name']), params=r['params'], responses=r['responses'])) 
     for m in modules: